@@ -1,20 +1,133 @@
 use soroban_sdk::{
-    Env, Vec, String,
+    Address, Env, Vec, String,
 };
 
 use crate::storage::{
-    ContentAnalytics, TimeBasedMetrics, CategoryAnalytics, TimePeriod,
+    ContentAnalytics, TimeBasedMetrics, CategoryAnalytics, TimePeriod, EngagementKind,
+    EngagementConfig, DataKey,
     save_content_analytics, get_content_analytics, save_time_based_metrics,
     get_time_based_metrics, save_category_analytics, get_category_analytics,
-    get_content, content_exists, get_all_content_ids
+    get_content, content_exists, get_all_content_ids, is_removed
 };
+use crate::trending::Trending;
+use crate::verifier;
+
+/// Time bucket used until one is configured: one hour
+pub const DEFAULT_BUCKET_SECS: u64 = 60 * 60;
+/// Engagements one address can record per bucket until configured
+pub const DEFAULT_MAX_PER_BUCKET: u32 = 60;
+/// Approximate ledger close time, used to size temporary entry lifetimes
+const LEDGER_SECS: u64 = 5;
 
 /// Analytics module for tracking content performance and engagement metrics
 pub struct Analytics;
 
 impl Analytics {
+    /// Check that an engagement is authorized by the viewer, or by a registered
+    /// reporter recording it on the viewer's behalf
+    pub fn authorize_engagement(env: &Env, viewer: &Address, reporter: &Option<Address>) {
+        match reporter {
+            Some(reporter) => {
+                reporter.require_auth();
+                if !Self::is_reporter(env, reporter) {
+                    panic!("reporter not registered");
+                }
+            }
+            None => viewer.require_auth(),
+        }
+    }
+
+    /// Record an engagement of `viewer` with a content item. A view counts once per
+    /// time bucket and a viewer casts one vote, up or down, over the lifetime of the
+    /// content; returns false when the engagement was a duplicate.
+    pub fn record_engagement(
+        env: &Env,
+        content_id: u64,
+        viewer: &Address,
+        kind: EngagementKind
+    ) -> Result<bool, String> {
+        if !content_exists(env, content_id) {
+            return Err(String::from_str(env, "Content does not exist"));
+        }
+        if is_removed(env, content_id) {
+            return Err(String::from_str(env, "content removed"));
+        }
+
+        let config = Self::get_engagement_config(env);
+        let bucket = env.ledger().timestamp() / config.bucket_secs;
+        let ttl = (config.bucket_secs / LEDGER_SECS + 1) as u32;
+
+        // View dedup and rate limit entries only matter for the current bucket, so
+        // they live in temporary storage and expire on their own
+        let storage = env.storage().temporary();
+        let engagement_key = DataKey::Engagement(content_id, viewer.clone(), bucket, kind);
+        let voter_key = DataKey::ContentVoter(content_id, viewer.clone());
+        let duplicate = match kind {
+            EngagementKind::View => storage.has(&engagement_key),
+            EngagementKind::Upvote | EngagementKind::Downvote => env.storage().persistent().has(&voter_key),
+        };
+        if duplicate {
+            return Ok(false);
+        }
+
+        let count_key = DataKey::EngagementCount(viewer.clone(), bucket);
+        let count: u32 = storage.get(&count_key).unwrap_or(0);
+        if count >= config.max_per_bucket {
+            return Err(String::from_str(env, "Engagement rate limit exceeded"));
+        }
+
+        if kind == EngagementKind::View {
+            storage.set(&engagement_key, &true);
+            storage.extend_ttl(&engagement_key, ttl, ttl);
+        } else {
+            env.storage().persistent().set(&voter_key, &kind);
+        }
+        storage.set(&count_key, &(count + 1));
+        storage.extend_ttl(&count_key, ttl, ttl);
+
+        match kind {
+            EngagementKind::View => Self::record_view(env, content_id, viewer)?,
+            EngagementKind::Upvote => Self::record_upvote(env, content_id)?,
+            EngagementKind::Downvote => Self::record_downvote(env, content_id)?,
+        }
+        Trending::record_engagement(env, content_id, kind);
+
+        Ok(true)
+    }
+
+    /// Allow a backend to report engagement on behalf of viewers (admin or governance)
+    pub fn set_reporter(env: &Env, manager: Address, reporter: Address, allowed: bool) {
+        verifier::require_manager(env, &manager);
+        let key = DataKey::Reporter(reporter);
+        if allowed {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+    }
+
+    pub fn is_reporter(env: &Env, reporter: &Address) -> bool {
+        env.storage().instance().has(&DataKey::Reporter(reporter.clone()))
+    }
+
+    /// Configure the time bucket and the per address rate limit (admin or governance)
+    pub fn set_engagement_config(env: &Env, manager: Address, config: EngagementConfig) {
+        verifier::require_manager(env, &manager);
+        if config.bucket_secs == 0 || config.max_per_bucket == 0 {
+            panic!("invalid engagement config");
+        }
+        env.storage().instance().set(&DataKey::EngagementConfig, &config);
+    }
+
+    pub fn get_engagement_config(env: &Env) -> EngagementConfig {
+        env.storage().instance().get(&DataKey::EngagementConfig).unwrap_or(EngagementConfig {
+            bucket_secs: DEFAULT_BUCKET_SECS,
+            max_per_bucket: DEFAULT_MAX_PER_BUCKET,
+        })
+    }
+
     /// Record a view for a content item
-    pub fn record_view(env: &Env, content_id: u64) -> Result<(), String> {
+    fn record_view(env: &Env, content_id: u64, viewer: &Address) -> Result<(), String> {
         if !content_exists(env, content_id) {
             return Err(String::from_str(env, "Content does not exist"));
         }
@@ -29,10 +142,18 @@ impl Analytics {
                 average_rating: 0,
                 trending_score: 0,
                 last_updated: env.ledger().timestamp(),
+                unique_viewers: 0,
             });
 
         analytics.total_views += 1;
         analytics.last_updated = env.ledger().timestamp();
+
+        // Count each viewer once over the lifetime of the content
+        let viewer_key = DataKey::ContentViewer(content_id, viewer.clone());
+        if !env.storage().persistent().has(&viewer_key) {
+            env.storage().persistent().set(&viewer_key, &true);
+            analytics.unique_viewers += 1;
+        }
         
         // Recalculate engagement rate
         analytics.engagement_rate = Self::calculate_engagement_rate(
@@ -50,7 +171,7 @@ impl Analytics {
     }
 
     /// Record an upvote for a content item
-    pub fn record_upvote(env: &Env, content_id: u64) -> Result<(), String> {
+    fn record_upvote(env: &Env, content_id: u64) -> Result<(), String> {
         if !content_exists(env, content_id) {
             return Err(String::from_str(env, "Content does not exist"));
         }
@@ -65,6 +186,7 @@ impl Analytics {
                 average_rating: 0,
                 trending_score: 0,
                 last_updated: env.ledger().timestamp(),
+                unique_viewers: 0,
             });
 
         analytics.total_upvotes += 1;
@@ -90,7 +212,7 @@ impl Analytics {
     }
 
     /// Record a downvote for a content item
-    pub fn record_downvote(env: &Env, content_id: u64) -> Result<(), String> {
+    fn record_downvote(env: &Env, content_id: u64) -> Result<(), String> {
         if !content_exists(env, content_id) {
             return Err(String::from_str(env, "Content does not exist"));
         }
@@ -105,6 +227,7 @@ impl Analytics {
                 average_rating: 0,
                 trending_score: 0,
                 last_updated: env.ledger().timestamp(),
+                unique_viewers: 0,
             });
 
         analytics.total_downvotes += 1;
@@ -1,8 +1,8 @@
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Vec};
 
 use crate::{
     storage::{
-        get_collaborative_permission, get_collaborative_submission, get_content, save_collaborative_permission, save_collaborative_submission, save_contribution_to_history, CollaboratorPermission, CollaboratorSubmission, Content, PermissionType, ReviewStatus
+        find_collaborative_permission, get_content, get_content_reviewers, get_next_submission_id, get_required_approvals, get_submission, get_version_count, index_submission, save_collaborative_permission, save_content_reviewers, save_contribution_to_history, save_required_approvals, save_submission, CollaboratorPermission, CollaboratorSubmission, Content, PermissionType, ReviewStatus
     },
     versioning,
 };
@@ -13,31 +13,58 @@ pub fn grant_permission(env: &Env, content_id: u64, owner: Address, collaborator
     if content.creator != owner {
         panic!("Only content creator can grant permissions");
     }
+    if is_reviewer(env, &collaborator, &content) {
+        panic!("user is already a reviewer");
+    }
 
-    let permission = CollaboratorPermission {
-        collaborator: collaborator.clone(),
-        content_id,
-        permission_type: PermissionType::Collaborator,
-        granted_by: owner,
-        granted_date: env.ledger().timestamp(),
-    };
+    save_permission(env, content_id, owner, collaborator, PermissionType::Collaborator);
+    true
+}
 
-    save_collaborative_permission(env, collaborator, content_id, &permission);
+/// Grant the reviewer role on a content. Reviewers can also submit.
+pub fn grant_reviewer(env: &Env, content_id: u64, owner: Address, reviewer: Address) -> bool {
+    let content = get_content(env, content_id);
+    if content.creator != owner {
+        panic!("Only content creator can grant permissions");
+    }
+    if is_reviewer(env, &reviewer, &content) {
+        panic!("user is already a reviewer");
+    }
+
+    save_permission(env, content_id, owner, reviewer.clone(), PermissionType::Reviewer);
+    let mut reviewers = get_content_reviewers(env, content_id);
+    reviewers.push_back(reviewer);
+    save_content_reviewers(env, content_id, &reviewers);
     true
 }
 
+/// Set how many of the reviewers (the creator included) must approve a submission
+pub fn set_required_approvals(env: &Env, content_id: u64, owner: Address, required: u32) {
+    let content = get_content(env, content_id);
+    if content.creator != owner {
+        panic!("Only content creator can set required approvals");
+    }
+    if required == 0 || required > reviewer_count(env, content_id) {
+        panic!("required approvals must be between 1 and the number of reviewers");
+    }
+    save_required_approvals(env, content_id, required);
+}
+
 /// Check if user has permission for content
 pub fn has_permission_submit_with_content(env: &Env, user: &Address, content: &Content) -> bool {
     if content.creator == *user {
         return true;
     }
 
-    let user_permission = get_collaborative_permission(env, user, content.id);
+    let user_permission = find_collaborative_permission(env, user, content.id).unwrap_or_else(|| {
+        panic!("permission not found for user and content_id")
+    });
 
     user_permission.permission_type == PermissionType::Collaborator
+        || user_permission.permission_type == PermissionType::Reviewer
 }
 
-/// Submit content update for review
+/// Submit content update for review. Returns the submission id.
 pub fn submit_for_review(
     env: &Env,
     content_id: u64,
@@ -45,7 +72,7 @@ pub fn submit_for_review(
     new_content_hash: BytesN<32>,
     new_subject_tags: Vec<String>,
     change_notes: String,
-) -> bool {
+) -> u64 {
     let content = get_content(env, content_id);
 
     if !has_permission_submit_with_content(env, &submitter, &content) {
@@ -53,6 +80,7 @@ pub fn submit_for_review(
     }
 
     let submission = CollaboratorSubmission {
+        submission_id: get_next_submission_id(env),
         content_id,
         collaborator: submitter.clone(),
         submission_date: env.ledger().timestamp(),
@@ -60,64 +88,123 @@ pub fn submit_for_review(
         new_content_hash,
         new_subject_tags,
         change_notes,
+        base_version: get_version_count(env, content_id),
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
         reviewer: None,
         review_date: None,
         review_feedback: None,
     };
 
-    save_collaborative_submission(env, submitter.clone(), content_id, &submission);
-    true
+    save_submission(env, &submission);
+    index_submission(env, &submission);
+
+    env.events().publish(
+        (symbol_short!("SUBMIT"), content_id),
+        (submission.submission_id, submitter, submission.base_version),
+    );
+
+    submission.submission_id
 }
 
-/// Accept or reject submission (creator only)
+/// Approve or reject a submission (creator or reviewer). The submission is merged
+/// once it has the required approvals, rejected once it can no longer get them and
+/// marked as a conflict if another version was merged since it was made.
 pub fn review_submission(
     env: &Env,
-    content_id: u64,
-    submitter: Address,
+    submission_id: u64,
     reviewer: Address,
     accept: bool,
     feedback: String,
-) -> bool {
+) -> ReviewStatus {
+    let mut submission = get_submission(env, submission_id);
+    let content_id = submission.content_id;
     let content = get_content(env, content_id);
-    if content.creator != reviewer {
-        panic!("Only content creator can review submissions");
+    if !is_reviewer(env, &reviewer, &content) {
+        panic!("Only the content creator or a reviewer can review submissions");
     }
 
-    let mut submission = get_collaborative_submission(env, &submitter, content_id);
     if submission.status != ReviewStatus::Pending {
         panic!("Submission must be pending to review");
     }
+    if submission.collaborator == reviewer && content.creator != reviewer {
+        panic!("cannot review own submission");
+    }
+    if submission.approvals.contains(&reviewer) || submission.rejections.contains(&reviewer) {
+        panic!("reviewer already reviewed this submission");
+    }
 
     submission.reviewer = Some(reviewer.clone());
     submission.review_date = Some(env.ledger().timestamp());
     submission.review_feedback = Some(feedback);
-    submission.status = if accept {
-        ReviewStatus::Accepted
+
+    let head_version = get_version_count(env, content_id);
+    if submission.base_version != head_version {
+        submission.status = ReviewStatus::Conflict;
+        env.events().publish(
+            (symbol_short!("CONFLICT"), content_id),
+            (submission_id, submission.base_version, head_version),
+        );
     } else {
-        ReviewStatus::Rejected
-    };
+        if accept {
+            submission.approvals.push_back(reviewer.clone());
+        } else {
+            submission.rejections.push_back(reviewer.clone());
+        }
+        env.events().publish((symbol_short!("REVIEW"), content_id), (submission_id, reviewer, accept));
+
+        let required = get_required_approvals(env, content_id);
+        if submission.approvals.len() >= required {
+            submission.status = ReviewStatus::Accepted;
+        } else if submission.rejections.len() > reviewer_count(env, content_id).saturating_sub(required) {
+            submission.status = ReviewStatus::Rejected;
+        }
+    }
 
-    save_collaborative_submission(
-        env,
-        submission.collaborator.clone(),
-        content_id,
-        &submission,
-    );
+    save_submission(env, &submission);
 
-    // save history after review
-    save_contribution_to_history(env, &submission.collaborator, content_id, &submission);
+    if submission.status != ReviewStatus::Pending {
+        // save history once the review is settled
+        save_contribution_to_history(env, &submission.collaborator, content_id, &submission);
+    }
 
-    if accept {
-        versioning::create_version(
+    if submission.status == ReviewStatus::Accepted {
+        let mut updated_content = content.clone();
+        updated_content.content_hash = submission.new_content_hash.clone();
+        updated_content.subject_tags = submission.new_subject_tags.clone();
+        versioning::apply_version(
             env,
-            content_id,
-            reviewer,
-            content.title.clone(),
-            submission.new_content_hash.clone(),
-            submission.new_subject_tags.clone(),
+            &content,
+            &updated_content,
+            content.creator.clone(),
             submission.change_notes.clone(),
+            Some(submission.collaborator.clone()),
+            submission.approvals.clone(),
         );
     }
 
-    true
+    submission.status
+}
+
+fn save_permission(env: &Env, content_id: u64, owner: Address, user: Address, permission_type: PermissionType) {
+    let permission = CollaboratorPermission {
+        collaborator: user.clone(),
+        content_id,
+        permission_type,
+        granted_by: owner,
+        granted_date: env.ledger().timestamp(),
+    };
+
+    save_collaborative_permission(env, user, content_id, &permission);
+}
+
+fn is_reviewer(env: &Env, user: &Address, content: &Content) -> bool {
+    content.creator == *user
+        || find_collaborative_permission(env, user, content.id)
+            .is_some_and(|permission| permission.permission_type == PermissionType::Reviewer)
+}
+
+// Reviewers of a content, the creator included
+fn reviewer_count(env: &Env, content_id: u64) -> u32 {
+    get_content_reviewers(env, content_id).len() + 1
 }
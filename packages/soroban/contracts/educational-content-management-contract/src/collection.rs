@@ -0,0 +1,187 @@
+use soroban_sdk::{symbol_short, Address, Env, String, Vec};
+
+use crate::storage::{
+    get_collection, get_collection_curator, get_content, get_content_analytics, get_next_collection_id, is_removed,
+    remove_collection_curator, save_collection, save_collection_curator, CollaboratorPermission, Collection,
+    CollectionAnalytics, PermissionType, Prerequisite,
+};
+
+/// Create an empty collection. Returns the collection id.
+pub fn create_collection(env: &Env, owner: Address, title: String, description: String, tags: Vec<String>) -> u64 {
+    let now = env.ledger().timestamp();
+    let collection = Collection {
+        id: get_next_collection_id(env),
+        owner: owner.clone(),
+        title,
+        description,
+        tags,
+        items: Vec::new(env),
+        prerequisites: Vec::new(env),
+        created_at: now,
+        updated_at: now,
+    };
+    save_collection(env, &collection);
+
+    env.events().publish((symbol_short!("COLL_NEW"), owner), collection.id);
+    collection.id
+}
+
+/// Let a user curate the items of a collection
+pub fn grant_curator(env: &Env, collection_id: u64, owner: Address, curator: Address) -> bool {
+    let collection = get_collection(env, collection_id);
+    if collection.owner != owner {
+        panic!("Only collection owner can grant permissions");
+    }
+
+    let permission = CollaboratorPermission {
+        collaborator: curator.clone(),
+        content_id: collection_id,
+        permission_type: PermissionType::Collaborator,
+        granted_by: owner,
+        granted_date: env.ledger().timestamp(),
+    };
+    save_collection_curator(env, curator, collection_id, &permission);
+    true
+}
+
+pub fn revoke_curator(env: &Env, collection_id: u64, owner: Address, curator: Address) {
+    let collection = get_collection(env, collection_id);
+    if collection.owner != owner {
+        panic!("Only collection owner can revoke permissions");
+    }
+    remove_collection_curator(env, &curator, collection_id);
+}
+
+/// Append a content item to a collection
+pub fn add_item(env: &Env, collection_id: u64, curator: Address, content_id: u64) {
+    let mut collection = get_curated_collection(env, collection_id, &curator);
+    get_content(env, content_id);
+    if collection.items.contains(content_id) {
+        panic!("content already in collection");
+    }
+
+    collection.items.push_back(content_id);
+    touch_and_save(env, &mut collection);
+    env.events().publish((symbol_short!("COLL_ADD"), collection_id), (content_id, curator));
+}
+
+/// Remove a content item and the prerequisites involving it
+pub fn remove_item(env: &Env, collection_id: u64, curator: Address, content_id: u64) {
+    let mut collection = get_curated_collection(env, collection_id, &curator);
+    let index = item_position(&collection, content_id);
+    collection.items.remove(index);
+
+    let mut prerequisites = Vec::new(env);
+    for prerequisite in collection.prerequisites.iter() {
+        if prerequisite.content_id != content_id && prerequisite.requires != content_id {
+            prerequisites.push_back(prerequisite);
+        }
+    }
+    collection.prerequisites = prerequisites;
+    touch_and_save(env, &mut collection);
+}
+
+/// Move a content item to `position` in the learning order
+pub fn move_item(env: &Env, collection_id: u64, curator: Address, content_id: u64, position: u32) {
+    let mut collection = get_curated_collection(env, collection_id, &curator);
+    if position >= collection.items.len() {
+        panic!("position out of range");
+    }
+    let index = item_position(&collection, content_id);
+    collection.items.remove(index);
+    collection.items.insert(position, content_id);
+
+    for prerequisite in collection.prerequisites.iter() {
+        if item_position(&collection, prerequisite.requires) > item_position(&collection, prerequisite.content_id) {
+            panic!("move breaks a prerequisite");
+        }
+    }
+    touch_and_save(env, &mut collection);
+}
+
+/// Require `requires` to be completed before `content_id`. Prerequisites follow
+/// the learning order, which keeps them free of cycles.
+pub fn add_prerequisite(env: &Env, collection_id: u64, curator: Address, content_id: u64, requires: u64) {
+    let mut collection = get_curated_collection(env, collection_id, &curator);
+    if item_position(&collection, requires) >= item_position(&collection, content_id) {
+        panic!("prerequisite must come earlier in the collection");
+    }
+
+    let prerequisite = Prerequisite { content_id, requires };
+    if collection.prerequisites.contains(&prerequisite) {
+        panic!("prerequisite already set");
+    }
+    collection.prerequisites.push_back(prerequisite);
+    touch_and_save(env, &mut collection);
+}
+
+/// Add up the analytics of the items of a collection, skipping removed content
+pub fn get_collection_analytics(env: &Env, collection_id: u64) -> CollectionAnalytics {
+    let collection = get_collection(env, collection_id);
+    let mut analytics = CollectionAnalytics {
+        collection_id,
+        item_count: 0,
+        total_views: 0,
+        total_upvotes: 0,
+        total_downvotes: 0,
+        unique_viewers: 0,
+        engagement_rate: 0,
+    };
+
+    for content_id in collection.items.iter() {
+        if is_removed(env, content_id) {
+            continue;
+        }
+        analytics.item_count += 1;
+        if let Some(item) = get_content_analytics(env, content_id) {
+            analytics.total_views = analytics.total_views.saturating_add(item.total_views);
+            analytics.total_upvotes = analytics.total_upvotes.saturating_add(item.total_upvotes as u64);
+            analytics.total_downvotes = analytics.total_downvotes.saturating_add(item.total_downvotes as u64);
+            analytics.unique_viewers = analytics.unique_viewers.saturating_add(item.unique_viewers);
+        }
+    }
+
+    let engagement = analytics.total_upvotes.saturating_add(analytics.total_downvotes);
+    let rate = engagement.saturating_mul(10000).checked_div(analytics.total_views).unwrap_or(0);
+    analytics.engagement_rate = rate.min(u32::MAX as u64) as u32;
+    analytics
+}
+
+/// Next item a learner should take given the items they completed: the first
+/// item in order that is not completed and whose prerequisites are. Removed
+/// content is skipped and no longer blocks the items depending on it.
+pub fn get_next_item(env: &Env, collection_id: u64, completed: Vec<u64>) -> Option<u64> {
+    let collection = get_collection(env, collection_id);
+
+    collection.items.iter().find(|&content_id| {
+        !completed.contains(content_id)
+            && !is_removed(env, content_id)
+            && collection.prerequisites.iter().all(|prerequisite| {
+                prerequisite.content_id != content_id
+                    || completed.contains(prerequisite.requires)
+                    || is_removed(env, prerequisite.requires)
+            })
+    })
+}
+
+fn get_curated_collection(env: &Env, collection_id: u64, curator: &Address) -> Collection {
+    let collection = get_collection(env, collection_id);
+    let is_curator = get_collection_curator(env, curator, collection_id)
+        .is_some_and(|permission| permission.permission_type == PermissionType::Collaborator);
+    if collection.owner != *curator && !is_curator {
+        panic!("No permission to curate collection");
+    }
+    collection
+}
+
+fn item_position(collection: &Collection, content_id: u64) -> u32 {
+    collection
+        .items
+        .first_index_of(content_id)
+        .unwrap_or_else(|| panic!("content not in collection"))
+}
+
+fn touch_and_save(env: &Env, collection: &mut Collection) {
+    collection.updated_at = env.ledger().timestamp();
+    save_collection(env, collection);
+}
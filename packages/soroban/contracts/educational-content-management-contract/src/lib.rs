@@ -4,14 +4,18 @@ use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 mod publish;
 mod vote;
 mod verify;
+mod verifier;
 mod storage;
 mod versioning;
 mod collaborative;
 mod moderation;
 mod analytics;
 mod trending;
+mod licensing;
+mod takedown;
+mod collection;
 
-use crate::storage::{CollaboratorPermission, CollaboratorSubmission, ContentVersion, VersionDiff};
+use crate::storage::{AccessPass, CollaboratorPermission, CollaboratorSubmission, ContentLicense, ContentOrigin, ContentVersion, EngagementConfig, EngagementKind, LeaderboardEntry, ReviewStatus, RevenueShare, VersionDiff};
 pub use crate::storage::{Content, VerificationLevel};
 
 #[contract]
@@ -19,6 +23,11 @@ pub struct TokenizedEducationalContent;
 
 #[contractimpl]
 impl TokenizedEducationalContent {
+    /// Record the deployer, the only account allowed to call `initialize`
+    pub fn __constructor(env: Env, deployer: Address) {
+        verifier::set_deployer(&env, &deployer);
+    }
+
     pub fn publish_content(
         env: Env,
         creator: Address,
@@ -45,8 +54,10 @@ impl TokenizedEducationalContent {
         verify::verify_content(&env, content_id, verifier, level)
     }
 
+    /// Get a content with its effective verification level, which ignores
+    /// expired and overturned verifications
     pub fn get_content(env: Env, content_id: u64) -> Content {
-        storage::get_content(&env, content_id)
+        verify::with_effective_level(&env, storage::get_content(&env, content_id))
     }
 
     /// Filter and retrieve only verified content
@@ -58,7 +69,7 @@ impl TokenizedEducationalContent {
 
         for i in 0..all_content_ids.len() {
             let content_id = all_content_ids.get(i).unwrap();
-            let content = storage::get_content(&env, content_id);
+            let content = verify::with_effective_level(&env, storage::get_content(&env, content_id));
 
             if content.verification_level > VerificationLevel::None {
                 verified_content.push_back(content);
@@ -75,7 +86,7 @@ impl TokenizedEducationalContent {
 
         for i in 0..all_content_ids.len() {
             let content_id = all_content_ids.get(i).unwrap();
-            let content = storage::get_content(&env, content_id);
+            let content = verify::with_effective_level(&env, storage::get_content(&env, content_id));
 
             if content.verification_level == level {
                 filtered_content.push_back(content);
@@ -140,7 +151,7 @@ impl TokenizedEducationalContent {
     /// @param version: Version number
     /// @return: ContentVersion struct with metadata
     pub fn get_version_info(env: Env, content_id: u64, version: u32) -> ContentVersion {
-        storage::get_version_info(&env, content_id, version)
+        versioning::get_content_version_info(&env, content_id, version)
     }
 
     /// Upvote a specific version of content.
@@ -179,6 +190,41 @@ impl TokenizedEducationalContent {
         versioning::get_version_diff(&env, content_id, from_version, to_version)
     }
 
+    /// Revert content to an earlier version by creating a new head version with its data.
+    /// @param content_id: ID of the content
+    /// @param creator: Address of the content creator (must authorize)
+    /// @param version: Earlier version to restore
+    /// @return: New version number
+    pub fn revert_to_version(env: Env, content_id: u64, creator: Address, version: u32) -> u32 {
+        creator.require_auth();
+        versioning::revert_to_version(&env, content_id, creator, version)
+    }
+
+    /// Fork a version of content into a new content item for derivative works.
+    /// @param content_id: ID of the source content
+    /// @param version: Version of the source to fork
+    /// @param creator: Address of the fork creator (must authorize)
+    /// @param title: Title of the fork
+    /// @return: ID of the new content
+    pub fn fork_content(env: Env, content_id: u64, version: u32, creator: Address, title: String) -> u64 {
+        creator.require_auth();
+        versioning::fork_content(&env, content_id, version, creator, title)
+    }
+
+    /// Get the content and version a content was forked from.
+    /// @param content_id: ID of the content
+    /// @return: ContentOrigin if the content is a fork
+    pub fn get_content_origin(env: Env, content_id: u64) -> Option<ContentOrigin> {
+        storage::get_content_origin(&env, content_id)
+    }
+
+    /// Get the contents forked from a content.
+    /// @param content_id: ID of the source content
+    /// @return: Vector of forked content IDs
+    pub fn get_content_forks(env: Env, content_id: u64) -> Vec<u64> {
+        storage::get_content_forks(&env, content_id)
+    }
+
     // === COLLABORATION FUNCTIONS ===
 
     /// Grant permission to a user for content collaboration.
@@ -196,13 +242,48 @@ impl TokenizedEducationalContent {
         collaborative::grant_permission(&env, content_id, owner, collaborator)
     }
 
-    /// Submit a content update for review by the content owner.
+    /// Grant the reviewer role on a content (creator only).
+    /// @param content_id: ID of the content
+    /// @param owner: Address of the content owner (must authorize)
+    /// @param reviewer: Address of the reviewer
+    /// @return: true if the role was granted
+    pub fn grant_reviewer(
+        env: Env,
+        content_id: u64,
+        owner: Address,
+        reviewer: Address,
+    ) -> bool {
+        owner.require_auth();
+        collaborative::grant_reviewer(&env, content_id, owner, reviewer)
+    }
+
+    /// Set how many reviewers, the creator included, must approve a submission (creator only).
+    /// @param content_id: ID of the content
+    /// @param owner: Address of the content owner (must authorize)
+    /// @param required: Number of approvals needed to merge
+    pub fn set_required_approvals(env: Env, content_id: u64, owner: Address, required: u32) {
+        owner.require_auth();
+        collaborative::set_required_approvals(&env, content_id, owner, required)
+    }
+
+    /// Get the approvals needed to merge a submission and the reviewers of a content.
+    /// @param content_id: ID of the content
+    /// @return: Required approvals and the reviewers besides the creator
+    pub fn get_approval_policy(env: Env, content_id: u64) -> (u32, Vec<Address>) {
+        (
+            storage::get_required_approvals(&env, content_id),
+            storage::get_content_reviewers(&env, content_id),
+        )
+    }
+
+    /// Submit a content update for review. A collaborator can have several
+    /// submissions pending on the same content.
     /// @param content_id: ID of the content
     /// @param submitter: Address of the submitter (must authorize)
     /// @param new_content_hash: Hash of the updated content
     /// @param new_subject_tags: List of new subject tags
     /// @param change_notes: Notes describing the changes
-    /// @return: true if submission successful
+    /// @return: ID of the submission
     pub fn submit_for_review(
         env: Env,
         content_id: u64,
@@ -210,28 +291,41 @@ impl TokenizedEducationalContent {
         new_content_hash: BytesN<32>,
         new_subject_tags: Vec<String>,
         change_notes: String,
-    ) -> bool {
+    ) -> u64 {
         submitter.require_auth();
         collaborative::submit_for_review(&env, content_id, submitter, new_content_hash, new_subject_tags, change_notes)
     }
 
-    /// Accept or reject a submission for content update (creator only).
-    /// @param content_id: ID of the content
-    /// @param submitter: Address of the submitter
-    /// @param reviewer: Address of the reviewer (must authorize, must be creator)
-    /// @param accept: true to accept, false to reject
+    /// Approve or reject a submission (creator or reviewer). The submission is merged
+    /// as a new version once it has the required approvals.
+    /// @param submission_id: ID of the submission
+    /// @param reviewer: Address of the reviewer (must authorize)
+    /// @param accept: true to approve, false to reject
     /// @param feedback: Feedback message for the submitter
-    /// @return: true if review processed
+    /// @return: Status of the submission after the review
     pub fn review_submission(
         env: Env,
-        content_id: u64,
-        submitter: Address,
+        submission_id: u64,
         reviewer: Address,
         accept: bool,
         feedback: String,
-    ) -> bool {
+    ) -> ReviewStatus {
         reviewer.require_auth();
-        collaborative::review_submission(&env, content_id, submitter, reviewer, accept, feedback)
+        collaborative::review_submission(&env, submission_id, reviewer, accept, feedback)
+    }
+
+    /// Get a submission by ID.
+    /// @param submission_id: ID of the submission
+    /// @return: CollaboratorSubmission struct
+    pub fn get_submission(env: Env, submission_id: u64) -> CollaboratorSubmission {
+        storage::get_submission(&env, submission_id)
+    }
+
+    /// Get the IDs of all submissions made on a content item.
+    /// @param content_id: ID of the content
+    /// @return: Vector of submission IDs
+    pub fn get_content_submissions(env: Env, content_id: u64) -> Vec<u64> {
+        storage::get_content_submissions(&env, content_id)
     }
 
     /// Check if a user has permission to submit content for review.
@@ -242,7 +336,7 @@ impl TokenizedEducationalContent {
         storage::get_collaborative_permission(&env, &user, content_id)
     }
 
-    /// Get details of a collaborative submission for a content item.
+    /// Get the latest collaborative submission of a user for a content item.
     /// @param submitter: Address of the submitter
     /// @param content_id: ID of the content
     /// @return: CollaboratorSubmission struct
@@ -263,6 +357,124 @@ impl TokenizedEducationalContent {
         storage::get_user_content_contribution_history(&env, &user, content_id)
     }
 
+    // --- Licensing ---
+
+    /// Set the license of a content and the price of its access passes (0 for free access)
+    pub fn set_license(env: Env, content_id: u64, creator: Address, license: ContentLicense) {
+        creator.require_auth();
+        licensing::set_license(&env, content_id, creator, license)
+    }
+
+    pub fn get_license(env: Env, content_id: u64) -> ContentLicense {
+        licensing::get_license(&env, content_id)
+    }
+
+    /// Split sales among accepted collaborators in basis points; the creator gets the rest
+    pub fn set_revenue_shares(env: Env, content_id: u64, creator: Address, shares: Vec<RevenueShare>) {
+        creator.require_auth();
+        licensing::set_revenue_shares(&env, content_id, creator, shares)
+    }
+
+    pub fn get_revenue_shares(env: Env, content_id: u64) -> Vec<RevenueShare> {
+        licensing::get_revenue_shares(&env, content_id)
+    }
+
+    /// Buy an access pass, or extend a timed one
+    pub fn purchase_access(env: Env, content_id: u64, buyer: Address) -> AccessPass {
+        buyer.require_auth();
+        licensing::purchase_access(&env, content_id, buyer)
+    }
+
+    pub fn get_access_pass(env: Env, content_id: u64, holder: Address) -> Option<AccessPass> {
+        licensing::get_access_pass(&env, content_id, &holder)
+    }
+
+    /// Whether `user` may download the content
+    pub fn has_access(env: Env, user: Address, content_id: u64) -> bool {
+        licensing::has_access(&env, &user, content_id)
+    }
+
+    // --- Verifier Registry ---
+
+    /// Set the admin, the token verifiers stake and the reputation contract
+    /// queried by `verify_content_advanced`. Can only be called once, by the deployer.
+    pub fn initialize(env: Env, admin: Address, stake_token: Address, reputation_contract: Address) {
+        let deployer = verifier::get_deployer(&env);
+        if deployer != admin {
+            deployer.require_auth();
+        }
+        admin.require_auth();
+        verifier::initialize(&env, admin, stake_token, reputation_contract)
+    }
+
+    /// Let a governance contract manage verifiers alongside the admin (admin only).
+    pub fn set_governance(env: Env, admin: Address, governance: Address) {
+        admin.require_auth();
+        verifier::set_governance(&env, admin, governance)
+    }
+
+    /// Register a verifier for a level, or move it to another level (admin or governance).
+    /// A verifier can grant its level and the levels below it.
+    pub fn add_verifier(env: Env, manager: Address, verifier: Address, level: VerificationLevel) {
+        manager.require_auth();
+        verifier::add_verifier(&env, manager, verifier, level)
+    }
+
+    /// Remove a verifier from the registry (admin or governance).
+    /// Its stake can be withdrawn after the unbonding period.
+    pub fn remove_verifier(env: Env, manager: Address, verifier: Address) {
+        manager.require_auth();
+        verifier::remove_verifier(&env, manager, verifier)
+    }
+
+    pub fn get_verifier(env: Env, verifier: Address) -> Option<crate::storage::VerifierInfo> {
+        verifier::get_verifier(&env, &verifier)
+    }
+
+    pub fn get_verifiers_by_level(env: Env, level: VerificationLevel) -> Vec<Address> {
+        verifier::get_verifiers_by_level(&env, level)
+    }
+
+    /// Set the stake verifiers of a level must hold to verify (admin or governance).
+    pub fn set_min_stake(env: Env, manager: Address, level: VerificationLevel, amount: i128) {
+        manager.require_auth();
+        verifier::set_min_stake(&env, manager, level, amount)
+    }
+
+    /// Set the reputation verifiers need to verify at a level with `verify_content_advanced`
+    /// (admin or governance).
+    pub fn set_min_reputation(env: Env, manager: Address, level: VerificationLevel, reputation: u32) {
+        manager.require_auth();
+        verifier::set_min_reputation(&env, manager, level, reputation)
+    }
+
+    pub fn get_min_reputation(env: Env, level: VerificationLevel) -> u32 {
+        verifier::get_min_reputation(&env, level)
+    }
+
+    /// Set the share of stake slashed for an overturned verification, in basis points
+    /// (admin or governance).
+    pub fn set_slash_rate(env: Env, manager: Address, rate: u32) {
+        manager.require_auth();
+        verifier::set_slash_rate(&env, manager, rate)
+    }
+
+    /// Stake tokens as a registered verifier. Returns the verifier's stake.
+    pub fn stake_verifier(env: Env, verifier: Address, amount: i128) -> i128 {
+        verifier.require_auth();
+        verifier::stake(&env, verifier, amount)
+    }
+
+    /// Withdraw stake. Returns the verifier's remaining stake.
+    pub fn unstake_verifier(env: Env, verifier: Address, amount: i128) -> i128 {
+        verifier.require_auth();
+        verifier::unstake(&env, verifier, amount)
+    }
+
+    pub fn get_verification_records(env: Env, content_id: u64) -> Vec<crate::storage::VerificationRecord> {
+        verify::get_verification_records(&env, content_id)
+    }
+
     // --- Advanced Verification ---
     pub fn verify_content_advanced(
         env: Env,
@@ -270,16 +482,15 @@ impl TokenizedEducationalContent {
         verifier: Address,
         level: VerificationLevel,
         delegated_by: Option<Address>,
-        min_reputation: u32,
         expiration_secs: Option<u64>,
     ) -> VerificationLevel {
+        verifier.require_auth();
         verify::verify_content_advanced(
             &env,
             content_id,
             verifier,
             level,
             delegated_by,
-            min_reputation,
             expiration_secs,
         )
     }
@@ -290,15 +501,30 @@ impl TokenizedEducationalContent {
         verifier: Address,
         new_expiration_secs: u64,
     ) {
+        verifier.require_auth();
         verify::renew_verification(&env, content_id, verifier, new_expiration_secs)
     }
 
+    /// Set how long before expiry renewal reminders are emitted (admin or governance)
+    pub fn set_reminder_window(env: Env, manager: Address, window_secs: u64) {
+        manager.require_auth();
+        verify::set_reminder_window(&env, manager, window_secs)
+    }
+
+    /// Keeper entry point: sweep expired verifications of `limit` contents starting
+    /// at index `start`, emitting downgrade and renewal reminder events.
+    /// Returns the number of contents downgraded.
+    pub fn sweep_verifications(env: Env, start: u32, limit: u32) -> u32 {
+        verify::sweep_verifications(&env, start, limit)
+    }
+
     pub fn delegate_verification(
         env: Env,
         delegator: Address,
         delegatee: Address,
         until: Option<u64>,
     ) {
+        delegator.require_auth();
         verify::delegate_verification(&env, delegator, delegatee, until)
     }
 
@@ -307,16 +533,61 @@ impl TokenizedEducationalContent {
         delegator: Address,
         delegatee: Address,
     ) {
+        delegator.require_auth();
         verify::revoke_delegation(&env, delegator, delegatee)
     }
 
     // --- Moderation ---
+
+    /// Register a moderator (admin or governance)
+    pub fn add_moderator(env: Env, manager: Address, moderator: Address) {
+        manager.require_auth();
+        moderation::add_moderator(&env, manager, moderator)
+    }
+
+    pub fn remove_moderator(env: Env, manager: Address, moderator: Address) {
+        manager.require_auth();
+        moderation::remove_moderator(&env, manager, moderator)
+    }
+
+    /// Moderator record with its votes, decisions and overturned decisions
+    pub fn get_moderator(env: Env, moderator: Address) -> Option<crate::storage::ModeratorInfo> {
+        moderation::get_moderator(&env, &moderator)
+    }
+
+    pub fn get_moderators(env: Env) -> Vec<Address> {
+        moderation::get_moderators(&env)
+    }
+
+    /// Set how many moderator votes remove a content (admin or governance)
+    pub fn set_moderation_quorum(env: Env, manager: Address, quorum: u32) {
+        manager.require_auth();
+        moderation::set_moderation_quorum(&env, manager, quorum)
+    }
+
+    /// Set how many flags escalate a content to moderators (admin or governance)
+    pub fn set_escalation_threshold(env: Env, manager: Address, threshold: u32) {
+        manager.require_auth();
+        moderation::set_escalation_threshold(&env, manager, threshold)
+    }
+
+    /// Returns the moderation quorum and the escalation threshold
+    pub fn get_moderation_policy(env: Env) -> (u32, u32) {
+        (moderation::get_moderation_quorum(&env), moderation::get_escalation_threshold(&env))
+    }
+
+    /// Content escalated by flags and waiting for a moderator decision
+    pub fn get_escalated_content(env: Env) -> Vec<u64> {
+        moderation::get_escalated_content(&env)
+    }
+
     pub fn flag_content(
         env: Env,
         content_id: u64,
         flagger: Address,
         reason: String,
     ) {
+        flagger.require_auth();
         moderation::flag_content(&env, content_id, flagger, reason)
     }
 
@@ -331,28 +602,108 @@ impl TokenizedEducationalContent {
         action: crate::storage::ModerationStatus,
         reason: String,
     ) {
+        moderator.require_auth();
         moderation::moderate_content(&env, content_id, moderator, action, reason)
     }
 
+    /// Moderators who voted to remove a content that was not removed yet
+    pub fn get_removal_votes(env: Env, content_id: u64) -> Vec<Address> {
+        moderation::get_removal_votes(&env, content_id)
+    }
+
     pub fn get_moderation_history(env: Env, content_id: u64) -> Vec<crate::storage::ModerationAction> {
         moderation::get_moderation_history(&env, content_id)
     }
 
+    /// Delete own content. It is tombstoned for good: the hash stays, the rest is hidden.
+    pub fn delete_content(env: Env, content_id: u64, creator: Address) {
+        creator.require_auth();
+        moderation::delete_content(&env, content_id, creator)
+    }
+
+    /// Tombstone of a removed content, None while it is visible
+    pub fn get_tombstone(env: Env, content_id: u64) -> Option<crate::storage::Tombstone> {
+        moderation::get_tombstone(&env, content_id)
+    }
+
+    /// Every removal, takedown and reinstatement step of a content
+    pub fn get_audit_trail(env: Env, content_id: u64) -> Vec<crate::storage::AuditEntry> {
+        moderation::get_audit_trail(&env, content_id)
+    }
+
+    /// File a takedown notice as a rights holder. Once approved the content is removed
+    /// until a counter-notice gets it reinstated.
+    pub fn file_takedown(env: Env, content_id: u64, claimant: Address, claim: String) -> u64 {
+        claimant.require_auth();
+        takedown::file_takedown(&env, content_id, claimant, claim)
+    }
+
+    /// Approve a filed takedown, removing the content, or reject it (admin or governance)
+    pub fn review_takedown(env: Env, notice_id: u64, manager: Address, approve: bool) {
+        manager.require_auth();
+        takedown::review_takedown(&env, notice_id, manager, approve)
+    }
+
+    pub fn file_counter_notice(env: Env, notice_id: u64, creator: Address, statement: String) {
+        creator.require_auth();
+        takedown::file_counter_notice(&env, notice_id, creator, statement)
+    }
+
+    pub fn withdraw_takedown(env: Env, notice_id: u64, claimant: Address) {
+        claimant.require_auth();
+        takedown::withdraw_takedown(&env, notice_id, claimant)
+    }
+
+    /// Keep countered content removed before the reinstatement deadline (admin or governance)
+    pub fn uphold_takedown(env: Env, notice_id: u64, manager: Address) {
+        manager.require_auth();
+        takedown::uphold_takedown(&env, notice_id, manager)
+    }
+
+    /// Keeper entry point: uphold an approved takedown that was not countered in time
+    pub fn settle_takedown(env: Env, notice_id: u64) {
+        takedown::settle_takedown(&env, notice_id)
+    }
+
+    /// Keeper entry point: reinstate countered content once the reinstatement delay is over
+    pub fn reinstate_content(env: Env, notice_id: u64) {
+        takedown::reinstate_content(&env, notice_id)
+    }
+
+    pub fn get_takedown_notice(env: Env, notice_id: u64) -> crate::storage::TakedownNotice {
+        takedown::get_notice(&env, notice_id)
+    }
+
+    /// Appeal the removal of own content by moderators
     pub fn create_dispute(
         env: Env,
         content_id: u64,
         creator: Address,
         reason: String,
     ) -> u64 {
+        creator.require_auth();
         moderation::create_dispute(&env, content_id, creator, reason)
     }
 
+    /// Challenge the verification of a content. Upholding the dispute overturns the
+    /// verification and slashes the verifiers answerable for it.
+    pub fn dispute_verification(
+        env: Env,
+        content_id: u64,
+        challenger: Address,
+        reason: String,
+    ) -> u64 {
+        challenger.require_auth();
+        moderation::dispute_verification(&env, content_id, challenger, reason)
+    }
+
     pub fn resolve_dispute(
         env: Env,
         dispute_id: u64,
         resolver: Address,
         approve: bool,
     ) {
+        resolver.require_auth();
         moderation::resolve_dispute(&env, dispute_id, resolver, approve)
     }
 
@@ -364,19 +715,45 @@ impl TokenizedEducationalContent {
     // ANALYTICS FUNCTIONS
     // ========================================
 
-    /// Record a view for a content item
-    pub fn record_content_view(env: Env, content_id: u64) {
-        analytics::Analytics::record_view(&env, content_id).unwrap_or_else(|e| panic!("{:?}", e))
+    /// Record a view for a content item, signed by the viewer or by a registered reporter.
+    /// Returns false when the viewer already viewed it in the current time bucket.
+    pub fn record_content_view(env: Env, content_id: u64, viewer: Address, reporter: Option<Address>) -> bool {
+        analytics::Analytics::authorize_engagement(&env, &viewer, &reporter);
+        analytics::Analytics::record_engagement(&env, content_id, &viewer, EngagementKind::View).unwrap_or_else(|e| panic!("{:?}", e))
     }
 
-    /// Record an upvote for a content item
-    pub fn record_content_upvote(env: Env, content_id: u64) {
-        analytics::Analytics::record_upvote(&env, content_id).unwrap_or_else(|e| panic!("{:?}", e))
+    /// Record an upvote for a content item, signed by the viewer or by a registered reporter
+    pub fn record_content_upvote(env: Env, content_id: u64, viewer: Address, reporter: Option<Address>) -> bool {
+        analytics::Analytics::authorize_engagement(&env, &viewer, &reporter);
+        analytics::Analytics::record_engagement(&env, content_id, &viewer, EngagementKind::Upvote).unwrap_or_else(|e| panic!("{:?}", e))
     }
 
-    /// Record a downvote for a content item
-    pub fn record_content_downvote(env: Env, content_id: u64) {
-        analytics::Analytics::record_downvote(&env, content_id).unwrap_or_else(|e| panic!("{:?}", e))
+    /// Record a downvote for a content item, signed by the viewer or by a registered reporter
+    pub fn record_content_downvote(env: Env, content_id: u64, viewer: Address, reporter: Option<Address>) -> bool {
+        analytics::Analytics::authorize_engagement(&env, &viewer, &reporter);
+        analytics::Analytics::record_engagement(&env, content_id, &viewer, EngagementKind::Downvote).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    /// Allow or disallow a backend to report engagement for viewers (admin or governance)
+    pub fn set_reporter(env: Env, manager: Address, reporter: Address, allowed: bool) {
+        manager.require_auth();
+        analytics::Analytics::set_reporter(&env, manager, reporter, allowed)
+    }
+
+    /// Check whether an address is a registered reporter
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        analytics::Analytics::is_reporter(&env, &reporter)
+    }
+
+    /// Configure engagement deduplication and rate limiting (admin or governance)
+    pub fn set_engagement_config(env: Env, manager: Address, config: EngagementConfig) {
+        manager.require_auth();
+        analytics::Analytics::set_engagement_config(&env, manager, config)
+    }
+
+    /// Get the engagement deduplication and rate limiting settings
+    pub fn get_engagement_config(env: Env) -> EngagementConfig {
+        analytics::Analytics::get_engagement_config(&env)
     }
 
     /// Get analytics for a specific content item
@@ -476,6 +853,94 @@ impl TokenizedEducationalContent {
     ) -> Vec<crate::storage::TrendingContent> {
         trending::Trending::get_trending_content_by_category(&env, &category, period, limit)
     }
+
+    /// Page through the trending leaderboard of a period, highest decayed score first
+    pub fn get_trending_leaderboard(
+        env: Env,
+        period: crate::storage::TrendingPeriod,
+        offset: u32,
+        limit: u32
+    ) -> Vec<LeaderboardEntry> {
+        trending::Trending::get_leaderboard(&env, None, period, offset, limit)
+    }
+
+    /// Page through the trending leaderboard of a category for a period
+    pub fn get_category_leaderboard(
+        env: Env,
+        category: String,
+        period: crate::storage::TrendingPeriod,
+        offset: u32,
+        limit: u32
+    ) -> Vec<LeaderboardEntry> {
+        trending::Trending::get_leaderboard(&env, Some(category), period, offset, limit)
+    }
+
+    // ========================================
+    // COLLECTION FUNCTIONS
+    // ========================================
+
+    /// Create an empty collection of content items. Returns the collection id.
+    pub fn create_collection(
+        env: Env,
+        owner: Address,
+        title: String,
+        description: String,
+        tags: Vec<String>
+    ) -> u64 {
+        owner.require_auth();
+        collection::create_collection(&env, owner, title, description, tags)
+    }
+
+    pub fn get_collection(env: Env, collection_id: u64) -> crate::storage::Collection {
+        storage::get_collection(&env, collection_id)
+    }
+
+    /// Let a user add, remove and reorder the items of a collection
+    pub fn grant_curator(env: Env, collection_id: u64, owner: Address, curator: Address) -> bool {
+        owner.require_auth();
+        collection::grant_curator(&env, collection_id, owner, curator)
+    }
+
+    pub fn revoke_curator(env: Env, collection_id: u64, owner: Address, curator: Address) {
+        owner.require_auth();
+        collection::revoke_curator(&env, collection_id, owner, curator)
+    }
+
+    /// Curation permission of a user on a collection
+    pub fn get_curator_permission(env: Env, curator: Address, collection_id: u64) -> Option<CollaboratorPermission> {
+        storage::get_collection_curator(&env, &curator, collection_id)
+    }
+
+    pub fn add_collection_item(env: Env, collection_id: u64, curator: Address, content_id: u64) {
+        curator.require_auth();
+        collection::add_item(&env, collection_id, curator, content_id)
+    }
+
+    pub fn remove_collection_item(env: Env, collection_id: u64, curator: Address, content_id: u64) {
+        curator.require_auth();
+        collection::remove_item(&env, collection_id, curator, content_id)
+    }
+
+    pub fn move_collection_item(env: Env, collection_id: u64, curator: Address, content_id: u64, position: u32) {
+        curator.require_auth();
+        collection::move_item(&env, collection_id, curator, content_id, position)
+    }
+
+    /// Require `requires` to be completed before `content_id`; it must come earlier in the collection
+    pub fn add_prerequisite(env: Env, collection_id: u64, curator: Address, content_id: u64, requires: u64) {
+        curator.require_auth();
+        collection::add_prerequisite(&env, collection_id, curator, content_id, requires)
+    }
+
+    /// Analytics of the items of a collection added up
+    pub fn get_collection_analytics(env: Env, collection_id: u64) -> crate::storage::CollectionAnalytics {
+        collection::get_collection_analytics(&env, collection_id)
+    }
+
+    /// Next recommended item for a learner who completed the `completed` content ids
+    pub fn get_next_item(env: Env, collection_id: u64, completed: Vec<u64>) -> Option<u64> {
+        collection::get_next_item(&env, collection_id, completed)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,145 @@
+use soroban_sdk::{symbol_short, token, Address, Env, Vec};
+use crate::storage::{
+    get_content, get_content_submissions, get_submission, AccessPass, AdvDataKey, ContentLicense, License,
+    ReviewStatus, RevenueShare,
+};
+
+// Set the license of a content and the price of its access passes
+pub fn set_license(env: &Env, content_id: u64, creator: Address, license: ContentLicense) {
+    let content = get_content(env, content_id);
+    if content.creator != creator {
+        panic!("only the creator can license content");
+    }
+    if license.price < 0 {
+        panic!("price cannot be negative");
+    }
+    if license.price > 0 && license.token.is_none() {
+        panic!("priced content needs a payment token");
+    }
+    if license.duration == Some(0) {
+        panic!("access duration must be positive");
+    }
+
+    env.storage().instance().set(&AdvDataKey::ContentLicense(content_id), &license);
+    env.events().publish((symbol_short!("LICENSE"), content_id), (license.license, license.price));
+}
+
+// Unlicensed content is all rights reserved and free to access
+pub fn get_license(env: &Env, content_id: u64) -> ContentLicense {
+    env.storage()
+        .instance()
+        .get(&AdvDataKey::ContentLicense(content_id))
+        .unwrap_or(ContentLicense { license: License::AllRightsReserved, token: None, price: 0, duration: None })
+}
+
+// Whether the license of a content lets others publish derivative works
+pub fn permits_derivatives(env: &Env, content_id: u64) -> bool {
+    matches!(get_license(env, content_id).license, License::CcBy | License::CcByNc)
+}
+
+// Split sales among accepted collaborators; the creator keeps the rest
+pub fn set_revenue_shares(env: &Env, content_id: u64, creator: Address, shares: Vec<RevenueShare>) {
+    let content = get_content(env, content_id);
+    if content.creator != creator {
+        panic!("only the creator can set revenue shares");
+    }
+
+    let mut total: u32 = 0;
+    let mut recipients = Vec::new(env);
+    for share in shares.iter() {
+        if recipients.contains(&share.recipient) {
+            panic!("duplicate revenue share recipient");
+        }
+        if !is_accepted_collaborator(env, content_id, &share.recipient) {
+            panic!("revenue share recipient is not an accepted collaborator");
+        }
+        total = total.saturating_add(share.share);
+        recipients.push_back(share.recipient);
+    }
+    if total > 10_000 {
+        panic!("revenue shares cannot exceed 10000 basis points");
+    }
+
+    env.storage().instance().set(&AdvDataKey::RevenueShares(content_id), &shares);
+}
+
+pub fn get_revenue_shares(env: &Env, content_id: u64) -> Vec<RevenueShare> {
+    env.storage().instance().get(&AdvDataKey::RevenueShares(content_id)).unwrap_or(Vec::new(env))
+}
+
+// Buy or extend an access pass. Timed passes extend from their current expiry.
+pub fn purchase_access(env: &Env, content_id: u64, buyer: Address) -> AccessPass {
+    let content = get_content(env, content_id);
+    let license = get_license(env, content_id);
+    if license.price == 0 {
+        panic!("content is not for sale");
+    }
+    if content.creator == buyer {
+        panic!("creator already has access");
+    }
+
+    let now = env.ledger().timestamp();
+    let existing = get_access_pass(env, content_id, &buyer);
+    if existing.as_ref().is_some_and(|pass| pass.expires_at.is_none()) {
+        panic!("already has perpetual access");
+    }
+
+    // Pay the collaborators their shares and the creator the rest
+    let token = token::Client::new(env, &license.token.unwrap());
+    let mut remaining = license.price;
+    for share in get_revenue_shares(env, content_id).iter() {
+        let amount = license.price * share.share as i128 / 10_000;
+        if amount > 0 {
+            token.transfer(&buyer, &share.recipient, &amount);
+            remaining -= amount;
+        }
+    }
+    if remaining > 0 {
+        token.transfer(&buyer, &content.creator, &remaining);
+    }
+
+    let expires_at = license.duration.map(|duration| {
+        let start = existing.as_ref().and_then(|pass| pass.expires_at).map_or(now, |expires_at| expires_at.max(now));
+        start + duration
+    });
+    let pass = AccessPass {
+        content_id,
+        holder: buyer.clone(),
+        purchased_at: now,
+        expires_at,
+        total_paid: existing.map_or(0, |pass| pass.total_paid) + license.price,
+    };
+    env.storage().persistent().set(&AdvDataKey::AccessPass(content_id, buyer.clone()), &pass);
+
+    env.events().publish((symbol_short!("ACCESS"), content_id), (buyer, license.price, expires_at));
+    pass
+}
+
+pub fn get_access_pass(env: &Env, content_id: u64, holder: &Address) -> Option<AccessPass> {
+    env.storage().persistent().get(&AdvDataKey::AccessPass(content_id, holder.clone()))
+}
+
+// Creators, accepted collaborators and holders of an unexpired pass can access
+// paid content; free content is open to everyone
+pub fn has_access(env: &Env, user: &Address, content_id: u64) -> bool {
+    let content = get_content(env, content_id);
+    if content.creator == *user || get_license(env, content_id).price == 0 {
+        return true;
+    }
+    if is_accepted_collaborator(env, content_id, user) {
+        return true;
+    }
+
+    get_access_pass(env, content_id, user).is_some_and(|pass| match pass.expires_at {
+        Some(expires_at) => env.ledger().timestamp() < expires_at,
+        None => true,
+    })
+}
+
+// Whether `user` had a submission merged into the content
+fn is_accepted_collaborator(env: &Env, content_id: u64, user: &Address) -> bool {
+    get_content_submissions(env, content_id).iter().any(|submission_id| {
+        let submission = get_submission(env, submission_id);
+        submission.collaborator == *user && submission.status == ReviewStatus::Accepted
+    })
+}
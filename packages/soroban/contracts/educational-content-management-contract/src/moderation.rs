@@ -1,9 +1,24 @@
 use soroban_sdk::{Address, Env, String, Vec, symbol_short};
-use crate::storage::{Flag, ModerationAction, ModerationStatus, Dispute, AdvDataKey};
+use crate::storage::{
+    get_content, get_content_record, AuditAction, AuditEntry, Content, Flag, ModerationAction, ModerationStatus, Dispute,
+    DisputeKind, AdvDataKey, ModeratorInfo, Tombstone, TombstoneReason, VerificationLevel,
+};
+use crate::trending::Trending;
+use crate::{verifier, verify};
 
-// Flag content as inappropriate
+/// Removal votes needed until a quorum is configured
+pub const DEFAULT_MODERATION_QUORUM: u32 = 1;
+/// Flags that escalate a content to moderators until configured
+pub const DEFAULT_ESCALATION_THRESHOLD: u32 = 3;
+
+// Flag content as inappropriate. Content is escalated to moderators once its
+// flags reach the escalation threshold.
 pub fn flag_content(env: &Env, content_id: u64, flagger: Address, reason: String) {
-    let mut flags: Vec<Flag> = env.storage().instance().get(&AdvDataKey::Flag(content_id)).unwrap_or(Vec::new(env));
+    get_content(env, content_id);
+    let mut flags = get_flags(env, content_id);
+    if flags.iter().any(|flag| flag.flagger == flagger) {
+        panic!("content already flagged by this user");
+    }
     let flag = Flag {
         content_id,
         flagger: flagger.clone(),
@@ -11,17 +26,31 @@ pub fn flag_content(env: &Env, content_id: u64, flagger: Address, reason: String
         timestamp: env.ledger().timestamp(),
     };
     flags.push_back(flag);
-    env.storage().instance().set(&AdvDataKey::Flag(content_id), &flags);
+    env.storage().persistent().set(&AdvDataKey::Flag(content_id), &flags);
     env.events().publish((symbol_short!("FLAG"), content_id, flagger), ());
+
+    let mut escalated = get_escalated_content(env);
+    if flags.len() >= get_escalation_threshold(env) && !escalated.contains(content_id) {
+        escalated.push_back(content_id);
+        env.storage().instance().set(&AdvDataKey::EscalatedContent, &escalated);
+        env.events().publish((symbol_short!("ESCALATE"), content_id), flags.len());
+    }
 }
 
 // Get flags of a content
 pub fn get_flags(env: &Env, content_id: u64) -> Vec<Flag> {
-    env.storage().instance().get(&AdvDataKey::Flag(content_id)).unwrap_or(Vec::new(env))
+    env.storage().persistent().get(&AdvDataKey::Flag(content_id)).unwrap_or(Vec::new(env))
 }
 
-// Moderator action on flagged content
+// Moderator action on flagged content. A removal is a vote: the content is
+// tombstoned once the quorum of moderators voted for it.
 pub fn moderate_content(env: &Env, content_id: u64, moderator: Address, action: ModerationStatus, reason: String) {
+    let mut info = require_moderator(env, &moderator);
+    let content = get_content_record(env, content_id);
+    if is_conflicted(env, &content, &moderator) {
+        panic!("moderator has a conflict of interest");
+    }
+
     let mut history: Vec<ModerationAction> = env.storage().instance().get(&AdvDataKey::Moderation(content_id)).unwrap_or(Vec::new(env));
     let moderation = ModerationAction {
         content_id,
@@ -32,7 +61,115 @@ pub fn moderate_content(env: &Env, content_id: u64, moderator: Address, action:
     };
     history.push_back(moderation);
     env.storage().instance().set(&AdvDataKey::Moderation(content_id), &history);
-    env.events().publish((symbol_short!("MODERATE"), content_id, moderator), action as u32);
+    env.events().publish((symbol_short!("MODERATE"), content_id, moderator.clone()), action.clone() as u32);
+
+    if action != ModerationStatus::Removed {
+        if action == ModerationStatus::Approved || action == ModerationStatus::Rejected {
+            dequeue_escalated(env, content_id);
+        }
+        return;
+    }
+
+    if get_tombstone(env, content_id).is_some() {
+        panic!("content already removed");
+    }
+    let mut votes = get_removal_votes(env, content_id);
+    if votes.contains(&moderator) {
+        panic!("moderator already voted");
+    }
+    votes.push_back(moderator.clone());
+    info.votes += 1;
+    save_moderator(env, &info);
+
+    if votes.len() < get_moderation_quorum(env) {
+        env.storage().instance().set(&AdvDataKey::RemovalVotes(content_id), &votes);
+        return;
+    }
+
+    // Quorum reached: remember who decided, to track overturned decisions
+    for decider in votes.iter() {
+        if let Some(mut decider_info) = get_moderator(env, &decider) {
+            decider_info.decisions += 1;
+            save_moderator(env, &decider_info);
+        }
+    }
+    env.storage().instance().remove(&AdvDataKey::RemovalVotes(content_id));
+    env.storage().instance().set(&AdvDataKey::RemovalDeciders(content_id), &votes);
+    dequeue_escalated(env, content_id);
+    tombstone(env, &content, TombstoneReason::Moderation, moderator, None);
+}
+
+// Pending removal votes of moderators still in the registry; votes of removed
+// moderators no longer count toward the quorum
+pub fn get_removal_votes(env: &Env, content_id: u64) -> Vec<Address> {
+    let votes: Vec<Address> = env.storage().instance().get(&AdvDataKey::RemovalVotes(content_id)).unwrap_or(Vec::new(env));
+    let moderators = get_moderators(env);
+    let mut active = Vec::new(env);
+    for voter in votes.iter() {
+        if moderators.contains(&voter) {
+            active.push_back(voter);
+        }
+    }
+    active
+}
+
+// Let a creator delete its content for good
+pub fn delete_content(env: &Env, content_id: u64, creator: Address) {
+    let content = get_content(env, content_id);
+    if content.creator != creator {
+        panic!("only the creator can delete content");
+    }
+    tombstone(env, &content, TombstoneReason::CreatorDeleted, creator, None);
+}
+
+// Hide a content, keeping its hash, and drop it from the trending leaderboards
+pub fn tombstone(env: &Env, content: &Content, reason: TombstoneReason, actor: Address, reference: Option<u64>) {
+    let tombstone = Tombstone {
+        content_id: content.id,
+        content_hash: content.content_hash.clone(),
+        reason,
+        removed_by: actor.clone(),
+        removed_at: env.ledger().timestamp(),
+        notice_id: reference,
+    };
+    env.storage().instance().set(&AdvDataKey::Tombstone(content.id), &tombstone);
+    Trending::remove_content(env, content);
+
+    let action = if reason == TombstoneReason::CreatorDeleted { AuditAction::Deleted } else { AuditAction::Removed };
+    record_audit(env, content.id, action, actor, reference);
+    env.events().publish((symbol_short!("TOMBSTONE"), content.id), reason as u32);
+}
+
+// Make a removed content visible again
+pub fn reinstate(env: &Env, content_id: u64, actor: Address, reference: Option<u64>) {
+    env.storage().instance().remove(&AdvDataKey::Tombstone(content_id));
+    record_audit(env, content_id, AuditAction::Reinstated, actor, reference);
+    env.events().publish((symbol_short!("REINSTATE"), content_id), reference);
+}
+
+pub fn get_tombstone(env: &Env, content_id: u64) -> Option<Tombstone> {
+    env.storage().instance().get(&AdvDataKey::Tombstone(content_id))
+}
+
+pub fn is_removed_by(env: &Env, content_id: u64, reason: TombstoneReason) -> bool {
+    get_tombstone(env, content_id).is_some_and(|tombstone| tombstone.reason == reason)
+}
+
+// Append a step to the removal history of a content. Entries are never changed.
+pub fn record_audit(env: &Env, content_id: u64, action: AuditAction, actor: Address, reference: Option<u64>) {
+    let mut trail = get_audit_trail(env, content_id);
+    trail.push_back(AuditEntry {
+        content_id,
+        action,
+        actor,
+        reference,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&AdvDataKey::AuditTrail(content_id), &trail);
+}
+
+pub fn get_audit_trail(env: &Env, content_id: u64) -> Vec<AuditEntry> {
+    env.storage().persistent().get(&AdvDataKey::AuditTrail(content_id)).unwrap_or(Vec::new(env))
 }
 
 // Get moderation history of a content
@@ -40,12 +177,31 @@ pub fn get_moderation_history(env: &Env, content_id: u64) -> Vec<ModerationActio
     env.storage().instance().get(&AdvDataKey::Moderation(content_id)).unwrap_or(Vec::new(env))
 }
 
-// Create dispute on a moderation action
+// Appeal a moderation removal, only by the creator while the content is removed
 pub fn create_dispute(env: &Env, content_id: u64, creator: Address, reason: String) -> u64 {
+    if get_content_record(env, content_id).creator != creator {
+        panic!("only the content creator can appeal");
+    }
+    if !is_removed_by(env, content_id, TombstoneReason::Moderation) {
+        panic!("content was not removed by moderators");
+    }
+    open_dispute(env, content_id, creator, reason, DisputeKind::Moderation)
+}
+
+// Challenge the verification of a content
+pub fn dispute_verification(env: &Env, content_id: u64, challenger: Address, reason: String) -> u64 {
+    if verify::with_effective_level(env, get_content(env, content_id)).verification_level == VerificationLevel::None {
+        panic!("content is not verified");
+    }
+    open_dispute(env, content_id, challenger, reason, DisputeKind::Verification)
+}
+
+fn open_dispute(env: &Env, content_id: u64, creator: Address, reason: String, kind: DisputeKind) -> u64 {
     let dispute_id = env.storage().instance().get(&AdvDataKey::DisputeCounter).unwrap_or(0u64);
     let dispute = Dispute {
         dispute_id,
         content_id,
+        kind,
         creator: creator.clone(),
         reason,
         status: ModerationStatus::UnderDispute,
@@ -65,6 +221,38 @@ pub fn resolve_dispute(env: &Env, dispute_id: u64, resolver: Address, approve: b
     if dispute.status != ModerationStatus::UnderDispute {
         panic!("Dispute already resolved");
     }
+    // Verification disputes can slash stake, so only verifier managers decide them
+    if dispute.kind == DisputeKind::Verification {
+        verifier::require_manager(env, &resolver);
+        if approve {
+            verify::overturn_verification(env, dispute.content_id, dispute_id);
+        }
+    }
+    // Appeals are decided by a moderator who did not take part in the removal
+    if dispute.kind == DisputeKind::Moderation {
+        let mut info = require_moderator(env, &resolver);
+        if resolver == dispute.creator || is_conflicted(env, &get_content_record(env, dispute.content_id), &resolver) {
+            panic!("moderator has a conflict of interest");
+        }
+        let deciders: Vec<Address> = env.storage().instance().get(&AdvDataKey::RemovalDeciders(dispute.content_id)).unwrap_or(Vec::new(env));
+        if deciders.contains(&resolver) {
+            panic!("moderator cannot resolve an appeal of own decision");
+        }
+        info.appeals_resolved += 1;
+        save_moderator(env, &info);
+
+        // An upheld appeal reinstates the content and counts against the deciders
+        if approve && is_removed_by(env, dispute.content_id, TombstoneReason::Moderation) {
+            for decider in deciders.iter() {
+                if let Some(mut decider_info) = get_moderator(env, &decider) {
+                    decider_info.overturned += 1;
+                    save_moderator(env, &decider_info);
+                }
+            }
+            env.storage().instance().remove(&AdvDataKey::RemovalDeciders(dispute.content_id));
+            reinstate(env, dispute.content_id, resolver.clone(), Some(dispute_id));
+        }
+    }
     dispute.status = if approve { ModerationStatus::Approved } else { ModerationStatus::Rejected };
     dispute.resolved_at = Some(env.ledger().timestamp());
     dispute.resolver = Some(resolver.clone());
@@ -75,4 +263,117 @@ pub fn resolve_dispute(env: &Env, dispute_id: u64, resolver: Address, approve: b
 // Get dispute
 pub fn get_dispute(env: &Env, dispute_id: u64) -> Option<Dispute> {
     env.storage().instance().get(&AdvDataKey::Dispute(dispute_id))
-} 
\ No newline at end of file
+}
+
+// Register a moderator (admin or governance). A returning moderator keeps its record.
+pub fn add_moderator(env: &Env, manager: Address, moderator: Address) {
+    verifier::require_manager(env, &manager);
+    let info = match get_moderator(env, &moderator) {
+        Some(info) if info.removed_at.is_none() => panic!("already a moderator"),
+        Some(mut info) => {
+            info.removed_at = None;
+            info
+        }
+        None => ModeratorInfo {
+            moderator: moderator.clone(),
+            added_at: env.ledger().timestamp(),
+            removed_at: None,
+            votes: 0,
+            decisions: 0,
+            overturned: 0,
+            appeals_resolved: 0,
+        },
+    };
+    save_moderator(env, &info);
+
+    let mut moderators = get_moderators(env);
+    moderators.push_back(moderator.clone());
+    env.storage().instance().set(&AdvDataKey::Moderators, &moderators);
+    env.events().publish((symbol_short!("MOD_ADD"), moderator), ());
+}
+
+// Remove a moderator from the registry (admin or governance). The remaining
+// moderators must still be able to reach the quorum.
+pub fn remove_moderator(env: &Env, manager: Address, moderator: Address) {
+    verifier::require_manager(env, &manager);
+    let mut info = require_moderator(env, &moderator);
+
+    let mut moderators = get_moderators(env);
+    if let Some(index) = moderators.first_index_of(&moderator) {
+        moderators.remove(index);
+    }
+    if moderators.len() < get_moderation_quorum(env) {
+        panic!("quorum exceeds the active moderators");
+    }
+
+    info.removed_at = Some(env.ledger().timestamp());
+    save_moderator(env, &info);
+    env.storage().instance().set(&AdvDataKey::Moderators, &moderators);
+    env.events().publish((symbol_short!("MOD_REM"), moderator), ());
+}
+
+// Moderator record with its performance metrics, kept after removal
+pub fn get_moderator(env: &Env, moderator: &Address) -> Option<ModeratorInfo> {
+    env.storage().instance().get(&AdvDataKey::Moderator(moderator.clone()))
+}
+
+pub fn get_moderators(env: &Env) -> Vec<Address> {
+    env.storage().instance().get(&AdvDataKey::Moderators).unwrap_or(Vec::new(env))
+}
+
+// Removal votes needed to remove content, at most the active moderators (admin or governance)
+pub fn set_moderation_quorum(env: &Env, manager: Address, quorum: u32) {
+    verifier::require_manager(env, &manager);
+    if quorum == 0 {
+        panic!("quorum must be at least 1");
+    }
+    if quorum > get_moderators(env).len() {
+        panic!("quorum exceeds the active moderators");
+    }
+    env.storage().instance().set(&AdvDataKey::ModerationQuorum, &quorum);
+}
+
+pub fn get_moderation_quorum(env: &Env) -> u32 {
+    env.storage().instance().get(&AdvDataKey::ModerationQuorum).unwrap_or(DEFAULT_MODERATION_QUORUM)
+}
+
+// Flags that escalate content to moderators (admin or governance)
+pub fn set_escalation_threshold(env: &Env, manager: Address, threshold: u32) {
+    verifier::require_manager(env, &manager);
+    if threshold == 0 {
+        panic!("escalation threshold must be at least 1");
+    }
+    env.storage().instance().set(&AdvDataKey::EscalationThreshold, &threshold);
+}
+
+pub fn get_escalation_threshold(env: &Env) -> u32 {
+    env.storage().instance().get(&AdvDataKey::EscalationThreshold).unwrap_or(DEFAULT_ESCALATION_THRESHOLD)
+}
+
+// Content waiting for a moderator decision, oldest escalation first
+pub fn get_escalated_content(env: &Env) -> Vec<u64> {
+    env.storage().instance().get(&AdvDataKey::EscalatedContent).unwrap_or(Vec::new(env))
+}
+
+fn dequeue_escalated(env: &Env, content_id: u64) {
+    let mut escalated = get_escalated_content(env);
+    if let Some(index) = escalated.first_index_of(content_id) {
+        escalated.remove(index);
+        env.storage().instance().set(&AdvDataKey::EscalatedContent, &escalated);
+    }
+}
+
+fn require_moderator(env: &Env, moderator: &Address) -> ModeratorInfo {
+    get_moderator(env, moderator)
+        .filter(|info| info.removed_at.is_none())
+        .unwrap_or_else(|| panic!("not a moderator"))
+}
+
+fn save_moderator(env: &Env, info: &ModeratorInfo) {
+    env.storage().instance().set(&AdvDataKey::Moderator(info.moderator.clone()), info);
+}
+
+// The creator and the flaggers of a content cannot moderate it
+fn is_conflicted(env: &Env, content: &Content, moderator: &Address) -> bool {
+    content.creator == *moderator || get_flags(env, content.id).iter().any(|flag| flag.flagger == *moderator)
+}
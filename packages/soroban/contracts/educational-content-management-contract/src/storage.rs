@@ -12,6 +12,7 @@ pub enum VerificationLevel {
 
 // Content data structure as per requirements
 #[contracttype]
+#[derive(Clone)]
 pub struct Content {
     pub id: u64,
     pub creator: Address,
@@ -32,6 +33,9 @@ pub struct ContentVersion {
     pub change_notes: String,
     pub upvotes: u32, // Version-specific upvotes
     pub verification_level: VerificationLevel, // Version-specific verification level
+    pub contributor: Option<Address>, // Collaborator whose submission produced the version
+    pub approvers: Vec<Address>, // Reviewers who approved that submission
+    pub reverted_from: Option<u32>, // Earlier version this version restores
 }
 
 #[contracttype]
@@ -40,6 +44,21 @@ pub struct VersionDiff {
     pub to_version: u32,
     pub title_changed: bool,
     pub content_changed: bool,
+    pub tags_added: Vec<String>,
+    pub tags_removed: Vec<String>,
+    pub from_verification_level: VerificationLevel,
+    pub to_verification_level: VerificationLevel,
+    pub upvote_delta: i64, // Upvotes of to_version minus upvotes of from_version
+    pub versions: Vec<u32>, // Versions from from_version to to_version, both included
+    pub authors: Vec<Address>, // Author of each version in `versions`
+}
+
+// Origin of a forked content
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentOrigin {
+    pub content_id: u64,
+    pub version: u32,
 }
 
 /// COLLABORATIVE STORAGE
@@ -47,7 +66,7 @@ pub struct VersionDiff {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PermissionType {
     Collaborator = 0, // Can submit content for review
-    // Reviewer = 1,
+    Reviewer = 1,     // Can submit and approve or reject submissions
 }
 
 #[contracttype]
@@ -56,6 +75,7 @@ pub enum ReviewStatus {
     Pending = 0,
     Accepted = 1,
     Rejected = 2,
+    Conflict = 3, // Based on an older version than the current head
 }
 
 #[contracttype]
@@ -70,6 +90,7 @@ pub struct CollaboratorPermission {
 #[contracttype]
 #[derive(Clone)]
 pub struct CollaboratorSubmission {
+    pub submission_id: u64,
     pub content_id: u64,
     pub collaborator: Address,
     pub submission_date: u64,
@@ -77,7 +98,10 @@ pub struct CollaboratorSubmission {
     pub new_content_hash: BytesN<32>,
     pub new_subject_tags: Vec<String>,
     pub change_notes: String,
-    pub reviewer: Option<Address>,
+    pub base_version: u32, // Head version when the submission was made
+    pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub reviewer: Option<Address>, // Latest reviewer
     pub review_date: Option<u64>,
     pub review_feedback: Option<String>,
 }
@@ -94,10 +118,17 @@ pub enum DataKey {
     ContentHistory(u64, u32),               // content_id, version -> ContentVersion
     VersionCount(u64),                      // content_id -> total versions  
     VersionVotes(Address, u64, u32),        // voter, content_id, version
+    ContentOrigin(u64),                     // forked content_id -> ContentOrigin
+    ContentForks(u64),                      // content_id -> Vec<u64> forked content ids
 
     // Collaborative keys
     CollaboratorPermission(Address, u64),   // collaborator, content_id -> CollaboratorPermission
-    CollaboratorSubmission(Address, u64),   // collaborator, content_id -> CollaboratorSubmission
+    CollaboratorSubmission(Address, u64),   // collaborator, content_id -> latest submission id
+    Submission(u64),                        // submission_id -> CollaboratorSubmission
+    SubmissionCounter,                      // u64
+    ContentSubmissions(u64),                // content_id -> Vec<u64> submission ids
+    ContentReviewers(u64),                  // content_id -> Vec<Address> granted the reviewer role
+    RequiredApprovals(u64),                 // content_id -> u32 approvals needed to merge
     UserContentContributions(Address, u64), // collaborator, content_id -> Vec<CollaboratorSubmission>
     
     // Analytics keys
@@ -106,12 +137,65 @@ pub enum DataKey {
     CategoryAnalytics(String),                // category -> CategoryAnalytics
     TrendingContent(u64, TrendingPeriod),     // content_id, period -> TrendingContent
     TrendingSnapshot(TrendingPeriod, u64),    // period, timestamp -> TrendingSnapshot
+    TrendingScore(u64, TrendingPeriod),       // content_id, period -> LeaderboardEntry (persistent)
+    Leaderboard(TrendingPeriod),              // period -> Vec<LeaderboardEntry> sorted by score (persistent)
+    CategoryLeaderboard(String, TrendingPeriod), // category, period -> Vec<LeaderboardEntry> sorted by score (persistent)
     AnalyticsCounter,                         // u64
+
+    // Engagement tracking keys
+    Reporter(Address),                                // reporter -> bool, allowed to report for viewers
+    EngagementConfig,                                 // EngagementConfig
+    ContentViewer(u64, Address),                      // content_id, viewer -> bool (persistent)
+    ContentVoter(u64, Address),                       // content_id, viewer -> EngagementKind of their vote (persistent)
+    Engagement(u64, Address, u64, EngagementKind),    // content_id, viewer, bucket, kind -> bool (temporary)
+    EngagementCount(Address, u64),                    // address, bucket -> u32 (temporary)
+
+    // Collection keys
+    Collection(u64),                          // collection_id -> Collection
+    CollectionCounter,                        // u64
+    CollectionCurator(Address, u64),          // curator, collection_id -> CollaboratorPermission
+}
+
+/// COLLECTION STORAGE STRUCTURES
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Prerequisite {
+    pub content_id: u64, // Item with the prerequisite
+    pub requires: u64,   // Item to complete first
+}
+
+/// Ordered curriculum of content items
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Collection {
+    pub id: u64,
+    pub owner: Address,
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub items: Vec<u64>, // Content ids in learning order
+    pub prerequisites: Vec<Prerequisite>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Analytics of the items of a collection added up
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CollectionAnalytics {
+    pub collection_id: u64,
+    pub item_count: u32,
+    pub total_views: u64,
+    pub total_upvotes: u64,
+    pub total_downvotes: u64,
+    pub unique_viewers: u64, // Sum over items, a viewer of several items counts several times
+    pub engagement_rate: u32, // (upvotes + downvotes) / views * 10000
 }
 
 // --- Advanced Verification and Moderation Additions ---
 
 #[contracttype]
+#[derive(Clone)]
 pub struct VerificationRecord {
     pub verifier: Address,
     pub level: VerificationLevel,
@@ -119,6 +203,21 @@ pub struct VerificationRecord {
     pub expiration: Option<u64>,
     pub delegated_by: Option<Address>,
     pub reputation_snapshot: Option<u32>,
+    pub overturned: bool, // Set when a verification dispute against the content is upheld
+    pub reminder_sent: bool, // Set once a renewal reminder was emitted for the current expiration
+    pub expired: bool, // Set by the sweep once the record expired, cleared on renewal
+}
+
+/// Registered verifier and its stake
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct VerifierInfo {
+    pub verifier: Address,
+    pub level: VerificationLevel, // Highest level the verifier may grant
+    pub stake: i128,
+    pub registered_at: u64,
+    pub removed_at: Option<u64>,  // Set once removed from the registry
+    pub slashed: i128,            // Total stake slashed so far
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -130,6 +229,7 @@ pub struct Delegation {
     pub until: Option<u64>,
 }
 
+#[derive(Clone)]
 #[contracttype]
 pub struct Flag {
     pub content_id: u64,
@@ -157,10 +257,18 @@ pub struct ModerationAction {
     pub timestamp: u64,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+pub enum DisputeKind {
+    Moderation,   // Appeal against a moderation action
+    Verification, // Challenge of the content's verification
+}
+
 #[contracttype]
 pub struct Dispute {
     pub dispute_id: u64,
     pub content_id: u64,
+    pub kind: DisputeKind,
     pub creator: Address,
     pub reason: String,
     pub status: ModerationStatus,
@@ -174,10 +282,170 @@ pub struct Dispute {
 pub enum AdvDataKey {
     VerificationRecord(u64), // content_id -> Vec<VerificationRecord>
     Delegation(Address),     // delegator -> Vec<Delegation>
-    Flag(u64),              // content_id -> Vec<Flag>
+    Flag(u64),              // content_id -> Vec<Flag> (persistent)
     Moderation(u64),        // content_id -> Vec<ModerationAction>
     Dispute(u64),           // dispute_id -> Dispute
     DisputeCounter,         // u64
+
+    // Verifier registry keys
+    Deployer,                             // Address set at deployment, the only one allowed to initialize
+    Admin,                                // Address
+    Governance,                           // Address allowed to manage verifiers with the admin
+    StakeToken,                           // Address of the token verifiers stake
+    ReputationContract,                   // Address queried for verifier reputation
+    Verifier(Address),                    // verifier -> VerifierInfo
+    VerifiersByLevel(VerificationLevel),  // level -> Vec<Address>
+    MinStake(VerificationLevel),          // level -> i128
+    MinReputation(VerificationLevel),     // level -> u32
+    SlashRate,                            // u32, basis points of the stake slashed
+
+    // Verification expiry keys
+    ReminderWindow,                       // u64, seconds before expiry renewal reminders are emitted
+
+    // Licensing keys
+    ContentLicense(u64),                  // content_id -> ContentLicense
+    RevenueShares(u64),                   // content_id -> Vec<RevenueShare>
+    AccessPass(u64, Address),             // content_id, holder -> AccessPass (persistent)
+
+    // Removal keys
+    Tombstone(u64),                       // content_id -> Tombstone while the content is removed
+    AuditTrail(u64),                      // content_id -> Vec<AuditEntry>, append only (persistent)
+    TakedownNotice(u64),                  // notice_id -> TakedownNotice (persistent)
+    TakedownCounter,                      // u64
+    OpenTakedown(u64),                    // content_id -> notice_id of its unresolved takedown
+    ClaimantTakedown(u64, Address),       // content_id, claimant -> notice_id (persistent)
+
+    // Moderator keys
+    Moderator(Address),                   // moderator -> ModeratorInfo
+    Moderators,                           // Vec<Address> of active moderators
+    ModerationQuorum,                     // u32, removal votes needed to remove content
+    EscalationThreshold,                  // u32, flags that escalate content to moderators
+    EscalatedContent,                     // Vec<u64> content ids waiting for a moderator decision
+    RemovalVotes(u64),                    // content_id -> Vec<Address> pending removal votes
+    RemovalDeciders(u64),                 // content_id -> Vec<Address> whose votes removed the content
+}
+
+/// Registered moderator and its track record
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct ModeratorInfo {
+    pub moderator: Address,
+    pub added_at: u64,
+    pub removed_at: Option<u64>, // Set once removed from the registry
+    pub votes: u32,              // Removal votes cast
+    pub decisions: u32,          // Removals carried by its vote
+    pub overturned: u32,         // Removals it carried that were reinstated on appeal
+    pub appeals_resolved: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+pub enum TombstoneReason {
+    Moderation,     // Removed by a moderator
+    CreatorDeleted, // Deleted by its creator, cannot be reinstated
+    Takedown,       // Taken down on a rights holder's notice
+}
+
+/// What remains visible of a removed content
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct Tombstone {
+    pub content_id: u64,
+    pub content_hash: BytesN<32>,
+    pub reason: TombstoneReason,
+    pub removed_by: Address,
+    pub removed_at: u64,
+    pub notice_id: Option<u64>, // Takedown notice that removed the content
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+pub enum AuditAction {
+    Removed,
+    Deleted,
+    TakedownFiled,
+    CounterNoticeFiled,
+    TakedownWithdrawn,
+    TakedownUpheld,
+    TakedownRejected,
+    Reinstated,
+}
+
+/// Step of a content's removal history
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct AuditEntry {
+    pub content_id: u64,
+    pub action: AuditAction,
+    pub actor: Address,
+    pub reference: Option<u64>, // Takedown notice or dispute id
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+pub enum TakedownStatus {
+    Filed,     // Waiting for admin review, content still visible
+    Active,    // Content removed, waiting for a counter-notice
+    Countered, // Counter-notice filed, content reinstated after the delay
+    Withdrawn,
+    Upheld,    // Claimant took action before reinstatement, content stays removed
+    Reinstated,
+    Rejected,  // Turned down on review, content never removed
+}
+
+/// DMCA-style takedown notice filed by a rights holder
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct TakedownNotice {
+    pub notice_id: u64,
+    pub content_id: u64,
+    pub claimant: Address,
+    pub claim: String,
+    pub filed_at: u64,
+    pub counter_deadline: u64,          // Last time the creator can file a counter-notice, set on approval
+    pub counter_notice: Option<String>,
+    pub reinstate_at: Option<u64>,      // Time the content is reinstated unless the takedown is upheld
+    pub status: TakedownStatus,
+}
+
+/// License a content is published under
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum License {
+    CcBy,
+    CcByNc,
+    AllRightsReserved,
+    Custom(String), // URI of the license terms
+}
+
+/// License of a content and price of its access passes, paid in a SEP-41 token
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ContentLicense {
+    pub license: License,
+    pub token: Option<Address>, // Token passes are paid in, required when priced
+    pub price: i128,            // 0 when access is free
+    pub duration: Option<u64>,  // Seconds of access per purchase, None for perpetual access
+}
+
+/// Access bought by a user
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AccessPass {
+    pub content_id: u64,
+    pub holder: Address,
+    pub purchased_at: u64,
+    pub expires_at: Option<u64>, // None for perpetual access
+    pub total_paid: i128,
+}
+
+/// Share of the sales paid to a collaborator, the creator gets the rest
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RevenueShare {
+    pub recipient: Address,
+    pub share: u32, // basis points
 }
 
 /// ANALYTICS STORAGE STRUCTURES
@@ -191,6 +459,23 @@ pub struct ContentAnalytics {
     pub average_rating: u32,  // 0-50000 (scaled to avoid decimals, 50000 = 5.0)
     pub trending_score: u32,  // Scaled trending score
     pub last_updated: u64,
+    pub unique_viewers: u64,  // Distinct addresses that viewed the content
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EngagementKind {
+    View = 0,
+    Upvote = 1,
+    Downvote = 2,
+}
+
+// Deduplication and rate limiting of engagement recording
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EngagementConfig {
+    pub bucket_secs: u64,     // Length of a time bucket; a viewer counts once per kind per bucket
+    pub max_per_bucket: u32,  // Engagements one address can record per bucket across all content
 }
 
 #[contracttype]
@@ -242,6 +527,15 @@ pub enum TrendingPeriod {
     Monthly = 2,
 }
 
+/// Decayed engagement score of a content, as of `updated_at`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LeaderboardEntry {
+    pub content_id: u64,
+    pub score: u64,
+    pub updated_at: u64,
+}
+
 #[contracttype]
 pub struct TrendingSnapshot {
     pub period: TrendingPeriod,
@@ -264,14 +558,27 @@ pub fn save_content(env: &Env, content: &Content) {
     env.storage().instance().set(&key, content);
 }
 
-// Retrieve content from contract storage
+// Retrieve content from contract storage. Removed content is hidden.
 pub fn get_content(env: &Env, content_id: u64) -> Content {
+    if is_removed(env, content_id) {
+        panic!("content removed");
+    }
+    get_content_record(env, content_id)
+}
+
+// Retrieve content whether or not it was removed
+pub fn get_content_record(env: &Env, content_id: u64) -> Content {
     let key = DataKey::Content(content_id);
     env.storage().instance().get(&key).unwrap_or_else(|| {
         panic!("content with ID {} not found", content_id)
     })
 }
 
+// Check if content is tombstoned
+pub fn is_removed(env: &Env, content_id: u64) -> bool {
+    env.storage().instance().has(&AdvDataKey::Tombstone(content_id))
+}
+
 // Record a user's vote for a specific content
 pub fn record_user_vote(env: &Env, voter: Address, content_id: u64) {
     let key = DataKey::UserVotes(voter, content_id);
@@ -296,13 +603,13 @@ pub fn content_exists(env: &Env, content_id: u64) -> bool {
     env.storage().instance().has(&key)
 }
 
-// Get all existing content IDs (for filtering operations)
+// Get all existing content IDs (for filtering operations), skipping removed content
 pub fn get_all_content_ids(env: &Env) -> Vec<u64> {
     let mut content_ids = Vec::new(env);
     let total_content = get_content_counter(env);
 
     for id in 0..total_content {
-        if content_exists(env, id) {
+        if content_exists(env, id) && !is_removed(env, id) {
             content_ids.push_back(id);
         }
     }
@@ -317,7 +624,7 @@ pub fn get_multiple_content(env: &Env, content_ids: &Vec<u64>) -> Vec<Content> {
 
     for i in 0..content_ids.len() {
         let content_id = content_ids.get(i).unwrap();
-        if content_exists(env, content_id) {
+        if content_exists(env, content_id) && !is_removed(env, content_id) {
             let content = get_content(env, content_id);
             contents.push_back(content);
         }
@@ -369,6 +676,27 @@ pub fn save_version_count(env: &Env, content_id: u64, count: u32) {
     env.storage().instance().set(&key, &count);
 }
 
+// Save the origin of a forked content and index the fork under its source
+pub fn save_content_origin(env: &Env, fork_id: u64, origin: &ContentOrigin) {
+    env.storage().instance().set(&DataKey::ContentOrigin(fork_id), origin);
+
+    let mut forks = get_content_forks(env, origin.content_id);
+    forks.push_back(fork_id);
+    env.storage().instance().set(&DataKey::ContentForks(origin.content_id), &forks);
+}
+
+// Get the origin of a forked content
+pub fn get_content_origin(env: &Env, content_id: u64) -> Option<ContentOrigin> {
+    let key = DataKey::ContentOrigin(content_id);
+    env.storage().instance().get(&key)
+}
+
+// Get the contents forked from a content
+pub fn get_content_forks(env: &Env, content_id: u64) -> Vec<u64> {
+    let key = DataKey::ContentForks(content_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
 // Check if user voted on specific version
 pub fn has_user_voted_on_version(env: &Env, voter: &Address, content_id: u64, version: u32) -> bool {
     let key = DataKey::VersionVotes(voter.clone(), content_id, version);
@@ -390,6 +718,12 @@ pub fn save_collaborative_permission(env: &Env, user: Address, content_id: u64,
     env.storage().instance().set(&key, permission);
 }
 
+/// Get user permission for content, if any
+pub fn find_collaborative_permission(env: &Env, user: &Address, content_id: u64) -> Option<CollaboratorPermission> {
+    let key = DataKey::CollaboratorPermission(user.clone(), content_id);
+    env.storage().instance().get(&key)
+}
+
 /// Get user permission for content
 pub fn get_collaborative_permission(env: &Env, user: &Address, content_id: u64) -> CollaboratorPermission {
     let key = DataKey::CollaboratorPermission(user.clone(), content_id);
@@ -398,18 +732,111 @@ pub fn get_collaborative_permission(env: &Env, user: &Address, content_id: u64)
     })
 }
 
+/// Get the next submission ID and increment the counter
+pub fn get_next_submission_id(env: &Env) -> u64 {
+    let key = DataKey::SubmissionCounter;
+    let id = env.storage().instance().get(&key).unwrap_or(0u64);
+    env.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+// Get the next collection ID and increment the counter
+pub fn get_next_collection_id(env: &Env) -> u64 {
+    let key = DataKey::CollectionCounter;
+    let id = env.storage().instance().get(&key).unwrap_or(0u64);
+    env.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+pub fn save_collection(env: &Env, collection: &Collection) {
+    let key = DataKey::Collection(collection.id);
+    env.storage().instance().set(&key, collection);
+}
+
+pub fn get_collection(env: &Env, collection_id: u64) -> Collection {
+    let key = DataKey::Collection(collection_id);
+    env.storage().instance().get(&key).unwrap_or_else(|| {
+        panic!("collection not found")
+    })
+}
+
+/// Save a curation permission; `content_id` of the permission holds the collection id
+pub fn save_collection_curator(env: &Env, curator: Address, collection_id: u64, permission: &CollaboratorPermission) {
+    let key = DataKey::CollectionCurator(curator, collection_id);
+    env.storage().instance().set(&key, permission);
+}
+
+pub fn get_collection_curator(env: &Env, curator: &Address, collection_id: u64) -> Option<CollaboratorPermission> {
+    let key = DataKey::CollectionCurator(curator.clone(), collection_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn remove_collection_curator(env: &Env, curator: &Address, collection_id: u64) {
+    let key = DataKey::CollectionCurator(curator.clone(), collection_id);
+    env.storage().instance().remove(&key);
+}
+
 /// Save collaborator submission
-pub fn save_collaborative_submission(env: &Env, submitter: Address, content_id: u64, submission: &CollaboratorSubmission) {
-    let key = DataKey::CollaboratorSubmission(submitter, content_id);
+pub fn save_submission(env: &Env, submission: &CollaboratorSubmission) {
+    let key = DataKey::Submission(submission.submission_id);
     env.storage().instance().set(&key, submission);
 }
 
-/// Get collaborator submission
+/// Get submission by ID
+pub fn get_submission(env: &Env, submission_id: u64) -> CollaboratorSubmission {
+    let key = DataKey::Submission(submission_id);
+    env.storage().instance().get(&key).unwrap_or_else(|| {
+        panic!("submission not found")
+    })
+}
+
+/// Index a new submission by content and as the submitter's latest
+pub fn index_submission(env: &Env, submission: &CollaboratorSubmission) {
+    let key = DataKey::CollaboratorSubmission(submission.collaborator.clone(), submission.content_id);
+    env.storage().instance().set(&key, &submission.submission_id);
+
+    let mut ids = get_content_submissions(env, submission.content_id);
+    ids.push_back(submission.submission_id);
+    env.storage().instance().set(&DataKey::ContentSubmissions(submission.content_id), &ids);
+}
+
+/// Get the submission IDs of a content
+pub fn get_content_submissions(env: &Env, content_id: u64) -> Vec<u64> {
+    let key = DataKey::ContentSubmissions(content_id);
+    env.storage().instance().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the latest submission of a collaborator for a content
 pub fn get_collaborative_submission(env: &Env, submitter: &Address, content_id: u64) -> CollaboratorSubmission {
     let key = DataKey::CollaboratorSubmission(submitter.clone(), content_id);
-    env.storage().instance().get(&key).unwrap_or_else(|| {
+    let submission_id: u64 = env.storage().instance().get(&key).unwrap_or_else(|| {
         panic!("submission not found for submitter and content_id")
-    })
+    });
+    get_submission(env, submission_id)
+}
+
+/// Get the addresses granted the reviewer role on a content
+pub fn get_content_reviewers(env: &Env, content_id: u64) -> Vec<Address> {
+    let key = DataKey::ContentReviewers(content_id);
+    env.storage().instance().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the reviewers of a content
+pub fn save_content_reviewers(env: &Env, content_id: u64, reviewers: &Vec<Address>) {
+    let key = DataKey::ContentReviewers(content_id);
+    env.storage().instance().set(&key, reviewers);
+}
+
+/// Get the approvals needed to merge a submission (1 by default)
+pub fn get_required_approvals(env: &Env, content_id: u64) -> u32 {
+    let key = DataKey::RequiredApprovals(content_id);
+    env.storage().instance().get(&key).unwrap_or(1)
+}
+
+/// Save the approvals needed to merge a submission
+pub fn save_required_approvals(env: &Env, content_id: u64, required: u32) {
+    let key = DataKey::RequiredApprovals(content_id);
+    env.storage().instance().set(&key, &required);
 }
 
 /// Save contribution to user history
@@ -490,6 +917,38 @@ pub fn get_trending_snapshot(env: &Env, period: TrendingPeriod, timestamp: u64)
     env.storage().instance().get(&key)
 }
 
+/// Save the decayed score of a content for a period
+pub fn save_trending_score(env: &Env, period: TrendingPeriod, entry: &LeaderboardEntry) {
+    let key = DataKey::TrendingScore(entry.content_id, period);
+    env.storage().persistent().set(&key, entry);
+}
+
+/// Get the decayed score of a content for a period
+pub fn get_trending_score(env: &Env, content_id: u64, period: TrendingPeriod) -> Option<LeaderboardEntry> {
+    let key = DataKey::TrendingScore(content_id, period);
+    env.storage().persistent().get(&key)
+}
+
+/// Save a leaderboard (global when no category is given)
+pub fn save_leaderboard(env: &Env, category: Option<String>, period: TrendingPeriod, entries: &Vec<LeaderboardEntry>) {
+    env.storage().persistent().set(&leaderboard_key(category, period), entries);
+}
+
+/// Get a leaderboard (global when no category is given), highest score first
+pub fn get_leaderboard(env: &Env, category: Option<String>, period: TrendingPeriod) -> Vec<LeaderboardEntry> {
+    env.storage()
+        .persistent()
+        .get(&leaderboard_key(category, period))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn leaderboard_key(category: Option<String>, period: TrendingPeriod) -> DataKey {
+    match category {
+        Some(category) => DataKey::CategoryLeaderboard(category, period),
+        None => DataKey::Leaderboard(period),
+    }
+}
+
 /// Get analytics counter
 #[allow(dead_code)]
 pub fn get_analytics_counter(env: &Env) -> u64 {
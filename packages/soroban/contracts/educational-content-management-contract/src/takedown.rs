@@ -0,0 +1,182 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+use crate::storage::{get_content, get_content_record, AdvDataKey, AuditAction, TakedownNotice, TakedownStatus, TombstoneReason};
+use crate::{moderation, verifier};
+
+/// Time the creator has to file a counter-notice after a takedown
+pub const COUNTER_NOTICE_WINDOW: u64 = 14 * 24 * 60 * 60;
+/// Time after a counter-notice before the content is reinstated, during which
+/// the claimant can have the takedown upheld
+pub const REINSTATEMENT_DELAY: u64 = 14 * 24 * 60 * 60;
+
+// File a takedown notice against a content. The content stays up until the admin
+// or governance approves the notice. A content has one pending notice at a time
+// and a claimant files one notice per content.
+pub fn file_takedown(env: &Env, content_id: u64, claimant: Address, claim: String) -> u64 {
+    let content = get_content(env, content_id);
+    if content.creator == claimant {
+        panic!("cannot file a takedown against own content");
+    }
+    if env.storage().instance().has(&AdvDataKey::OpenTakedown(content_id)) {
+        panic!("content already has a pending takedown");
+    }
+    let claimant_key = AdvDataKey::ClaimantTakedown(content_id, claimant.clone());
+    if env.storage().persistent().has(&claimant_key) {
+        panic!("takedown already filed for this content");
+    }
+
+    let notice_id = env.storage().instance().get(&AdvDataKey::TakedownCounter).unwrap_or(0u64);
+    let notice = TakedownNotice {
+        notice_id,
+        content_id,
+        claimant: claimant.clone(),
+        claim,
+        filed_at: env.ledger().timestamp(),
+        counter_deadline: 0,
+        counter_notice: None,
+        reinstate_at: None,
+        status: TakedownStatus::Filed,
+    };
+    save_notice(env, &notice);
+    env.storage().instance().set(&AdvDataKey::TakedownCounter, &(notice_id + 1));
+    env.storage().instance().set(&AdvDataKey::OpenTakedown(content_id), &notice_id);
+    env.storage().persistent().set(&claimant_key, &notice_id);
+
+    moderation::record_audit(env, content_id, AuditAction::TakedownFiled, claimant.clone(), Some(notice_id));
+    env.events().publish((symbol_short!("TAKEDOWN"), content_id, claimant), notice_id);
+    notice_id
+}
+
+// Approve a filed notice, which removes the content and opens the counter-notice
+// window, or reject it (admin or governance)
+pub fn review_takedown(env: &Env, notice_id: u64, manager: Address, approve: bool) {
+    verifier::require_manager(env, &manager);
+    let mut notice = get_notice(env, notice_id);
+    if notice.status != TakedownStatus::Filed {
+        panic!("takedown is not waiting for review");
+    }
+
+    if approve {
+        let content = get_content(env, notice.content_id);
+        notice.counter_deadline = env.ledger().timestamp() + COUNTER_NOTICE_WINDOW;
+        notice.status = TakedownStatus::Active;
+        save_notice(env, &notice);
+        moderation::tombstone(env, &content, TombstoneReason::Takedown, notice.claimant.clone(), Some(notice_id));
+    } else {
+        notice.status = TakedownStatus::Rejected;
+        save_notice(env, &notice);
+        close_notice(env, &notice);
+        moderation::record_audit(env, notice.content_id, AuditAction::TakedownRejected, manager, Some(notice_id));
+    }
+}
+
+// Creator's counter-notice, starts the reinstatement delay
+pub fn file_counter_notice(env: &Env, notice_id: u64, creator: Address, statement: String) {
+    let mut notice = get_notice(env, notice_id);
+    if get_content_record(env, notice.content_id).creator != creator {
+        panic!("only the creator can file a counter-notice");
+    }
+    if notice.status != TakedownStatus::Active {
+        panic!("takedown is not active");
+    }
+    let now = env.ledger().timestamp();
+    if now > notice.counter_deadline {
+        panic!("counter-notice deadline passed");
+    }
+
+    notice.counter_notice = Some(statement);
+    notice.reinstate_at = Some(now + REINSTATEMENT_DELAY);
+    notice.status = TakedownStatus::Countered;
+    save_notice(env, &notice);
+
+    moderation::record_audit(env, notice.content_id, AuditAction::CounterNoticeFiled, creator, Some(notice_id));
+    env.events().publish((symbol_short!("COUNTER"), notice.content_id), (notice_id, notice.reinstate_at));
+}
+
+// Claimant withdraws its notice, the content is reinstated
+pub fn withdraw_takedown(env: &Env, notice_id: u64, claimant: Address) {
+    let mut notice = get_notice(env, notice_id);
+    if notice.claimant != claimant {
+        panic!("only the claimant can withdraw a takedown");
+    }
+    let removed = match notice.status {
+        TakedownStatus::Filed => false,
+        TakedownStatus::Active | TakedownStatus::Countered => true,
+        _ => panic!("takedown is not pending"),
+    };
+
+    notice.status = TakedownStatus::Withdrawn;
+    save_notice(env, &notice);
+    close_notice(env, &notice);
+
+    moderation::record_audit(env, notice.content_id, AuditAction::TakedownWithdrawn, claimant.clone(), Some(notice_id));
+    if removed {
+        moderation::reinstate(env, notice.content_id, claimant, Some(notice_id));
+    }
+}
+
+// Keep the content removed after a counter-notice, once the claimant showed it
+// took action (admin or governance)
+pub fn uphold_takedown(env: &Env, notice_id: u64, manager: Address) {
+    verifier::require_manager(env, &manager);
+    let mut notice = get_notice(env, notice_id);
+    if notice.status != TakedownStatus::Countered {
+        panic!("takedown was not countered");
+    }
+    if notice.reinstate_at.is_some_and(|reinstate_at| env.ledger().timestamp() >= reinstate_at) {
+        panic!("reinstatement deadline passed");
+    }
+
+    notice.status = TakedownStatus::Upheld;
+    save_notice(env, &notice);
+    close_notice(env, &notice);
+    moderation::record_audit(env, notice.content_id, AuditAction::TakedownUpheld, manager, Some(notice_id));
+}
+
+// Keeper entry point: uphold an approved takedown the creator did not counter
+// before the counter-notice deadline
+pub fn settle_takedown(env: &Env, notice_id: u64) {
+    let mut notice = get_notice(env, notice_id);
+    if notice.status != TakedownStatus::Active {
+        panic!("takedown is not active");
+    }
+    if env.ledger().timestamp() <= notice.counter_deadline {
+        panic!("counter-notice deadline not passed");
+    }
+
+    notice.status = TakedownStatus::Upheld;
+    save_notice(env, &notice);
+    close_notice(env, &notice);
+    moderation::record_audit(env, notice.content_id, AuditAction::TakedownUpheld, env.current_contract_address(), Some(notice_id));
+}
+
+// Keeper entry point: reinstate countered content once the delay is over
+pub fn reinstate_content(env: &Env, notice_id: u64) {
+    let mut notice = get_notice(env, notice_id);
+    if notice.status != TakedownStatus::Countered {
+        panic!("takedown was not countered");
+    }
+    if notice.reinstate_at.is_some_and(|reinstate_at| env.ledger().timestamp() < reinstate_at) {
+        panic!("reinstatement deadline not reached");
+    }
+
+    notice.status = TakedownStatus::Reinstated;
+    save_notice(env, &notice);
+    close_notice(env, &notice);
+    moderation::reinstate(env, notice.content_id, env.current_contract_address(), Some(notice_id));
+}
+
+pub fn get_notice(env: &Env, notice_id: u64) -> TakedownNotice {
+    env.storage()
+        .persistent()
+        .get(&AdvDataKey::TakedownNotice(notice_id))
+        .unwrap_or_else(|| panic!("takedown notice not found"))
+}
+
+fn save_notice(env: &Env, notice: &TakedownNotice) {
+    env.storage().persistent().set(&AdvDataKey::TakedownNotice(notice.notice_id), notice);
+}
+
+// A resolved notice no longer blocks new notices against its content
+fn close_notice(env: &Env, notice: &TakedownNotice) {
+    env.storage().instance().remove(&AdvDataKey::OpenTakedown(notice.content_id));
+}
@@ -1,13 +1,66 @@
 use crate::{TokenizedEducationalContent, TokenizedEducationalContentClient, VerificationLevel};
+use crate::storage::{
+    AuditAction, ContentAnalytics, ContentLicense, EngagementConfig, License, ModerationStatus, ReviewStatus, RevenueShare, TakedownStatus,
+    TombstoneReason,
+};
 use soroban_sdk::{
-    testutils::{Address as AddressTrait, BytesN as _},
-    Address, BytesN, Env, String, vec,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as AddressTrait, BytesN as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env, String, Vec, vec,
 };
 
+// Reputation contract stand-in returning scores set by the tests
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn set_reputation(env: Env, user: Address, score: u32) {
+        env.storage().instance().set(&(symbol_short!("REP"), user), &score);
+    }
+
+    pub fn get_reputation(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&(symbol_short!("REP"), user)).unwrap_or(0)
+    }
+}
+
+struct VerificationSetup {
+    admin: Address,
+    stake_token: Address,
+    reputation: Address,
+}
+
+// Initialize the verifier registry with a stake token and a mock reputation contract
+fn setup_verification(env: &Env, client: &TokenizedEducationalContentClient) -> VerificationSetup {
+    let admin = Address::generate(env);
+    let stake_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let reputation = env.register(MockReputation, ());
+    client.initialize(&admin, &stake_token, &reputation);
+    VerificationSetup { admin, stake_token, reputation }
+}
+
+// Register a verifier able to verify at every level
+fn register_verifier(client: &TokenizedEducationalContentClient, admin: &Address, verifier: &Address) {
+    client.add_verifier(admin, verifier, &VerificationLevel::Institutional);
+}
+
+// Set up the registry and register `count` moderators
+fn setup_moderators(env: &Env, client: &TokenizedEducationalContentClient, count: u32) -> (Address, Vec<Address>) {
+    let admin = setup_verification(env, client).admin;
+    let mut moderators = Vec::new(env);
+    for _ in 0..count {
+        let moderator = Address::generate(env);
+        client.add_moderator(&admin, &moderator);
+        moderators.push_back(moderator);
+    }
+    (admin, moderators)
+}
+
 #[test]
 fn test_publish_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     let creator = Address::generate(&env);
@@ -39,7 +92,7 @@ fn test_publish_content() {
 #[test]
 fn test_multiple_content_publish() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     let creator = Address::generate(&env);
@@ -81,7 +134,7 @@ fn test_multiple_content_publish() {
 #[test]
 fn test_publish_empty_tags() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     let creator = Address::generate(&env);
@@ -103,7 +156,7 @@ fn test_publish_empty_tags() {
 #[test]
 fn test_upvote_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication for all users
@@ -137,7 +190,7 @@ fn test_upvote_content() {
 #[test]
 fn test_multiple_upvotes() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication for all users
@@ -171,7 +224,7 @@ fn test_multiple_upvotes() {
 #[should_panic(expected = "user has already voted for this content")]
 fn test_duplicate_upvote() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication for all users
@@ -199,7 +252,7 @@ fn test_duplicate_upvote() {
 #[should_panic(expected = "content with ID")]
 fn test_upvote_nonexistent_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -213,7 +266,7 @@ fn test_upvote_nonexistent_content() {
 #[test]
 fn test_verify_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -236,6 +289,8 @@ fn test_verify_content() {
 
     // Verify content to Peer level
     let verifier = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     let verified_level = client.verify_content(&content_id, &verifier, &VerificationLevel::Peer);
     assert_eq!(verified_level, VerificationLevel::Peer);
 
@@ -247,7 +302,7 @@ fn test_verify_content() {
 #[test]
 fn test_multiple_verifications() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -265,11 +320,14 @@ fn test_multiple_verifications() {
 
     // First verification (to Peer)
     let verifier1 = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier1);
     let verified_level1 = client.verify_content(&content_id, &verifier1, &VerificationLevel::Peer);
     assert_eq!(verified_level1, VerificationLevel::Peer);
 
     // Second verification (upgrade to Expert)
     let verifier2 = Address::generate(&env);
+    register_verifier(&client, &verification.admin, &verifier2);
     let verified_level2 = client.verify_content(&content_id, &verifier2, &VerificationLevel::Expert);
     assert_eq!(verified_level2, VerificationLevel::Expert);
 
@@ -282,7 +340,7 @@ fn test_multiple_verifications() {
 #[should_panic(expected = "content with ID")]
 fn test_verify_nonexistent_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -296,7 +354,7 @@ fn test_verify_nonexistent_content() {
 #[test]
 fn test_creator_can_verify_own_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -313,6 +371,8 @@ fn test_creator_can_verify_own_content() {
     let content_id = client.publish_content(&creator, &title, &content_hash, &subject_tags);
 
      // MODIFIED: Call verify_content with a specific level
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &creator);
     client.verify_content(&content_id, &creator, &VerificationLevel::Peer);
 
     let content = client.get_content(&content_id);
@@ -323,7 +383,7 @@ fn test_creator_can_verify_own_content() {
 #[test]
 fn test_content_with_long_title_and_many_tags() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -383,7 +443,7 @@ fn test_content_with_long_title_and_many_tags() {
 #[test]
 fn test_multiple_content_and_popularity_tracking() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -470,7 +530,7 @@ fn test_multiple_content_and_popularity_tracking() {
 #[test]
 fn test_verify_before_and_after_upvotes() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -490,6 +550,8 @@ fn test_verify_before_and_after_upvotes() {
     
     // Scenario 1: Verify first, then upvote
     let verifier = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_content(&content_id, &verifier, &VerificationLevel::Expert);
     
     // Check content is verified
@@ -545,7 +607,7 @@ fn test_verify_before_and_after_upvotes() {
 #[test]
 fn test_complex_workflow() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -599,6 +661,8 @@ fn test_complex_workflow() {
     
     // 3. Verify only content 2
     let verifier = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
      client.verify_content(&content_id2, &verifier, &VerificationLevel::Institutional);
     
     // 4. Retrieve and check both contents
@@ -617,7 +681,7 @@ fn test_complex_workflow() {
 #[test]
 fn test_filter_by_verification_empty_results() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -643,7 +707,7 @@ fn test_filter_by_verification_empty_results() {
 #[test]
 fn test_filter_by_verification_mixed_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -660,6 +724,8 @@ fn test_filter_by_verification_mixed_content() {
         &BytesN::random(&env),
         &vec![&env, String::from_str(&env, "verified")]
     );
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_content(&content_id1, &verifier, &VerificationLevel::Peer);
 
     // Content 2: Not verified
@@ -717,7 +783,7 @@ fn test_filter_by_verification_mixed_content() {
 #[test]
 fn test_filter_by_min_upvotes_empty_results() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -750,7 +816,7 @@ fn test_filter_by_min_upvotes_empty_results() {
 #[test]
 fn test_filter_by_min_upvotes_various_thresholds() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -830,7 +896,7 @@ fn test_filter_by_min_upvotes_various_thresholds() {
 #[test]
 fn test_filter_combinations_verified_and_popular() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -848,6 +914,8 @@ fn test_filter_combinations_verified_and_popular() {
         &BytesN::random(&env),
         &vec![&env, String::from_str(&env, "best")]
     );
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
      client.verify_content(&content_id1, &verifier, &VerificationLevel::Institutional);
     for _ in 0..10 {
         let voter = Address::generate(&env);
@@ -944,7 +1012,7 @@ fn test_filter_combinations_verified_and_popular() {
 #[test]
 fn test_filters_with_large_dataset() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -959,6 +1027,8 @@ fn test_filters_with_large_dataset() {
     let mut expected_popular_5 = 0u32;
     let mut expected_popular_10 = 0u32;
 
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     for i in 0..20 {
         let title = match i {
             0 => String::from_str(&env, "Content 00"),
@@ -1050,7 +1120,7 @@ fn test_filters_with_large_dataset() {
 #[test]
 fn test_filter_by_verification_level() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -1060,6 +1130,8 @@ fn test_filter_by_verification_level() {
 
     // Content 1: Peer verified
     let content_id1 = client.publish_content(&creator, &String::from_str(&env, "Peer Verified"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_content(&content_id1, &verifier, &VerificationLevel::Peer);
 
     // Content 2: Not verified
@@ -1096,7 +1168,7 @@ fn test_filter_by_verification_level() {
 #[test]
 fn test_verification_tier_upgrade() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -1108,6 +1180,8 @@ fn test_verification_tier_upgrade() {
     assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::None);
 
     // 1. Verify to Peer
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     let level = client.verify_content(&content_id, &verifier, &VerificationLevel::Peer);
     assert_eq!(level, VerificationLevel::Peer);
     assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::Peer);
@@ -1127,7 +1201,7 @@ fn test_verification_tier_upgrade() {
 #[should_panic(expected = "cannot overwrite a higher or equal verification level")]
 fn test_prevent_verification_downgrade() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -1136,6 +1210,8 @@ fn test_prevent_verification_downgrade() {
     let verifier = Address::generate(&env);
     let content_id = client.publish_content(&creator, &String::from_str(&env, "Test Downgrade"), &BytesN::random(&env), &vec![&env]);
 
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_content(&content_id, &verifier, &VerificationLevel::Institutional);
     client.verify_content(&content_id, &verifier, &VerificationLevel::Peer); // Should panic
 }
@@ -1144,7 +1220,7 @@ fn test_prevent_verification_downgrade() {
 #[should_panic(expected = "cannot overwrite a higher or equal verification level")]
 fn test_prevent_same_level_verification() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     // Configure authentication
@@ -1153,6 +1229,8 @@ fn test_prevent_same_level_verification() {
     let verifier = Address::generate(&env);
     let content_id = client.publish_content(&creator, &String::from_str(&env, "Test Same Level"), &BytesN::random(&env), &vec![&env]);
 
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_content(&content_id, &verifier, &VerificationLevel::Expert);
     client.verify_content(&content_id, &verifier, &VerificationLevel::Expert); // Should panic
 }
@@ -1163,7 +1241,7 @@ fn test_prevent_same_level_verification() {
 #[test]
 fn test_create_new_version_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1203,7 +1281,7 @@ fn test_create_new_version_content() {
 #[test]
 fn test_create_multiple_versions() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1248,7 +1326,7 @@ fn test_create_multiple_versions() {
 #[test]
 fn test_get_content_at_version() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1292,7 +1370,7 @@ fn test_get_content_at_version() {
 #[test]
 fn test_get_version_info() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1331,7 +1409,7 @@ fn test_get_version_info() {
 #[test]
 fn test_upvote_version() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1373,7 +1451,7 @@ fn test_upvote_version() {
 #[test]
 fn test_verify_version() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1399,6 +1477,8 @@ fn test_verify_version() {
     );
 
     // Verify the version
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     let verified_level = client.verify_version(&content_id, &version, &verifier, &VerificationLevel::Expert);
     assert_eq!(verified_level, VerificationLevel::Expert);
 
@@ -1410,7 +1490,7 @@ fn test_verify_version() {
 #[test]
 fn test_get_version_diff() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1474,10 +1554,218 @@ fn test_get_version_diff() {
     assert!(diff_0_to_2.content_changed);
 }
 
+#[test]
+#[should_panic(expected = "version diff spans too many versions")]
+fn test_version_diff_span_is_capped() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let title = String::from_str(&env, "Long History");
+    let content_id = client.publish_content(&creator, &title, &BytesN::random(&env), &vec![&env]);
+    for _ in 0..crate::versioning::MAX_DIFF_VERSIONS {
+        client.create_new_version_content(&content_id, &creator, &title, &BytesN::random(&env), &vec![&env], &String::from_str(&env, "edit"));
+    }
+
+    assert_eq!(client.get_version_diff(&content_id, &1, &50).versions.len(), 50);
+    client.get_version_diff(&content_id, &50, &0);
+}
+
+#[test]
+fn test_version_diff_details() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let content_id = client.publish_content(
+        &creator,
+        &String::from_str(&env, "Diffed"),
+        &BytesN::random(&env),
+        &vec![&env, String::from_str(&env, "math"), String::from_str(&env, "basics")]
+    );
+
+    // Version 1 by the creator
+    client.create_new_version_content(
+        &content_id,
+        &creator,
+        &String::from_str(&env, "Diffed"),
+        &BytesN::random(&env),
+        &vec![&env, String::from_str(&env, "math")],
+        &String::from_str(&env, "Drop a tag")
+    );
+
+    // Version 2 merged from a collaborator
+    client.grant_permission(&content_id, &creator, &collaborator);
+    let submission_id = client.submit_for_review(
+        &content_id,
+        &collaborator,
+        &BytesN::random(&env),
+        &vec![&env, String::from_str(&env, "math"), String::from_str(&env, "algebra")],
+        &String::from_str(&env, "Add algebra")
+    );
+    client.review_submission(&submission_id, &creator, &true, &String::from_str(&env, "Thanks"));
+
+    client.upvote_version(&content_id, &2, &Address::generate(&env));
+    client.upvote_version(&content_id, &2, &Address::generate(&env));
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.verify_version(&content_id, &2, &verifier, &VerificationLevel::Expert);
+
+    let diff = client.get_version_diff(&content_id, &0, &2);
+    assert_eq!(diff.tags_added, vec![&env, String::from_str(&env, "algebra")]);
+    assert_eq!(diff.tags_removed, vec![&env, String::from_str(&env, "basics")]);
+    assert_eq!(diff.from_verification_level, VerificationLevel::None);
+    assert_eq!(diff.to_verification_level, VerificationLevel::Expert);
+    assert_eq!(diff.upvote_delta, 2);
+    assert_eq!(diff.versions, vec![&env, 0, 1, 2]);
+    assert_eq!(diff.authors, vec![&env, creator.clone(), creator.clone(), collaborator.clone()]);
+
+    // Diffs work backwards too
+    let diff = client.get_version_diff(&content_id, &2, &1);
+    assert_eq!(diff.versions, vec![&env, 2, 1]);
+    assert_eq!(diff.upvote_delta, -2);
+    assert_eq!(diff.tags_removed, vec![&env, String::from_str(&env, "algebra")]);
+}
+
+#[test]
+fn test_revert_to_version() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let original_hash = BytesN::random(&env);
+    let content_id = client.publish_content(
+        &creator,
+        &String::from_str(&env, "Original"),
+        &original_hash,
+        &vec![&env, String::from_str(&env, "original")]
+    );
+    client.create_new_version_content(
+        &content_id,
+        &creator,
+        &String::from_str(&env, "Broken"),
+        &BytesN::random(&env),
+        &vec![&env],
+        &String::from_str(&env, "Bad edit")
+    );
+
+    let new_version = client.revert_to_version(&content_id, &creator, &0);
+    assert_eq!(new_version, 2);
+
+    let content = client.get_content(&content_id);
+    assert_eq!(content.title, String::from_str(&env, "Original"));
+    assert_eq!(content.content_hash, original_hash);
+    assert_eq!(content.subject_tags, vec![&env, String::from_str(&env, "original")]);
+    assert_eq!(client.get_version_info(&content_id, &2).reverted_from, Some(0));
+
+    // The bad edit stays in the history
+    assert_eq!(client.get_content_at_version(&content_id, &1).title, String::from_str(&env, "Broken"));
+}
+
+#[test]
+#[should_panic(expected = "can only revert to an earlier version")]
+fn test_revert_to_head_version() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Original"), &BytesN::random(&env), &vec![&env]);
+    client.revert_to_version(&content_id, &creator, &0);
+}
+
+#[test]
+#[should_panic(expected = "only the creator can revert content")]
+fn test_revert_non_creator() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Original"), &BytesN::random(&env), &vec![&env]);
+    client.create_new_version_content(&content_id, &creator, &String::from_str(&env, "V1"), &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Edit"));
+    client.revert_to_version(&content_id, &Address::generate(&env), &0);
+}
+
+#[test]
+fn test_fork_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let forker = Address::generate(&env);
+    let original_hash = BytesN::random(&env);
+    let tags = vec![&env, String::from_str(&env, "physics")];
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Original"), &original_hash, &tags);
+    client.create_new_version_content(&content_id, &creator, &String::from_str(&env, "V1"), &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Edit"));
+    client.set_license(&content_id, &creator, &ContentLicense { license: License::CcBy, token: None, price: 0, duration: None });
+
+    // Fork the original version
+    let fork_id = client.fork_content(&content_id, &0, &forker, &String::from_str(&env, "Derivative"));
+    assert_ne!(fork_id, content_id);
+
+    let fork = client.get_content(&fork_id);
+    assert_eq!(fork.creator, forker);
+    assert_eq!(fork.title, String::from_str(&env, "Derivative"));
+    assert_eq!(fork.content_hash, original_hash);
+    assert_eq!(fork.subject_tags, tags);
+    assert_eq!(fork.upvotes, 0);
+    assert_eq!(fork.verification_level, VerificationLevel::None);
+
+    let origin = client.get_content_origin(&fork_id).unwrap();
+    assert_eq!(origin.content_id, content_id);
+    assert_eq!(origin.version, 0);
+    assert_eq!(client.get_content_forks(&content_id), vec![&env, fork_id]);
+    assert_eq!(client.get_content_origin(&content_id), None);
+}
+
+#[test]
+fn test_fork_requires_derivative_license_and_access() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let forker = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Reserved"), &BytesN::random(&env), &vec![&env]);
+    let title = String::from_str(&env, "Derivative");
+
+    // Unlicensed content is all rights reserved, but its creator can still fork it
+    assert!(client.try_fork_content(&content_id, &0, &forker, &title).is_err());
+    client.fork_content(&content_id, &0, &creator, &title);
+
+    // A paid license needs an access pass to fork
+    let token = setup_payment_token(&env, &forker, 1_000);
+    client.set_license(&content_id, &creator, &ContentLicense { license: License::CcByNc, token: Some(token), price: 1_000, duration: None });
+    assert!(client.try_fork_content(&content_id, &0, &forker, &title).is_err());
+    client.purchase_access(&content_id, &forker);
+    let fork_id = client.fork_content(&content_id, &0, &forker, &title);
+    assert_eq!(client.get_content(&fork_id).creator, forker);
+}
+
 #[test]
 fn test_version_independence() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1518,6 +1806,8 @@ fn test_version_independence() {
     client.upvote_version(&content_id, &version1, &voter2);
 
     // Verify version 2 only
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_version(&content_id, &version2, &verifier, &VerificationLevel::Expert);
 
     // Check that versions have independent stats
@@ -1534,7 +1824,7 @@ fn test_version_independence() {
 #[test]
 fn test_versioning_workflow() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1563,6 +1853,8 @@ fn test_versioning_workflow() {
     // 3. Vote and verify version 1
     let voter = Address::generate(&env);
     client.upvote_version(&content_id, &version1, &voter);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_version(&content_id, &version1, &verifier, &VerificationLevel::Expert);
 
     // 4. Create version 2
@@ -1601,7 +1893,7 @@ fn test_versioning_workflow() {
 #[should_panic(expected = "only the creator can create a new version")]
 fn test_create_version_non_creator() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1631,7 +1923,7 @@ fn test_create_version_non_creator() {
 #[should_panic(expected = "version does not exist")]
 fn test_get_content_at_nonexistent_version() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1652,7 +1944,7 @@ fn test_get_content_at_nonexistent_version() {
 #[should_panic(expected = "already voted on this version")]
 fn test_duplicate_version_vote() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1687,7 +1979,7 @@ fn test_duplicate_version_vote() {
 #[should_panic(expected = "cannot downgrade verification")]
 fn test_version_verification_downgrade() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1712,6 +2004,8 @@ fn test_version_verification_downgrade() {
     );
 
     // Verify to Expert level
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
     client.verify_version(&content_id, &version, &verifier, &VerificationLevel::Expert);
     
     // Try to downgrade to Peer - should panic
@@ -1724,7 +2018,7 @@ fn test_version_verification_downgrade() {
 #[test]
 fn test_grant_permission() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1754,7 +2048,7 @@ fn test_grant_permission() {
 #[test]
 fn test_submit_for_review() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1777,14 +2071,14 @@ fn test_submit_for_review() {
     let new_subject_tags = vec![&env, String::from_str(&env, "updated")];
     let change_notes = String::from_str(&env, "Fixed typos and added examples");
 
-    let success = client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &new_content_hash,
         &new_subject_tags,
         &change_notes
     );
-    assert!(success);
+    assert_eq!(submission_id, 0);
 
     // Verify submission was created
     let submission = client.get_collaborative_submission(&collaborator, &content_id);
@@ -1798,7 +2092,7 @@ fn test_submit_for_review() {
 #[test]
 fn test_review_submission_accept() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1820,7 +2114,7 @@ fn test_review_submission_accept() {
     let new_subject_tags = vec![&env, String::from_str(&env, "updated")];
     let change_notes = String::from_str(&env, "Improved content quality");
 
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &new_content_hash,
@@ -1830,14 +2124,13 @@ fn test_review_submission_accept() {
 
     // Accept the submission
     let feedback = String::from_str(&env, "Great improvements!");
-    let success = client.review_submission(
-        &content_id,
-        &collaborator,
+    let status = client.review_submission(
+        &submission_id,
         &creator,
         &true, // accept
         &feedback
     );
-    assert!(success);
+    assert_eq!(status, ReviewStatus::Accepted);
 
     // Verify submission was accepted and content was updated
     let submission = client.get_collaborative_submission(&collaborator, &content_id);
@@ -1854,7 +2147,7 @@ fn test_review_submission_accept() {
 #[test]
 fn test_review_submission_reject() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1876,7 +2169,7 @@ fn test_review_submission_reject() {
     let new_content_hash = BytesN::random(&env);
     let new_subject_tags = vec![&env, String::from_str(&env, "updated")];
 
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &new_content_hash,
@@ -1886,14 +2179,13 @@ fn test_review_submission_reject() {
 
     // Reject the submission
     let feedback = String::from_str(&env, "Needs more work");
-    let success = client.review_submission(
-        &content_id,
-        &collaborator,
+    let status = client.review_submission(
+        &submission_id,
         &creator,
         &false, // reject
         &feedback
     );
-    assert!(success);
+    assert_eq!(status, ReviewStatus::Rejected);
 
     // Verify submission was rejected
     let submission = client.get_collaborative_submission(&collaborator, &content_id);
@@ -1908,7 +2200,7 @@ fn test_review_submission_reject() {
 #[test]
 fn test_get_user_contribution_history() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -1927,7 +2219,7 @@ fn test_get_user_contribution_history() {
     client.grant_permission(&content_id, &creator, &collaborator);
 
     // Submit first contribution
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &BytesN::random(&env),
@@ -1937,15 +2229,14 @@ fn test_get_user_contribution_history() {
 
     // Review first contribution (accept)
     client.review_submission(
-        &content_id,
-        &collaborator,
+        &submission_id,
         &creator,
         &true,
         &String::from_str(&env, "Good work")
     );
 
     // Submit second contribution
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &BytesN::random(&env),
@@ -1955,8 +2246,7 @@ fn test_get_user_contribution_history() {
 
     // Review second contribution (reject)
     client.review_submission(
-        &content_id,
-        &collaborator,
+        &submission_id,
         &creator,
         &false,
         &String::from_str(&env, "Needs improvement")
@@ -1982,7 +2272,7 @@ fn test_get_user_contribution_history() {
 #[test]
 fn test_collaborative_workflow_complete() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2006,7 +2296,7 @@ fn test_collaborative_workflow_complete() {
 
     // 3. Collaborator1 submits improvement
     let improved_hash = BytesN::random(&env);
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator1,
         &improved_hash,
@@ -2016,8 +2306,7 @@ fn test_collaborative_workflow_complete() {
 
     // 4. Creator accepts collaborator1's submission
     client.review_submission(
-        &content_id,
-        &collaborator1,
+        &submission_id,
         &creator,
         &true,
         &String::from_str(&env, "Excellent improvements!")
@@ -2029,7 +2318,7 @@ fn test_collaborative_workflow_complete() {
 
     // 6. Collaborator2 submits another improvement
     let further_improved_hash = BytesN::random(&env);
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator2,
         &further_improved_hash,
@@ -2039,8 +2328,7 @@ fn test_collaborative_workflow_complete() {
 
     // 7. Creator rejects collaborator2's submission
     client.review_submission(
-        &content_id,
-        &collaborator2,
+        &submission_id,
         &creator,
         &false,
         &String::from_str(&env, "Too advanced for target audience")
@@ -2061,7 +2349,7 @@ fn test_collaborative_workflow_complete() {
 #[test]
 fn test_multiple_collaborators_same_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2104,8 +2392,11 @@ fn test_multiple_collaborators_same_content() {
         );
     }
 
-    // Creator reviews all submissions - accept first two, reject last one
-    for (i, collaborator) in collaborators.iter().enumerate() {
+    // Creator reviews all submissions - accept first two, reject last one.
+    // The first merge moves the head, so the second submission conflicts.
+    let submission_ids = client.get_content_submissions(&content_id);
+    assert_eq!(submission_ids.len(), 3);
+    for (i, submission_id) in submission_ids.iter().enumerate() {
         let accept = i < 2; // Accept first two, reject third
         let feedback = if accept {
             String::from_str(&env, "Great contribution!")
@@ -2113,13 +2404,17 @@ fn test_multiple_collaborators_same_content() {
             String::from_str(&env, "Overlaps with existing content")
         };
 
-        client.review_submission(
-            &content_id,
-            collaborator,
+        let status = client.review_submission(
+            &submission_id,
             &creator,
             &accept,
             &feedback
         );
+        let expected = match i {
+            0 => ReviewStatus::Accepted,
+            _ => ReviewStatus::Conflict,
+        };
+        assert_eq!(status, expected);
     }
 
     // Verify all collaborators have history entries
@@ -2129,11 +2424,180 @@ fn test_multiple_collaborators_same_content() {
     }
 }
 
+#[test]
+fn test_n_of_m_approval_and_attribution() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let reviewer1 = Address::generate(&env);
+    let reviewer2 = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+
+    let content_id = client.publish_content(
+        &creator,
+        &String::from_str(&env, "Reviewed Content"),
+        &BytesN::random(&env),
+        &vec![&env]
+    );
+
+    client.grant_permission(&content_id, &creator, &collaborator);
+    client.grant_reviewer(&content_id, &creator, &reviewer1);
+    client.grant_reviewer(&content_id, &creator, &reviewer2);
+    client.set_required_approvals(&content_id, &creator, &2);
+    assert_eq!(client.get_approval_policy(&content_id), (2, vec![&env, reviewer1.clone(), reviewer2.clone()]));
+
+    let new_hash = BytesN::random(&env);
+    let submission_id = client.submit_for_review(
+        &content_id,
+        &collaborator,
+        &new_hash,
+        &vec![&env],
+        &String::from_str(&env, "Reviewed change")
+    );
+
+    // One approval out of two keeps the submission pending
+    let status = client.review_submission(&submission_id, &reviewer1, &true, &String::from_str(&env, "LGTM"));
+    assert_eq!(status, ReviewStatus::Pending);
+    assert_eq!(client.get_submission(&submission_id).approvals.len(), 1);
+
+    let status = client.review_submission(&submission_id, &reviewer2, &true, &String::from_str(&env, "Approved"));
+    assert_eq!(status, ReviewStatus::Accepted);
+    assert_eq!(client.get_content(&content_id).content_hash, new_hash);
+
+    // The merged version credits the collaborator and the approvers
+    let version = client.get_version_info(&content_id, &1);
+    assert_eq!(version.creator, creator);
+    assert_eq!(version.contributor, Some(collaborator));
+    assert_eq!(version.approvers, vec![&env, reviewer1, reviewer2]);
+}
+
+#[test]
+fn test_submission_rejected_when_approvals_unreachable() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Content"), &BytesN::random(&env), &vec![&env]);
+    client.grant_permission(&content_id, &creator, &collaborator);
+    client.grant_reviewer(&content_id, &creator, &reviewer);
+    client.set_required_approvals(&content_id, &creator, &2);
+
+    let submission_id = client.submit_for_review(&content_id, &collaborator, &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Change"));
+
+    // With 2 of 2 required, a single rejection settles it
+    let status = client.review_submission(&submission_id, &reviewer, &false, &String::from_str(&env, "No"));
+    assert_eq!(status, ReviewStatus::Rejected);
+    assert_eq!(client.get_user_contribution_history(&collaborator, &content_id).len(), 1);
+}
+
+#[test]
+fn test_concurrent_submissions_and_conflicts() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+
+    let original_hash = BytesN::random(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Content"), &original_hash, &vec![&env]);
+    client.grant_permission(&content_id, &creator, &collaborator);
+
+    let first_hash = BytesN::random(&env);
+    let first = client.submit_for_review(&content_id, &collaborator, &first_hash, &vec![&env], &String::from_str(&env, "First"));
+    let second = client.submit_for_review(&content_id, &collaborator, &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Second"));
+    assert_ne!(first, second);
+    assert_eq!(client.get_collaborative_submission(&collaborator, &content_id).submission_id, second);
+
+    // Both are based on version 0; merging the first leaves the second behind the head
+    assert_eq!(client.review_submission(&first, &creator, &true, &String::from_str(&env, "Merged")), ReviewStatus::Accepted);
+    assert_eq!(client.review_submission(&second, &creator, &true, &String::from_str(&env, "Merged")), ReviewStatus::Conflict);
+    assert_eq!(client.get_content(&content_id).content_hash, first_hash);
+
+    // Resubmitting on top of the new head merges cleanly
+    let rebased_hash = BytesN::random(&env);
+    let rebased = client.submit_for_review(&content_id, &collaborator, &rebased_hash, &vec![&env], &String::from_str(&env, "Rebased"));
+    assert_eq!(client.get_submission(&rebased).base_version, 1);
+    assert_eq!(client.review_submission(&rebased, &creator, &true, &String::from_str(&env, "Merged")), ReviewStatus::Accepted);
+    assert_eq!(client.get_content(&content_id).content_hash, rebased_hash);
+}
+
+#[test]
+#[should_panic(expected = "reviewer already reviewed this submission")]
+fn test_reviewer_cannot_review_twice() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Content"), &BytesN::random(&env), &vec![&env]);
+    client.grant_permission(&content_id, &creator, &collaborator);
+    client.grant_reviewer(&content_id, &creator, &reviewer);
+    client.set_required_approvals(&content_id, &creator, &2);
+
+    let submission_id = client.submit_for_review(&content_id, &collaborator, &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Change"));
+    client.review_submission(&submission_id, &reviewer, &true, &String::from_str(&env, "Yes"));
+    client.review_submission(&submission_id, &reviewer, &true, &String::from_str(&env, "Yes again"));
+}
+
+#[test]
+#[should_panic(expected = "cannot review own submission")]
+fn test_reviewer_cannot_review_own_submission() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Content"), &BytesN::random(&env), &vec![&env]);
+    client.grant_reviewer(&content_id, &creator, &reviewer);
+
+    let submission_id = client.submit_for_review(&content_id, &reviewer, &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Change"));
+    client.review_submission(&submission_id, &reviewer, &true, &String::from_str(&env, "Self approval"));
+}
+
+#[test]
+#[should_panic(expected = "required approvals must be between 1 and the number of reviewers")]
+fn test_required_approvals_above_reviewer_count() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Content"), &BytesN::random(&env), &vec![&env]);
+    client.grant_reviewer(&content_id, &creator, &reviewer);
+    client.set_required_approvals(&content_id, &creator, &3);
+}
+
 #[test]
 #[should_panic(expected = "Only content creator can grant permissions")]
 fn test_grant_permission_non_creator() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2157,7 +2621,7 @@ fn test_grant_permission_non_creator() {
 #[should_panic(expected = "permission not found for user and content_id")]
 fn test_submit_without_permission() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2183,10 +2647,10 @@ fn test_submit_without_permission() {
 }
 
 #[test]
-#[should_panic(expected = "Only content creator can review submissions")]
+#[should_panic(expected = "Only the content creator or a reviewer can review submissions")]
 fn test_review_submission_non_creator() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2204,7 +2668,7 @@ fn test_review_submission_non_creator() {
 
     client.grant_permission(&content_id, &creator, &collaborator);
     
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &BytesN::random(&env),
@@ -2214,8 +2678,7 @@ fn test_review_submission_non_creator() {
 
     // Non-creator tries to review
     client.review_submission(
-        &content_id,
-        &collaborator,
+        &submission_id,
         &non_creator, // Wrong reviewer
         &true,
         &String::from_str(&env, "Unauthorized review")
@@ -2226,7 +2689,7 @@ fn test_review_submission_non_creator() {
 #[should_panic(expected = "Submission must be pending to review")]
 fn test_review_already_reviewed_submission() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2243,7 +2706,7 @@ fn test_review_already_reviewed_submission() {
 
     client.grant_permission(&content_id, &creator, &collaborator);
     
-    client.submit_for_review(
+    let submission_id = client.submit_for_review(
         &content_id,
         &collaborator,
         &BytesN::random(&env),
@@ -2253,8 +2716,7 @@ fn test_review_already_reviewed_submission() {
 
     // Review once (accept)
     client.review_submission(
-        &content_id,
-        &collaborator,
+        &submission_id,
         &creator,
         &true,
         &String::from_str(&env, "Good work")
@@ -2262,8 +2724,7 @@ fn test_review_already_reviewed_submission() {
 
     // Try to review again - should panic
     client.review_submission(
-        &content_id,
-        &collaborator,
+        &submission_id,
         &creator,
         &false,
         &String::from_str(&env, "Changed my mind")
@@ -2274,7 +2735,7 @@ fn test_review_already_reviewed_submission() {
 #[should_panic(expected = "permission not found for user and content_id")]
 fn test_get_nonexistent_permission() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2297,7 +2758,7 @@ fn test_get_nonexistent_permission() {
 #[should_panic(expected = "submission not found for submitter and content_id")]
 fn test_get_nonexistent_submission() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -2319,7 +2780,7 @@ fn test_get_nonexistent_submission() {
 #[test]
 fn test_flag_and_get_flags() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     let creator = Address::generate(&env);
@@ -2334,11 +2795,11 @@ fn test_flag_and_get_flags() {
 #[test]
 fn test_moderate_and_get_history() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     let creator = Address::generate(&env);
-    let moderator = Address::generate(&env);
+    let moderator = setup_moderators(&env, &client, 1).1.get(0).unwrap();
     let content_id = client.publish_content(&creator, &String::from_str(&env, "Moderatable Content"), &BytesN::random(&env), &vec![&env, String::from_str(&env, "test")]);
     client.flag_content(&content_id, &creator, &String::from_str(&env, "abuse"));
     client.moderate_content(&content_id, &moderator, &crate::storage::ModerationStatus::Removed, &String::from_str(&env, "confirmed abuse"));
@@ -2351,12 +2812,14 @@ fn test_moderate_and_get_history() {
 #[test]
 fn test_create_and_resolve_dispute() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     let creator = Address::generate(&env);
-    let resolver = Address::generate(&env);
+    let moderators = setup_moderators(&env, &client, 2).1;
+    let resolver = moderators.get(1).unwrap();
     let content_id = client.publish_content(&creator, &String::from_str(&env, "Disputable Content"), &BytesN::random(&env), &vec![&env, String::from_str(&env, "test")]);
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
     let dispute_id = client.create_dispute(&content_id, &creator, &String::from_str(&env, "unfair removal"));
     let dispute = client.get_dispute(&dispute_id).unwrap();
     assert_eq!(dispute.status, crate::storage::ModerationStatus::UnderDispute);
@@ -2369,47 +2832,452 @@ fn test_create_and_resolve_dispute() {
 #[test]
 fn test_advanced_verification_and_delegation() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     let creator = Address::generate(&env);
     let verifier = Address::generate(&env);
     let delegatee = Address::generate(&env);
     let content_id = client.publish_content(&creator, &String::from_str(&env, "Advanced Verification"), &BytesN::random(&env), &vec![&env, String::from_str(&env, "test")]);
+    // The delegator answers for the verification, the delegatee needs the reputation
+    let verification = setup_verification(&env, &client);
+    client.add_verifier(&verification.admin, &verifier, &VerificationLevel::Expert);
+    client.set_min_reputation(&verification.admin, &VerificationLevel::Expert, &50);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&delegatee, &60);
     // Delegate
     client.delegate_verification(&verifier, &delegatee, &Some(100000));
     // Advanced verification with delegation
-    let level = client.verify_content_advanced(&content_id, &delegatee, &VerificationLevel::Expert, &Some(verifier.clone()), &50, &Some(1000));
+    let level = client.verify_content_advanced(&content_id, &delegatee, &VerificationLevel::Expert, &Some(verifier.clone()), &Some(1000));
     assert_eq!(level, VerificationLevel::Expert);
     // Renew verification
     client.renew_verification(&content_id, &delegatee, &2000);
     // Revoke delegation
     client.revoke_delegation(&verifier, &delegatee);
+    let records = client.get_verification_records(&content_id);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records.get(0).unwrap().reputation_snapshot, Some(60));
 }
 
-// ========================================
-// ANALYTICS TESTS
-// ========================================
+#[test]
+#[should_panic(expected = "Verifier does not meet minimum reputation")]
+fn test_advanced_verification_low_reputation() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Low Reputation"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.set_min_reputation(&verification.admin, &VerificationLevel::Expert, &50);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&verifier, &40);
+    client.verify_content_advanced(&content_id, &verifier, &VerificationLevel::Expert, &None, &None);
+}
 
 #[test]
-fn test_record_content_view() {
+fn test_min_reputation_set_per_level() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
-    
     let creator = Address::generate(&env);
-    let content_id = client.publish_content(
-        &creator,
-        &String::from_str(&env, "Test Content for Analytics"),
-        &BytesN::random(&env),
-        &vec![&env, String::from_str(&env, "analytics")]
+    let verifier = Address::generate(&env);
+    let peer_content = client.publish_content(&creator, &String::from_str(&env, "Peer Minimum"), &BytesN::random(&env), &vec![&env]);
+    let expert_content = client.publish_content(&creator, &String::from_str(&env, "Expert Minimum"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&verifier, &60);
+
+    client.set_min_reputation(&verification.admin, &VerificationLevel::Peer, &50);
+    client.set_min_reputation(&verification.admin, &VerificationLevel::Expert, &80);
+    assert_eq!(client.get_min_reputation(&VerificationLevel::Expert), 80);
+    assert_eq!(client.get_min_reputation(&VerificationLevel::Institutional), 0);
+
+    // The verifier meets the peer minimum but not the expert one
+    client.verify_content_advanced(&peer_content, &verifier, &VerificationLevel::Peer, &None, &None);
+    assert!(client.try_verify_content_advanced(&expert_content, &verifier, &VerificationLevel::Expert, &None, &None).is_err());
+
+    // Only the admin or governance sets the minimum
+    assert!(client.try_set_min_reputation(&verifier, &VerificationLevel::Expert, &0).is_err());
+}
+
+// ========================================
+// VERIFIER REGISTRY TESTS
+// ========================================
+
+#[test]
+#[should_panic(expected = "verifier not registered")]
+fn test_unregistered_verifier_cannot_verify() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Unregistered"), &BytesN::random(&env), &vec![&env]);
+    setup_verification(&env, &client);
+    client.verify_content(&content_id, &verifier, &VerificationLevel::Peer);
+}
+
+#[test]
+#[should_panic(expected = "verifier not registered for this level")]
+fn test_verifier_cannot_verify_above_level() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Above Level"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    client.add_verifier(&verification.admin, &verifier, &VerificationLevel::Peer);
+    client.verify_content(&content_id, &verifier, &VerificationLevel::Expert);
+}
+
+#[test]
+#[should_panic(expected = "only admin or governance can manage verifiers")]
+fn test_add_verifier_non_manager() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    setup_verification(&env, &client);
+    let outsider = Address::generate(&env);
+    client.add_verifier(&outsider, &outsider, &VerificationLevel::Institutional);
+}
+
+#[test]
+fn test_governance_manages_verifiers() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let verification = setup_verification(&env, &client);
+    let governance = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    client.set_governance(&verification.admin, &governance);
+
+    client.add_verifier(&governance, &verifier, &VerificationLevel::Expert);
+    assert_eq!(client.get_verifiers_by_level(&VerificationLevel::Expert), vec![&env, verifier.clone()]);
+
+    // Moving the verifier to another level updates both lists
+    client.add_verifier(&governance, &verifier, &VerificationLevel::Peer);
+    assert_eq!(client.get_verifiers_by_level(&VerificationLevel::Expert).len(), 0);
+    assert_eq!(client.get_verifier(&verifier).unwrap().level, VerificationLevel::Peer);
+
+    client.remove_verifier(&governance, &verifier);
+    assert_eq!(client.get_verifiers_by_level(&VerificationLevel::Peer).len(), 0);
+    assert!(client.get_verifier(&verifier).unwrap().removed_at.is_some());
+}
+
+#[test]
+#[should_panic(expected = "verifier stake below the level minimum")]
+fn test_verifier_below_min_stake() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Unstaked"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.set_min_stake(&verification.admin, &VerificationLevel::Institutional, &1_000);
+    client.verify_content(&content_id, &verifier, &VerificationLevel::Peer);
+}
+
+#[test]
+fn test_stake_and_unstake() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let verifier = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    let token = TokenClient::new(&env, &verification.stake_token);
+    StellarAssetClient::new(&env, &verification.stake_token).mint(&verifier, &5_000);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.set_min_stake(&verification.admin, &VerificationLevel::Institutional, &1_000);
+
+    assert_eq!(client.stake_verifier(&verifier, &3_000), 3_000);
+    assert_eq!(token.balance(&verifier), 2_000);
+    assert_eq!(token.balance(&contract_id), 3_000);
+
+    // Active verifiers can withdraw down to the minimum
+    assert_eq!(client.unstake_verifier(&verifier, &2_000), 1_000);
+    assert_eq!(token.balance(&verifier), 4_000);
+
+    // Removed verifiers withdraw the rest once unbonded
+    client.remove_verifier(&verification.admin, &verifier);
+    env.ledger().with_mut(|li| li.timestamp += 14 * 24 * 60 * 60);
+    assert_eq!(client.unstake_verifier(&verifier, &1_000), 0);
+    assert_eq!(token.balance(&verifier), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "stake would fall below the level minimum")]
+fn test_unstake_below_min_stake() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let verifier = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    StellarAssetClient::new(&env, &verification.stake_token).mint(&verifier, &2_000);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.set_min_stake(&verification.admin, &VerificationLevel::Institutional, &1_000);
+    client.stake_verifier(&verifier, &2_000);
+    client.unstake_verifier(&verifier, &1_500);
+}
+
+#[test]
+#[should_panic(expected = "stake is still unbonding")]
+fn test_unstake_during_unbonding() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let verifier = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    StellarAssetClient::new(&env, &verification.stake_token).mint(&verifier, &2_000);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.stake_verifier(&verifier, &2_000);
+    client.remove_verifier(&verification.admin, &verifier);
+    client.unstake_verifier(&verifier, &2_000);
+}
+
+#[test]
+fn test_upheld_verification_dispute_slashes_verifier() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let challenger = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Disputed Verification"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    let token = TokenClient::new(&env, &verification.stake_token);
+    StellarAssetClient::new(&env, &verification.stake_token).mint(&verifier, &2_000);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.stake_verifier(&verifier, &2_000);
+    client.verify_content(&content_id, &verifier, &VerificationLevel::Expert);
+
+    let dispute_id = client.dispute_verification(&content_id, &challenger, &String::from_str(&env, "plagiarized"));
+    client.resolve_dispute(&dispute_id, &verification.admin, &true);
+
+    // Half of the stake goes to the admin by default
+    let info = client.get_verifier(&verifier).unwrap();
+    assert_eq!(info.stake, 1_000);
+    assert_eq!(info.slashed, 1_000);
+    assert_eq!(token.balance(&verification.admin), 1_000);
+    assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::None);
+    assert!(client.get_verification_records(&content_id).get(0).unwrap().overturned);
+}
+
+#[test]
+#[should_panic(expected = "only admin or governance can manage verifiers")]
+fn test_verification_dispute_resolved_by_outsider() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Disputed Verification"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    client.verify_content(&content_id, &verifier, &VerificationLevel::Peer);
+    let dispute_id = client.dispute_verification(&content_id, &creator, &String::from_str(&env, "wrong"));
+    client.resolve_dispute(&dispute_id, &creator, &true);
+}
+
+#[test]
+#[should_panic(expected = "content is not verified")]
+fn test_dispute_unverified_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Unverified"), &BytesN::random(&env), &vec![&env]);
+    client.dispute_verification(&content_id, &creator, &String::from_str(&env, "wrong"));
+}
+
+// ========================================
+// VERIFICATION EXPIRY TESTS
+// ========================================
+
+#[test]
+fn test_expired_verification_lowers_effective_level() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let peer = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Expiring"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &peer);
+    register_verifier(&client, &verification.admin, &expert);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&expert, &100);
+
+    client.verify_content(&content_id, &peer, &VerificationLevel::Peer);
+    client.verify_content_advanced(&content_id, &expert, &VerificationLevel::Expert, &None, &Some(1000));
+    assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::Expert);
+
+    // Once the expert record lapses the content falls back to the peer verification
+    env.ledger().with_mut(|li| li.timestamp += 1000);
+    assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::Peer);
+    assert_eq!(client.filter_by_verification_level(&VerificationLevel::Expert).len(), 0);
+    assert_eq!(client.filter_by_verification_level(&VerificationLevel::Peer).len(), 1);
+
+    // Expired content can be verified again at the lapsed level
+    client.verify_content(&content_id, &expert, &VerificationLevel::Expert);
+    assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::Expert);
+}
+
+#[test]
+fn test_sweep_verifications_downgrades_and_reminds() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let expiring = client.publish_content(&creator, &String::from_str(&env, "Expiring"), &BytesN::random(&env), &vec![&env]);
+    let lasting = client.publish_content(&creator, &String::from_str(&env, "Lasting"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&verifier, &100);
+    client.set_reminder_window(&verification.admin, &500);
+
+    client.verify_content_advanced(&expiring, &verifier, &VerificationLevel::Expert, &None, &Some(1000));
+    client.verify_content_advanced(&lasting, &verifier, &VerificationLevel::Peer, &None, &Some(10_000));
+
+    // Inside the reminder window of the first content only
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    assert_eq!(client.sweep_verifications(&0, &10), 0);
+    assert!(client.get_verification_records(&expiring).get(0).unwrap().reminder_sent);
+    assert!(!client.get_verification_records(&lasting).get(0).unwrap().reminder_sent);
+
+    // Renewing resets the reminder
+    client.renew_verification(&expiring, &verifier, &1000);
+    assert!(!client.get_verification_records(&expiring).get(0).unwrap().reminder_sent);
+
+    env.ledger().with_mut(|li| li.timestamp += 1000);
+    assert_eq!(client.sweep_verifications(&0, &10), 1);
+    assert!(client.get_verification_records(&expiring).get(0).unwrap().expired);
+    assert_eq!(client.get_content(&expiring).verification_level, VerificationLevel::None);
+    assert_eq!(client.get_content(&lasting).verification_level, VerificationLevel::Peer);
+
+    // Nothing left to downgrade
+    assert_eq!(client.sweep_verifications(&0, &10), 0);
+
+    // The swept record can still be renewed
+    client.renew_verification(&expiring, &verifier, &1000);
+    assert!(!client.get_verification_records(&expiring).get(0).unwrap().expired);
+    assert_eq!(client.get_content(&expiring).verification_level, VerificationLevel::Expert);
+}
+
+#[test]
+fn test_sweep_pages_over_content_ids() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let removed = client.publish_content(&creator, &String::from_str(&env, "Removed"), &BytesN::random(&env), &vec![&env]);
+    let expiring = client.publish_content(&creator, &String::from_str(&env, "Expiring"), &BytesN::random(&env), &vec![&env]);
+    let (admin, moderators) = setup_moderators(&env, &client, 1);
+    register_verifier(&client, &admin, &verifier);
+    client.verify_content_advanced(&expiring, &verifier, &VerificationLevel::Peer, &None, &Some(1000));
+    client.moderate_content(&removed, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+
+    // The page starting at the second id still covers the second content
+    env.ledger().with_mut(|li| li.timestamp += 2000);
+    assert_eq!(client.sweep_verifications(&0, &1), 0);
+    assert_eq!(client.sweep_verifications(&1, &1), 1);
+    assert_eq!(client.get_content(&expiring).verification_level, VerificationLevel::None);
+}
+
+#[test]
+#[should_panic(expected = "verifier not registered")]
+fn test_removed_verifier_cannot_renew() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Removed Verifier"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&verifier, &100);
+
+    client.verify_content_advanced(&content_id, &verifier, &VerificationLevel::Expert, &None, &Some(100));
+    client.remove_verifier(&verification.admin, &verifier);
+    client.renew_verification(&content_id, &verifier, &1000);
+}
+
+#[test]
+fn test_renew_expired_verification_restores_level() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Renewed"), &BytesN::random(&env), &vec![&env]);
+    let verification = setup_verification(&env, &client);
+    register_verifier(&client, &verification.admin, &verifier);
+    MockReputationClient::new(&env, &verification.reputation).set_reputation(&verifier, &100);
+
+    client.verify_content_advanced(&content_id, &verifier, &VerificationLevel::Institutional, &None, &Some(100));
+    env.ledger().with_mut(|li| li.timestamp += 200);
+    assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::None);
+
+    client.renew_verification(&content_id, &verifier, &1000);
+    assert_eq!(client.get_content(&content_id).verification_level, VerificationLevel::Institutional);
+}
+
+#[test]
+#[should_panic(expected = "only admin or governance can manage verifiers")]
+fn test_set_reminder_window_non_manager() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    setup_verification(&env, &client);
+    client.set_reminder_window(&Address::generate(&env), &100);
+}
+
+// ========================================
+// ANALYTICS TESTS
+// ========================================
+
+#[test]
+fn test_record_content_view() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(
+        &creator,
+        &String::from_str(&env, "Test Content for Analytics"),
+        &BytesN::random(&env),
+        &vec![&env, String::from_str(&env, "analytics")]
     );
     
     // Record multiple views
-    client.record_content_view(&content_id);
-    client.record_content_view(&content_id);
-    client.record_content_view(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
     
     // Get analytics and verify
     let analytics = client.get_content_analytics(&content_id);
@@ -2423,7 +3291,7 @@ fn test_record_content_view() {
 #[test]
 fn test_record_content_upvotes_and_downvotes() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2436,17 +3304,17 @@ fn test_record_content_upvotes_and_downvotes() {
     );
     
     // Record views first
-    client.record_content_view(&content_id);
-    client.record_content_view(&content_id);
-    client.record_content_view(&content_id);
-    client.record_content_view(&content_id);
-    client.record_content_view(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
     
     // Record upvotes and downvotes
-    client.record_content_upvote(&content_id);
-    client.record_content_upvote(&content_id);
-    client.record_content_upvote(&content_id);
-    client.record_content_downvote(&content_id);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
+    client.record_content_downvote(&content_id, &Address::generate(&env), &None);
     
     // Get analytics and verify
     let analytics = client.get_content_analytics(&content_id);
@@ -2460,7 +3328,7 @@ fn test_record_content_upvotes_and_downvotes() {
 #[test]
 fn test_update_category_analytics() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2473,8 +3341,8 @@ fn test_update_category_analytics() {
     );
     
     // Record some activity
-    client.record_content_view(&content_id);
-    client.record_content_upvote(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
     
     // Update category analytics
     client.update_category_analytics(&content_id);
@@ -2490,7 +3358,7 @@ fn test_update_category_analytics() {
 #[test]
 fn test_calculate_trending_score() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2503,9 +3371,9 @@ fn test_calculate_trending_score() {
     );
     
     // Record activity to generate analytics
-    client.record_content_view(&content_id);
-    client.record_content_upvote(&content_id);
-    client.record_content_upvote(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
     
     // Calculate trending score for daily period
     let trending_score = client.calculate_trending_score(&content_id, &crate::storage::TrendingPeriod::Daily);
@@ -2517,7 +3385,7 @@ fn test_calculate_trending_score() {
 #[test]
 fn test_update_trending_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2530,8 +3398,8 @@ fn test_update_trending_content() {
     );
     
     // Record some activity
-    client.record_content_view(&content_id);
-    client.record_content_upvote(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
     
     // Update trending content for daily period
     client.update_trending_content(&content_id, &crate::storage::TrendingPeriod::Daily);
@@ -2543,7 +3411,7 @@ fn test_update_trending_content() {
 #[test]
 fn test_create_trending_snapshot() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2556,8 +3424,8 @@ fn test_create_trending_snapshot() {
     );
     
     // Record activity
-    client.record_content_view(&content_id);
-    client.record_content_upvote(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
     
     // Create trending snapshot for daily period
     let snapshot = client.create_trending_snapshot(&crate::storage::TrendingPeriod::Daily);
@@ -2571,7 +3439,7 @@ fn test_create_trending_snapshot() {
 #[test]
 fn test_update_all_trending_content() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2584,8 +3452,8 @@ fn test_update_all_trending_content() {
     );
     
     // Record activity
-    client.record_content_view(&content_id);
-    client.record_content_upvote(&content_id);
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+    client.record_content_upvote(&content_id, &Address::generate(&env), &None);
     
     // Update all trending content
     client.update_all_trending_content(&content_id);
@@ -2601,7 +3469,7 @@ fn test_update_all_trending_content() {
 #[test]
 fn test_analytics_and_trending_integration() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
     
@@ -2615,15 +3483,15 @@ fn test_analytics_and_trending_integration() {
     
     // Simulate content becoming popular
     for _ in 0..10 {
-        client.record_content_view(&content_id);
+        client.record_content_view(&content_id, &Address::generate(&env), &None);
     }
     
     for _ in 0..8 {
-        client.record_content_upvote(&content_id);
+        client.record_content_upvote(&content_id, &Address::generate(&env), &None);
     }
     
     for _ in 0..2 {
-        client.record_content_downvote(&content_id);
+        client.record_content_downvote(&content_id, &Address::generate(&env), &None);
     }
     
     // Get analytics
@@ -2650,25 +3518,1125 @@ fn test_analytics_and_trending_integration() {
 }
 
 #[test]
-#[should_panic(expected = "Content does not exist")]
-fn test_analytics_nonexistent_content() {
+fn test_engagement_deduplicated_per_bucket() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
-    
-    // Try to record view for non-existent content
-    client.record_content_view(&999);
+
+    let creator = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Deduped"), &BytesN::random(&env), &vec![&env]);
+
+    // Repeated engagement in the same bucket counts once
+    assert!(client.record_content_view(&content_id, &viewer, &None));
+    assert!(!client.record_content_view(&content_id, &viewer, &None));
+    assert!(client.record_content_upvote(&content_id, &viewer, &None));
+    assert!(!client.record_content_upvote(&content_id, &viewer, &None));
+
+    let analytics = client.get_content_analytics(&content_id);
+    assert_eq!(analytics.total_views, 1);
+    assert_eq!(analytics.total_upvotes, 1);
+    assert_eq!(analytics.unique_viewers, 1);
+
+    // A new bucket counts the view again, but not as a new viewer
+    env.ledger().with_mut(|li| li.timestamp += 60 * 60);
+    assert!(client.record_content_view(&content_id, &viewer, &None));
+    client.record_content_view(&content_id, &Address::generate(&env), &None);
+
+    let analytics = client.get_content_analytics(&content_id);
+    assert_eq!(analytics.total_views, 3);
+    assert_eq!(analytics.unique_viewers, 2);
 }
 
 #[test]
-#[should_panic(expected = "Content does not exist")]
-fn test_trending_nonexistent_content() {
+fn test_engagement_one_vote_per_viewer() {
     let env = Env::default();
-    let contract_id = env.register(TokenizedEducationalContent, ());
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
     let client = TokenizedEducationalContentClient::new(&env, &contract_id);
     env.mock_all_auths();
-    
-    // Try to calculate trending score for non-existent content
-    client.calculate_trending_score(&999, &crate::storage::TrendingPeriod::Daily);
+
+    let creator = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "One Vote"), &BytesN::random(&env), &vec![&env]);
+
+    // An upvote rules out a downvote from the same viewer
+    assert!(client.record_content_upvote(&content_id, &viewer, &None));
+    assert!(!client.record_content_downvote(&content_id, &viewer, &None));
+
+    // Later buckets do not reopen voting
+    env.ledger().with_mut(|li| li.timestamp += 60 * 60);
+    assert!(!client.record_content_upvote(&content_id, &viewer, &None));
+    assert!(!client.record_content_downvote(&content_id, &viewer, &None));
+
+    let analytics = client.get_content_analytics(&content_id);
+    assert_eq!(analytics.total_upvotes, 1);
+    assert_eq!(analytics.total_downvotes, 0);
+}
+
+#[test]
+fn test_engagement_requires_viewer_or_reporter_auth() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let reporter = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Reported"), &BytesN::random(&env), &vec![&env]);
+
+    client.record_content_view(&content_id, &viewer, &None);
+    assert_eq!(env.auths()[0].0, viewer);
+
+    let verification = setup_verification(&env, &client);
+    client.set_reporter(&verification.admin, &reporter, &true);
+    assert!(client.is_reporter(&reporter));
+
+    // The backend reports for a viewer without the viewer's signature
+    let other_viewer = Address::generate(&env);
+    client.record_content_view(&content_id, &other_viewer, &Some(reporter.clone()));
+    assert_eq!(env.auths()[0].0, reporter);
+    assert_eq!(client.get_content_analytics(&content_id).unique_viewers, 2);
+}
+
+#[test]
+#[should_panic(expected = "reporter not registered")]
+fn test_engagement_unregistered_reporter() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Reported"), &BytesN::random(&env), &vec![&env]);
+    client.record_content_view(&content_id, &Address::generate(&env), &Some(Address::generate(&env)));
+}
+
+#[test]
+#[should_panic(expected = "Engagement rate limit exceeded")]
+fn test_engagement_rate_limit() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let verification = setup_verification(&env, &client);
+    client.set_engagement_config(&verification.admin, &EngagementConfig { bucket_secs: 3600, max_per_bucket: 2 });
+
+    for i in 0..3 {
+        let title = match i {
+            0 => String::from_str(&env, "First"),
+            1 => String::from_str(&env, "Second"),
+            _ => String::from_str(&env, "Third"),
+        };
+        let content_id = client.publish_content(&creator, &title, &BytesN::random(&env), &vec![&env]);
+        client.record_content_view(&content_id, &viewer, &None);
+    }
+}
+
+#[test]
+fn test_trending_leaderboard_ordering_and_pagination() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let quiet = client.publish_content(&creator, &String::from_str(&env, "Quiet"), &BytesN::random(&env), &vec![&env]);
+    let popular = client.publish_content(&creator, &String::from_str(&env, "Popular"), &BytesN::random(&env), &vec![&env]);
+    let disliked = client.publish_content(&creator, &String::from_str(&env, "Disliked"), &BytesN::random(&env), &vec![&env]);
+
+    client.record_content_view(&quiet, &Address::generate(&env), &None);
+    client.record_content_view(&popular, &Address::generate(&env), &None);
+    client.record_content_upvote(&popular, &Address::generate(&env), &None);
+    client.record_content_view(&disliked, &Address::generate(&env), &None);
+    client.record_content_view(&disliked, &Address::generate(&env), &None);
+    client.record_content_downvote(&disliked, &Address::generate(&env), &None);
+
+    let daily = crate::storage::TrendingPeriod::Daily;
+    let leaderboard = client.get_trending_leaderboard(&daily, &0, &10);
+    assert_eq!(leaderboard.len(), 2);
+    assert_eq!(leaderboard.get(0).unwrap().content_id, popular);
+    assert_eq!(leaderboard.get(0).unwrap().score, 5_000);
+    assert_eq!(leaderboard.get(1).unwrap().content_id, quiet);
+    assert_eq!(leaderboard.get(1).unwrap().score, 1_000);
+
+    // Pages are read from the stored board
+    let page = client.get_trending_leaderboard(&daily, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().content_id, quiet);
+    assert_eq!(client.get_trending_leaderboard(&daily, &5, &10).len(), 0);
+
+    let trending = client.get_trending_content(&daily, &1);
+    assert_eq!(trending.len(), 1);
+    assert_eq!(trending.get(0).unwrap().content_id, popular);
+    let snapshot = client.create_trending_snapshot(&daily);
+    assert_eq!(snapshot.trending_content_ids, vec![&env, popular, quiet]);
+}
+
+#[test]
+fn test_trending_leaderboard_decay() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let early = client.publish_content(&creator, &String::from_str(&env, "Early"), &BytesN::random(&env), &vec![&env]);
+    let late = client.publish_content(&creator, &String::from_str(&env, "Late"), &BytesN::random(&env), &vec![&env]);
+    client.record_content_upvote(&early, &Address::generate(&env), &None);
+
+    let daily = crate::storage::TrendingPeriod::Daily;
+    let weekly = crate::storage::TrendingPeriod::Weekly;
+
+    // The daily score halves at every 6 hour boundary, the weekly one much slower
+    env.ledger().with_mut(|li| li.timestamp += 3 * 60 * 60);
+    assert_eq!(client.get_trending_leaderboard(&daily, &0, &1).get(0).unwrap().score, 4_000);
+    env.ledger().with_mut(|li| li.timestamp += 3 * 60 * 60);
+    assert_eq!(client.get_trending_leaderboard(&daily, &0, &1).get(0).unwrap().score, 2_000);
+    assert_eq!(client.get_trending_leaderboard(&weekly, &0, &1).get(0).unwrap().score, 4_000);
+
+    // Fresh engagement overtakes decayed engagement
+    client.record_content_view(&late, &Address::generate(&env), &None);
+    env.ledger().with_mut(|li| li.timestamp += 6 * 60 * 60);
+    client.record_content_view(&late, &Address::generate(&env), &None);
+
+    let leaderboard = client.get_trending_leaderboard(&daily, &0, &10);
+    assert_eq!(leaderboard.get(0).unwrap().content_id, late);
+    assert_eq!(leaderboard.get(0).unwrap().score, 1_500);
+    assert_eq!(leaderboard.get(1).unwrap().content_id, early);
+    assert_eq!(leaderboard.get(1).unwrap().score, 1_000);
+    assert_eq!(client.get_trending_leaderboard(&weekly, &0, &10).get(0).unwrap().content_id, early);
+}
+
+#[test]
+fn test_category_leaderboard() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let math = String::from_str(&env, "math");
+    let art = String::from_str(&env, "art");
+    let algebra = client.publish_content(&creator, &String::from_str(&env, "Algebra"), &BytesN::random(&env), &vec![&env, math.clone()]);
+    let geometry = client.publish_content(&creator, &String::from_str(&env, "Geometry"), &BytesN::random(&env), &vec![&env, math.clone(), art.clone()]);
+
+    client.record_content_view(&algebra, &Address::generate(&env), &None);
+    client.record_content_upvote(&geometry, &Address::generate(&env), &None);
+
+    let daily = crate::storage::TrendingPeriod::Daily;
+    let math_board = client.get_category_leaderboard(&math, &daily, &0, &10);
+    assert_eq!(math_board.len(), 2);
+    assert_eq!(math_board.get(0).unwrap().content_id, geometry);
+    assert_eq!(math_board.get(1).unwrap().content_id, algebra);
+
+    let art_board = client.get_category_leaderboard(&art, &daily, &0, &10);
+    assert_eq!(art_board.len(), 1);
+    assert_eq!(art_board.get(0).unwrap().content_id, geometry);
+
+    let by_category = client.get_trending_content_by_category(&art, &daily, &10);
+    assert_eq!(by_category.len(), 1);
+    assert_eq!(client.get_category_leaderboard(&String::from_str(&env, "music"), &daily, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_trending_leaderboard_is_bounded() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let mut last = 0;
+    for _ in 0..(crate::trending::LEADERBOARD_SIZE + 5) {
+        last = client.publish_content(&creator, &String::from_str(&env, "Bounded"), &BytesN::random(&env), &vec![&env]);
+        client.record_content_view(&last, &Address::generate(&env), &None);
+    }
+
+    let daily = crate::storage::TrendingPeriod::Daily;
+    let leaderboard = client.get_trending_leaderboard(&daily, &0, &100);
+    assert_eq!(leaderboard.len(), crate::trending::LEADERBOARD_SIZE);
+
+    // Content dropped off the board gets back on once it outscores the tail
+    client.record_content_upvote(&0, &Address::generate(&env), &None);
+    let leaderboard = client.get_trending_leaderboard(&daily, &0, &100);
+    assert_eq!(leaderboard.len(), crate::trending::LEADERBOARD_SIZE);
+    assert_eq!(leaderboard.get(0).unwrap().content_id, 0);
+    assert_eq!(leaderboard.get(1).unwrap().content_id, last);
+}
+
+#[test]
+#[should_panic(expected = "Content does not exist")]
+fn test_analytics_nonexistent_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    
+    // Try to record view for non-existent content
+    client.record_content_view(&999, &Address::generate(&env), &None);
+}
+
+#[test]
+#[should_panic(expected = "Content does not exist")]
+fn test_trending_nonexistent_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    
+    // Try to calculate trending score for non-existent content
+    client.calculate_trending_score(&999, &crate::storage::TrendingPeriod::Daily);
+}
+
+// ========================================
+// LICENSING TESTS
+// ========================================
+
+// Register a payment token and mint `amount` to `buyer`
+fn setup_payment_token(env: &Env, buyer: &Address, amount: i128) -> Address {
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    StellarAssetClient::new(env, &token).mint(buyer, &amount);
+    token
+}
+
+#[test]
+fn test_paid_access_with_revenue_split() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Paid"), &BytesN::random(&env), &vec![&env]);
+
+    // Merge a collaborator's submission so it can share the revenue
+    client.grant_permission(&content_id, &creator, &collaborator);
+    let submission_id = client.submit_for_review(&content_id, &collaborator, &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Fix"));
+    client.review_submission(&submission_id, &creator, &true, &String::from_str(&env, "Thanks"));
+
+    let token = setup_payment_token(&env, &buyer, 1_000);
+    let license = ContentLicense { license: License::CcByNc, token: Some(token.clone()), price: 1_000, duration: None };
+    client.set_license(&content_id, &creator, &license);
+    client.set_revenue_shares(&content_id, &creator, &vec![&env, RevenueShare { recipient: collaborator.clone(), share: 2_500 }]);
+    assert_eq!(client.get_license(&content_id).license, License::CcByNc);
+
+    assert!(!client.has_access(&buyer, &content_id));
+    assert!(client.has_access(&creator, &content_id));
+    assert!(client.has_access(&collaborator, &content_id));
+
+    let pass = client.purchase_access(&content_id, &buyer);
+    assert_eq!(pass.expires_at, None);
+    assert_eq!(pass.total_paid, 1_000);
+    assert!(client.has_access(&buyer, &content_id));
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&buyer), 0);
+    assert_eq!(token_client.balance(&collaborator), 250);
+    assert_eq!(token_client.balance(&creator), 750);
+}
+
+#[test]
+fn test_timed_access_pass_expires_and_extends() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Timed"), &BytesN::random(&env), &vec![&env]);
+    let token = setup_payment_token(&env, &buyer, 300);
+    let license = ContentLicense {
+        license: License::Custom(String::from_str(&env, "ipfs://terms")),
+        token: Some(token.clone()),
+        price: 100,
+        duration: Some(1_000),
+    };
+    client.set_license(&content_id, &creator, &license);
+
+    client.purchase_access(&content_id, &buyer);
+    // Buying again before expiry extends the pass
+    env.ledger().with_mut(|li| li.timestamp += 500);
+    let pass = client.purchase_access(&content_id, &buyer);
+    assert_eq!(pass.expires_at, Some(2_000));
+    assert_eq!(pass.total_paid, 200);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_999);
+    assert!(client.has_access(&buyer, &content_id));
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(!client.has_access(&buyer, &content_id));
+
+    // After expiry the pass starts over from now
+    let pass = client.purchase_access(&content_id, &buyer);
+    assert_eq!(pass.expires_at, Some(3_000));
+    assert_eq!(TokenClient::new(&env, &token).balance(&creator), 300);
+}
+
+#[test]
+fn test_free_content_access() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Free"), &BytesN::random(&env), &vec![&env]);
+
+    let license = client.get_license(&content_id);
+    assert_eq!(license.license, License::AllRightsReserved);
+    assert_eq!(license.price, 0);
+    assert!(client.has_access(&Address::generate(&env), &content_id));
+
+    client.set_license(&content_id, &creator, &ContentLicense { license: License::CcBy, token: None, price: 0, duration: None });
+    assert!(client.has_access(&Address::generate(&env), &content_id));
+}
+
+#[test]
+#[should_panic(expected = "content is not for sale")]
+fn test_purchase_free_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Free"), &BytesN::random(&env), &vec![&env]);
+    client.purchase_access(&content_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "revenue share recipient is not an accepted collaborator")]
+fn test_revenue_share_requires_accepted_collaborator() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let collaborator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Shared"), &BytesN::random(&env), &vec![&env]);
+
+    // A granted permission alone does not earn a share
+    client.grant_permission(&content_id, &creator, &collaborator);
+    client.set_revenue_shares(&content_id, &creator, &vec![&env, RevenueShare { recipient: collaborator, share: 1_000 }]);
+}
+
+// ========================================
+// REMOVAL AND TAKEDOWN TESTS
+// ========================================
+
+#[test]
+fn test_removed_content_hidden_from_queries() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let moderators = setup_moderators(&env, &client, 2).1;
+    let moderator = moderators.get(0).unwrap();
+    let tag = String::from_str(&env, "math");
+    let kept = client.publish_content(&creator, &String::from_str(&env, "Kept"), &BytesN::random(&env), &vec![&env, tag.clone()]);
+    let hash = BytesN::random(&env);
+    let removed = client.publish_content(&creator, &String::from_str(&env, "Removed"), &hash, &vec![&env, tag.clone()]);
+    client.record_content_view(&kept, &Address::generate(&env), &None);
+    client.record_content_upvote(&removed, &Address::generate(&env), &None);
+
+    client.moderate_content(&removed, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+
+    let tombstone = client.get_tombstone(&removed).unwrap();
+    assert_eq!(tombstone.content_hash, hash);
+    assert_eq!(tombstone.reason, TombstoneReason::Moderation);
+    assert_eq!(client.filter_by_min_upvotes(&0).len(), 1);
+    let daily = crate::storage::TrendingPeriod::Daily;
+    assert_eq!(client.get_trending_leaderboard(&daily, &0, &10).len(), 1);
+    assert_eq!(client.get_category_leaderboard(&tag, &daily, &0, &10).get(0).unwrap().content_id, kept);
+
+    // An upheld appeal reinstates it
+    let dispute_id = client.create_dispute(&removed, &creator, &String::from_str(&env, "unfair removal"));
+    client.resolve_dispute(&dispute_id, &moderators.get(1).unwrap(), &true);
+    assert!(client.get_tombstone(&removed).is_none());
+    assert_eq!(client.get_content(&removed).title, String::from_str(&env, "Removed"));
+    assert_eq!(client.filter_by_min_upvotes(&0).len(), 2);
+
+    let trail = client.get_audit_trail(&removed);
+    assert_eq!(trail.len(), 2);
+    assert_eq!(trail.get(0).unwrap().action, AuditAction::Removed);
+    assert_eq!(trail.get(1).unwrap().action, AuditAction::Reinstated);
+}
+
+#[test]
+#[should_panic(expected = "content removed")]
+fn test_get_removed_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Removed"), &BytesN::random(&env), &vec![&env]);
+    let moderator = setup_moderators(&env, &client, 1).1.get(0).unwrap();
+    client.moderate_content(&content_id, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+    client.get_content(&content_id);
+}
+
+#[test]
+fn test_removed_content_versions_hidden() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Versioned"), &BytesN::random(&env), &vec![&env]);
+    client.create_new_version_content(&content_id, &creator, &String::from_str(&env, "V1"), &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Edit"));
+    client.delete_content(&content_id, &creator);
+
+    assert!(client.try_get_content_at_version(&content_id, &0).is_err());
+    assert!(client.try_get_version_info(&content_id, &1).is_err());
+    assert!(client.try_get_version_diff(&content_id, &0, &1).is_err());
+    assert!(client.try_fork_content(&content_id, &0, &creator, &String::from_str(&env, "Fork")).is_err());
+}
+
+#[test]
+#[should_panic(expected = "content removed")]
+fn test_removed_content_snapshot_hidden() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Versioned"), &BytesN::random(&env), &vec![&env]);
+    client.create_new_version_content(&content_id, &creator, &String::from_str(&env, "V1"), &BytesN::random(&env), &vec![&env], &String::from_str(&env, "Edit"));
+    let moderator = setup_moderators(&env, &client, 1).1.get(0).unwrap();
+    client.moderate_content(&content_id, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+    client.get_content_at_version(&content_id, &0);
+}
+
+#[test]
+fn test_creator_delete_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Deleted"), &BytesN::random(&env), &vec![&env]);
+    client.delete_content(&content_id, &creator);
+
+    assert_eq!(client.get_tombstone(&content_id).unwrap().reason, TombstoneReason::CreatorDeleted);
+    assert_eq!(client.get_audit_trail(&content_id).get(0).unwrap().action, AuditAction::Deleted);
+
+    // Deleted content cannot be appealed
+    assert!(client.try_create_dispute(&content_id, &creator, &String::from_str(&env, "undo")).is_err());
+    assert!(client.get_tombstone(&content_id).is_some());
+    assert_eq!(client.filter_by_min_upvotes(&0).len(), 0);
+}
+
+#[test]
+fn test_appeal_reinstates_removed_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Appealed"), &BytesN::random(&env), &vec![&env]);
+    let moderators = setup_moderators(&env, &client, 2).1;
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+
+    let dispute_id = client.create_dispute(&content_id, &creator, &String::from_str(&env, "unfair removal"));
+    client.resolve_dispute(&dispute_id, &moderators.get(1).unwrap(), &true);
+
+    assert!(client.get_tombstone(&content_id).is_none());
+    let trail = client.get_audit_trail(&content_id);
+    assert_eq!(trail.get(1).unwrap().action, AuditAction::Reinstated);
+    assert_eq!(trail.get(1).unwrap().reference, Some(dispute_id));
+}
+
+#[test]
+fn test_takedown_counter_notice_and_reinstatement() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let verification = setup_verification(&env, &client);
+    let creator = Address::generate(&env);
+    let claimant = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Claimed"), &BytesN::random(&env), &vec![&env]);
+
+    // The content stays up until the notice is approved
+    let notice_id = client.file_takedown(&content_id, &claimant, &String::from_str(&env, "copied from my book"));
+    assert_eq!(client.get_takedown_notice(&notice_id).status, TakedownStatus::Filed);
+    assert!(client.get_tombstone(&content_id).is_none());
+    client.review_takedown(&notice_id, &verification.admin, &true);
+    assert_eq!(client.get_tombstone(&content_id).unwrap().notice_id, Some(notice_id));
+
+    env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    client.file_counter_notice(&notice_id, &creator, &String::from_str(&env, "original work"));
+    let notice = client.get_takedown_notice(&notice_id);
+    assert_eq!(notice.status, TakedownStatus::Countered);
+    assert_eq!(notice.reinstate_at, Some(24 * 60 * 60 + crate::takedown::REINSTATEMENT_DELAY));
+
+    env.ledger().with_mut(|li| li.timestamp += crate::takedown::REINSTATEMENT_DELAY);
+    client.reinstate_content(&notice_id);
+    assert_eq!(client.get_takedown_notice(&notice_id).status, TakedownStatus::Reinstated);
+    assert_eq!(client.get_content(&content_id).title, String::from_str(&env, "Claimed"));
+
+    let trail = client.get_audit_trail(&content_id);
+    assert_eq!(trail.len(), 4);
+    assert_eq!(trail.get(0).unwrap().action, AuditAction::TakedownFiled);
+    assert_eq!(trail.get(1).unwrap().action, AuditAction::Removed);
+    assert_eq!(trail.get(2).unwrap().action, AuditAction::CounterNoticeFiled);
+    assert_eq!(trail.get(3).unwrap().action, AuditAction::Reinstated);
+}
+
+#[test]
+#[should_panic(expected = "reinstatement deadline not reached")]
+fn test_reinstate_before_deadline() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let verification = setup_verification(&env, &client);
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Claimed"), &BytesN::random(&env), &vec![&env]);
+    let notice_id = client.file_takedown(&content_id, &Address::generate(&env), &String::from_str(&env, "mine"));
+    client.review_takedown(&notice_id, &verification.admin, &true);
+    client.file_counter_notice(&notice_id, &creator, &String::from_str(&env, "original work"));
+    client.reinstate_content(&notice_id);
+}
+
+#[test]
+#[should_panic(expected = "takedown was not countered")]
+fn test_upheld_takedown_stays_removed() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let verification = setup_verification(&env, &client);
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Claimed"), &BytesN::random(&env), &vec![&env]);
+    let notice_id = client.file_takedown(&content_id, &Address::generate(&env), &String::from_str(&env, "mine"));
+    client.review_takedown(&notice_id, &verification.admin, &true);
+    client.file_counter_notice(&notice_id, &creator, &String::from_str(&env, "original work"));
+    client.uphold_takedown(&notice_id, &verification.admin);
+    assert_eq!(client.get_takedown_notice(&notice_id).status, TakedownStatus::Upheld);
+
+    env.ledger().with_mut(|li| li.timestamp += crate::takedown::REINSTATEMENT_DELAY);
+    client.reinstate_content(&notice_id);
+}
+
+#[test]
+#[should_panic(expected = "counter-notice deadline passed")]
+fn test_counter_notice_after_deadline() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let verification = setup_verification(&env, &client);
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Claimed"), &BytesN::random(&env), &vec![&env]);
+    let notice_id = client.file_takedown(&content_id, &Address::generate(&env), &String::from_str(&env, "mine"));
+    client.review_takedown(&notice_id, &verification.admin, &true);
+    env.ledger().with_mut(|li| li.timestamp += crate::takedown::COUNTER_NOTICE_WINDOW + 1);
+    client.file_counter_notice(&notice_id, &creator, &String::from_str(&env, "original work"));
+}
+
+#[test]
+fn test_uncountered_takedown_settled_after_deadline() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let verification = setup_verification(&env, &client);
+    let creator = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Claimed"), &BytesN::random(&env), &vec![&env]);
+    let notice_id = client.file_takedown(&content_id, &Address::generate(&env), &String::from_str(&env, "mine"));
+    client.review_takedown(&notice_id, &verification.admin, &true);
+
+    // The notice cannot be settled while the creator can still answer
+    assert!(client.try_settle_takedown(&notice_id).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += crate::takedown::COUNTER_NOTICE_WINDOW + 1);
+    client.settle_takedown(&notice_id);
+    assert_eq!(client.get_takedown_notice(&notice_id).status, TakedownStatus::Upheld);
+    assert!(client.get_tombstone(&content_id).is_some());
+    assert_eq!(client.get_audit_trail(&content_id).last().unwrap().action, AuditAction::TakedownUpheld);
+}
+
+#[test]
+fn test_rejected_takedown_and_repeat_notices() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let verification = setup_verification(&env, &client);
+    let creator = Address::generate(&env);
+    let claimant = Address::generate(&env);
+    let other_claimant = Address::generate(&env);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Claimed"), &BytesN::random(&env), &vec![&env]);
+    let claim = String::from_str(&env, "mine");
+
+    // One unresolved notice per content
+    let notice_id = client.file_takedown(&content_id, &claimant, &claim);
+    assert!(client.try_file_takedown(&content_id, &other_claimant, &claim).is_err());
+
+    // Rejecting leaves the content up and lets other claimants file
+    client.review_takedown(&notice_id, &verification.admin, &false);
+    assert_eq!(client.get_takedown_notice(&notice_id).status, TakedownStatus::Rejected);
+    assert!(client.get_tombstone(&content_id).is_none());
+    client.file_takedown(&content_id, &other_claimant, &claim);
+
+    // A claimant cannot repeat its notice
+    client.withdraw_takedown(&(notice_id + 1), &other_claimant);
+    assert!(client.try_file_takedown(&content_id, &claimant, &claim).is_err());
+    assert!(client.try_file_takedown(&content_id, &other_claimant, &claim).is_err());
+}
+
+// ========================================
+// MODERATOR TESTS
+// ========================================
+
+#[test]
+fn test_removal_requires_quorum() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let (admin, moderators) = setup_moderators(&env, &client, 3);
+    client.set_moderation_quorum(&admin, &2);
+    assert_eq!(client.get_moderation_policy(), (2, 3));
+    assert_eq!(client.get_moderators().len(), 3);
+
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Voted"), &BytesN::random(&env), &vec![&env]);
+    let reason = String::from_str(&env, "abuse");
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &reason);
+    assert!(client.get_tombstone(&content_id).is_none());
+    assert_eq!(client.get_removal_votes(&content_id).len(), 1);
+
+    client.moderate_content(&content_id, &moderators.get(1).unwrap(), &ModerationStatus::Removed, &reason);
+    assert!(client.get_tombstone(&content_id).is_some());
+    assert_eq!(client.get_removal_votes(&content_id).len(), 0);
+
+    let info = client.get_moderator(&moderators.get(0).unwrap()).unwrap();
+    assert_eq!((info.votes, info.decisions, info.overturned), (1, 1, 0));
+    assert_eq!(client.get_moderator(&moderators.get(2).unwrap()).unwrap().votes, 0);
+}
+
+#[test]
+fn test_removed_moderator_votes_do_not_count() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let (admin, moderators) = setup_moderators(&env, &client, 3);
+    client.set_moderation_quorum(&admin, &2);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Voted"), &BytesN::random(&env), &vec![&env]);
+    let reason = String::from_str(&env, "abuse");
+
+    // The first vote is dropped once its moderator leaves the registry
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &reason);
+    client.remove_moderator(&admin, &moderators.get(0).unwrap());
+    assert_eq!(client.get_removal_votes(&content_id).len(), 0);
+
+    client.moderate_content(&content_id, &moderators.get(1).unwrap(), &ModerationStatus::Removed, &reason);
+    assert!(client.get_tombstone(&content_id).is_none());
+    client.moderate_content(&content_id, &moderators.get(2).unwrap(), &ModerationStatus::Removed, &reason);
+    assert!(client.get_tombstone(&content_id).is_some());
+}
+
+#[test]
+#[should_panic(expected = "quorum exceeds the active moderators")]
+fn test_quorum_cannot_exceed_moderators() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let (admin, _) = setup_moderators(&env, &client, 2);
+    client.set_moderation_quorum(&admin, &3);
+}
+
+#[test]
+#[should_panic(expected = "quorum exceeds the active moderators")]
+fn test_moderator_removal_keeps_quorum_reachable() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let (admin, moderators) = setup_moderators(&env, &client, 3);
+    client.set_moderation_quorum(&admin, &2);
+    client.remove_moderator(&admin, &moderators.get(0).unwrap());
+    assert_eq!(client.get_moderators().len(), 2);
+
+    // Removing another would leave a single moderator under a quorum of 2
+    client.remove_moderator(&admin, &moderators.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "moderator already voted")]
+fn test_duplicate_removal_vote() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let (admin, moderators) = setup_moderators(&env, &client, 2);
+    client.set_moderation_quorum(&admin, &2);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Voted"), &BytesN::random(&env), &vec![&env]);
+    let reason = String::from_str(&env, "abuse");
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &reason);
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &reason);
+}
+
+#[test]
+#[should_panic(expected = "not a moderator")]
+fn test_moderate_content_requires_moderator() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    setup_moderators(&env, &client, 1);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Unmoderated"), &BytesN::random(&env), &vec![&env]);
+    client.moderate_content(&content_id, &Address::generate(&env), &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+}
+
+#[test]
+#[should_panic(expected = "not a moderator")]
+fn test_removed_moderator_cannot_vote() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let (admin, moderators) = setup_moderators(&env, &client, 2);
+    let moderator = moderators.get(0).unwrap();
+    client.remove_moderator(&admin, &moderator);
+    assert!(client.get_moderator(&moderator).unwrap().removed_at.is_some());
+
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Unmoderated"), &BytesN::random(&env), &vec![&env]);
+    client.moderate_content(&content_id, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+}
+
+#[test]
+#[should_panic(expected = "moderator has a conflict of interest")]
+fn test_flagger_cannot_moderate() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let moderator = setup_moderators(&env, &client, 1).1.get(0).unwrap();
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Flagged"), &BytesN::random(&env), &vec![&env]);
+    client.flag_content(&content_id, &moderator, &String::from_str(&env, "spam"));
+    client.moderate_content(&content_id, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "spam"));
+}
+
+#[test]
+fn test_flags_escalate_content() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let (admin, moderators) = setup_moderators(&env, &client, 1);
+    client.set_escalation_threshold(&admin, &2);
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Flagged"), &BytesN::random(&env), &vec![&env]);
+
+    client.flag_content(&content_id, &Address::generate(&env), &String::from_str(&env, "spam"));
+    assert_eq!(client.get_escalated_content().len(), 0);
+    client.flag_content(&content_id, &Address::generate(&env), &String::from_str(&env, "spam"));
+    assert_eq!(client.get_escalated_content(), vec![&env, content_id]);
+
+    // A decision takes the content off the queue
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Rejected, &String::from_str(&env, "not spam"));
+    assert_eq!(client.get_escalated_content().len(), 0);
+}
+
+#[test]
+fn test_overturned_decisions_tracked() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let moderators = setup_moderators(&env, &client, 2).1;
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Appealed"), &BytesN::random(&env), &vec![&env]);
+    client.moderate_content(&content_id, &moderators.get(0).unwrap(), &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+
+    let dispute_id = client.create_dispute(&content_id, &creator, &String::from_str(&env, "unfair removal"));
+    client.resolve_dispute(&dispute_id, &moderators.get(1).unwrap(), &true);
+
+    let decider = client.get_moderator(&moderators.get(0).unwrap()).unwrap();
+    assert_eq!((decider.decisions, decider.overturned), (1, 1));
+    assert_eq!(client.get_moderator(&moderators.get(1).unwrap()).unwrap().appeals_resolved, 1);
+}
+
+#[test]
+fn test_only_creator_appeals_moderation_removal() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let moderator = setup_moderators(&env, &client, 1).1.get(0).unwrap();
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Appealed"), &BytesN::random(&env), &vec![&env]);
+    let reason = String::from_str(&env, "unfair removal");
+
+    // Nothing to appeal while the content is live
+    assert!(client.try_create_dispute(&content_id, &creator, &reason).is_err());
+
+    client.moderate_content(&content_id, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+    assert!(client.try_create_dispute(&content_id, &Address::generate(&env), &reason).is_err());
+
+    client.create_dispute(&content_id, &creator, &reason);
+    assert_eq!(env.auths()[0].0, creator);
+}
+
+#[test]
+#[should_panic(expected = "moderator cannot resolve an appeal of own decision")]
+fn test_decider_cannot_resolve_appeal() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let moderator = setup_moderators(&env, &client, 1).1.get(0).unwrap();
+    let content_id = client.publish_content(&creator, &String::from_str(&env, "Appealed"), &BytesN::random(&env), &vec![&env]);
+    client.moderate_content(&content_id, &moderator, &ModerationStatus::Removed, &String::from_str(&env, "abuse"));
+
+    let dispute_id = client.create_dispute(&content_id, &creator, &String::from_str(&env, "unfair removal"));
+    client.resolve_dispute(&dispute_id, &moderator, &true);
+}
+
+// ========================================
+// COLLECTION TESTS
+// ========================================
+
+// Publish `count` contents and put them in a new collection, in order
+fn setup_collection(env: &Env, client: &TokenizedEducationalContentClient, owner: &Address, count: u32) -> (u64, Vec<u64>) {
+    let collection_id = client.create_collection(
+        owner,
+        &String::from_str(env, "Algebra Course"),
+        &String::from_str(env, "From equations to polynomials"),
+        &vec![env, String::from_str(env, "math")],
+    );
+    let mut items = Vec::new(env);
+    for _ in 0..count {
+        let content_id = client.publish_content(owner, &String::from_str(env, "Lesson"), &BytesN::random(env), &vec![env]);
+        client.add_collection_item(&collection_id, owner, &content_id);
+        items.push_back(content_id);
+    }
+    (collection_id, items)
+}
+
+#[test]
+fn test_collection_learning_path() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (collection_id, items) = setup_collection(&env, &client, &owner, 3);
+    let (first, second, third) = (items.get(0).unwrap(), items.get(1).unwrap(), items.get(2).unwrap());
+    client.add_prerequisite(&collection_id, &owner, &third, &first);
+
+    let collection = client.get_collection(&collection_id);
+    assert_eq!(collection.items, items);
+    assert_eq!(collection.prerequisites.len(), 1);
+
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env]), Some(first));
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env, first]), Some(second));
+    // The third item waits for the first one
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env, second]), Some(first));
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env, first, second]), Some(third));
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env, first, second, third]), None);
+
+    // Reordering and removing items keep the path consistent
+    client.move_collection_item(&collection_id, &owner, &second, &0);
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env]), Some(second));
+    client.remove_collection_item(&collection_id, &owner, &first);
+    let collection = client.get_collection(&collection_id);
+    assert_eq!(collection.items, vec![&env, second, third]);
+    assert_eq!(collection.prerequisites.len(), 0);
+
+    // Removed content is skipped
+    client.delete_content(&second, &owner);
+    assert_eq!(client.get_next_item(&collection_id, &vec![&env]), Some(third));
+}
+
+#[test]
+fn test_collection_curator() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let curator = Address::generate(&env);
+    let (collection_id, _) = setup_collection(&env, &client, &owner, 1);
+    assert!(client.grant_curator(&collection_id, &owner, &curator));
+
+    let permission = client.get_curator_permission(&curator, &collection_id).unwrap();
+    assert_eq!(permission.content_id, collection_id);
+    assert_eq!(permission.granted_by, owner);
+
+    let content_id = client.publish_content(&Address::generate(&env), &String::from_str(&env, "Guest Lesson"), &BytesN::random(&env), &vec![&env]);
+    client.add_collection_item(&collection_id, &curator, &content_id);
+    assert_eq!(client.get_collection(&collection_id).items.len(), 2);
+
+    client.revoke_curator(&collection_id, &owner, &curator);
+    assert!(client.get_curator_permission(&curator, &collection_id).is_none());
+}
+
+#[test]
+#[should_panic(expected = "No permission to curate collection")]
+fn test_collection_requires_curator() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (collection_id, items) = setup_collection(&env, &client, &owner, 2);
+    client.move_collection_item(&collection_id, &Address::generate(&env), &items.get(1).unwrap(), &0);
+}
+
+#[test]
+#[should_panic(expected = "prerequisite must come earlier in the collection")]
+fn test_prerequisite_must_come_earlier() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (collection_id, items) = setup_collection(&env, &client, &owner, 2);
+    client.add_prerequisite(&collection_id, &owner, &items.get(0).unwrap(), &items.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "move breaks a prerequisite")]
+fn test_move_breaks_prerequisite() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (collection_id, items) = setup_collection(&env, &client, &owner, 2);
+    client.add_prerequisite(&collection_id, &owner, &items.get(1).unwrap(), &items.get(0).unwrap());
+    client.move_collection_item(&collection_id, &owner, &items.get(1).unwrap(), &0);
+}
+
+#[test]
+fn test_collection_analytics() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (collection_id, items) = setup_collection(&env, &client, &owner, 2);
+    let viewer = Address::generate(&env);
+    client.record_content_view(&items.get(0).unwrap(), &viewer, &None);
+    client.record_content_view(&items.get(1).unwrap(), &viewer, &None);
+    client.record_content_upvote(&items.get(1).unwrap(), &viewer, &None);
+
+    let analytics = client.get_collection_analytics(&collection_id);
+    assert_eq!(analytics.item_count, 2);
+    assert_eq!(analytics.total_views, 2);
+    assert_eq!(analytics.total_upvotes, 1);
+    assert_eq!(analytics.unique_viewers, 2);
+    assert_eq!(analytics.engagement_rate, 5000);
+}
+
+#[test]
+fn test_collection_analytics_sums_do_not_overflow() {
+    let env = Env::default();
+    let contract_id = env.register(TokenizedEducationalContent, (Address::generate(&env),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (collection_id, items) = setup_collection(&env, &client, &owner, 2);
+    env.as_contract(&contract_id, || {
+        for content_id in items.iter() {
+            crate::storage::save_content_analytics(&env, &ContentAnalytics {
+                content_id,
+                total_views: 1,
+                total_upvotes: u32::MAX,
+                total_downvotes: u32::MAX,
+                engagement_rate: 0,
+                average_rating: 0,
+                trending_score: 0,
+                last_updated: 0,
+                unique_viewers: 1,
+            });
+        }
+    });
+
+    let analytics = client.get_collection_analytics(&collection_id);
+    assert_eq!(analytics.total_upvotes, 2 * u32::MAX as u64);
+    assert_eq!(analytics.total_downvotes, 2 * u32::MAX as u64);
+    assert_eq!(analytics.engagement_rate, u32::MAX);
+}
+
+#[test]
+fn test_initialize_requires_deployer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TokenizedEducationalContent, (deployer.clone(),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &Address::generate(&env), &Address::generate(&env));
+    assert_eq!(env.auths()[0].0, deployer);
+    assert_eq!(env.auths()[1].0, admin);
+}
+
+#[test]
+fn test_deployer_can_initialize_as_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let contract_id = env.register(TokenizedEducationalContent, (deployer.clone(),));
+    let client = TokenizedEducationalContentClient::new(&env, &contract_id);
+
+    client.initialize(&deployer, &Address::generate(&env), &Address::generate(&env));
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, deployer);
 }
@@ -4,10 +4,23 @@ use soroban_sdk::{
 
 use crate::storage::{
     TrendingContent, TrendingSnapshot, TrendingPeriod, ContentAnalytics, TimeBasedMetrics, TimePeriod,
+    Content, EngagementKind, LeaderboardEntry,
     save_trending_content, get_trending_content, save_trending_snapshot, get_trending_snapshot,
-    get_content_analytics, get_time_based_metrics, get_all_content_ids, content_exists
+    save_trending_score, get_trending_score, save_leaderboard, get_leaderboard,
+    get_content_analytics, get_time_based_metrics, get_content, content_exists
 };
 
+/// Entries kept on each leaderboard
+pub const LEADERBOARD_SIZE: u32 = 50;
+/// Scores halve this many times over a period's time window
+const HALF_LIVES_PER_WINDOW: u64 = 4;
+/// Score added by a view, scaled to leave room for decay
+const VIEW_WEIGHT: u64 = 1_000;
+/// Score added by an upvote
+const UPVOTE_WEIGHT: u64 = 4_000;
+/// Score removed by a downvote
+const DOWNVOTE_WEIGHT: u64 = 2_000;
+
 /// Trending module for calculating and managing trending content
 pub struct Trending;
 
@@ -87,39 +100,13 @@ impl Trending {
         Ok(())
     }
 
-    /// Get trending content for a specific period
+    /// Get trending content for a specific period, read from the stored leaderboard
     pub fn get_trending_content(
         env: &Env,
         period: TrendingPeriod,
         limit: u32
     ) -> Vec<TrendingContent> {
-        let all_content_ids = get_all_content_ids(env);
-        let mut trending_list = Vec::new(env);
-
-        // Collect trending data for all content
-        for i in 0..all_content_ids.len() {
-            let content_id = all_content_ids.get(i).unwrap();
-            if let Some(trending) = get_trending_content(env, content_id, period) {
-                trending_list.push_back(trending);
-            }
-        }
-
-        // Sort by trending score (descending)
-        Self::sort_trending_by_score(&mut trending_list);
-
-        // Return top results
-        let mut result = Vec::new(env);
-        let max_items = if trending_list.len() > limit {
-            limit
-        } else {
-            trending_list.len()
-        };
-
-        for i in 0..max_items {
-            result.push_back(trending_list.get(i).unwrap());
-        }
-
-        result
+        Self::to_trending_content(env, Self::get_leaderboard(env, None, period, 0, limit), period)
     }
 
     /// Create a trending snapshot for a specific period
@@ -127,16 +114,15 @@ impl Trending {
         env: &Env,
         period: TrendingPeriod
     ) -> Result<TrendingSnapshot, String> {
-        let trending_content = Self::get_trending_content(env, period, 50); // Top 50
+        let leaderboard = Self::get_leaderboard(env, None, period, 0, LEADERBOARD_SIZE);
         let current_time = env.ledger().timestamp();
 
         let mut content_ids = Vec::new(env);
         let mut scores = Vec::new(env);
 
-        for i in 0..trending_content.len() {
-            let content = trending_content.get(i).unwrap();
-            content_ids.push_back(content.content_id);
-            scores.push_back(content.trending_score);
+        for entry in leaderboard.iter() {
+            content_ids.push_back(entry.content_id);
+            scores.push_back(Self::to_u32(entry.score));
         }
 
         let snapshot = TrendingSnapshot {
@@ -167,36 +153,86 @@ impl Trending {
         Ok(())
     }
 
-    /// Get trending content by category
+    /// Get trending content by category, read from the category leaderboard
     pub fn get_trending_content_by_category(
         env: &Env,
         category: &String,
         period: TrendingPeriod,
         limit: u32
     ) -> Vec<TrendingContent> {
-        let all_trending = Self::get_trending_content(env, period, 100); // Get more to filter
-        let mut category_trending = Vec::new(env);
-
-        for i in 0..all_trending.len() {
-            let trending = all_trending.get(i).unwrap();
-            let content = crate::storage::get_content(env, trending.content_id);
-            
-            // Check if content belongs to the category
-            for j in 0..content.subject_tags.len() {
-                let tag = content.subject_tags.get(j).unwrap();
-                if tag == *category {
-                    category_trending.push_back(trending);
-                    break;
-                }
+        let leaderboard = Self::get_leaderboard(env, Some(category.clone()), period, 0, limit);
+        Self::to_trending_content(env, leaderboard, period)
+    }
+
+    /// Add a counted engagement to the decayed scores of a content and to the
+    /// global and category leaderboards of every period
+    pub fn record_engagement(env: &Env, content_id: u64, kind: EngagementKind) {
+        let current_time = env.ledger().timestamp();
+        let content = get_content(env, content_id);
+
+        for period in [TrendingPeriod::Daily, TrendingPeriod::Weekly, TrendingPeriod::Monthly] {
+            let previous = get_trending_score(env, content_id, period)
+                .map(|entry| Self::decayed_score(&entry, period, current_time))
+                .unwrap_or(0);
+            let score = match kind {
+                EngagementKind::View => previous.saturating_add(VIEW_WEIGHT),
+                EngagementKind::Upvote => previous.saturating_add(UPVOTE_WEIGHT),
+                EngagementKind::Downvote => previous.saturating_sub(DOWNVOTE_WEIGHT),
+            };
+
+            let entry = LeaderboardEntry {
+                content_id,
+                score,
+                updated_at: current_time,
+            };
+            save_trending_score(env, period, &entry);
+
+            Self::update_leaderboard(env, None, period, &entry);
+            for tag in content.subject_tags.iter() {
+                Self::update_leaderboard(env, Some(tag), period, &entry);
+            }
+        }
+    }
+
+    /// Drop a removed content from the global and category leaderboards
+    pub fn remove_content(env: &Env, content: &Content) {
+        for period in [TrendingPeriod::Daily, TrendingPeriod::Weekly, TrendingPeriod::Monthly] {
+            Self::remove_from_leaderboard(env, None, period, content.id);
+            for tag in content.subject_tags.iter() {
+                Self::remove_from_leaderboard(env, Some(tag), period, content.id);
             }
+        }
+    }
 
-            // Stop if we have enough results
-            if category_trending.len() >= limit {
+    /// Page through a stored leaderboard (global when no category is given),
+    /// highest score first, with scores decayed to the current time
+    pub fn get_leaderboard(
+        env: &Env,
+        category: Option<String>,
+        period: TrendingPeriod,
+        offset: u32,
+        limit: u32
+    ) -> Vec<LeaderboardEntry> {
+        let current_time = env.ledger().timestamp();
+        let leaderboard = get_leaderboard(env, category, period);
+        let end = offset.saturating_add(limit).min(leaderboard.len());
+
+        let mut page = Vec::new(env);
+        for i in offset..end {
+            let entry = leaderboard.get(i).unwrap();
+            let score = Self::decayed_score(&entry, period, current_time);
+            // Fully decayed entries sit at the end of the board
+            if score == 0 {
                 break;
             }
+            page.push_back(LeaderboardEntry {
+                content_id: entry.content_id,
+                score,
+                updated_at: current_time,
+            });
         }
 
-        category_trending
+        page
     }
 
     // Private helper methods
@@ -304,20 +340,81 @@ impl Trending {
         (base_score * decay_factor) / 10000
     }
 
-    /// Sort trending content by score (descending)
-    fn sort_trending_by_score(trending_list: &mut Vec<TrendingContent>) {
-        let len = trending_list.len();
-        for i in 0..len {
-            for j in 0..len - i - 1 {
-                let current = trending_list.get(j).unwrap();
-                let next = trending_list.get(j + 1).unwrap();
-                
-                if current.trending_score < next.trending_score {
-                    // Swap elements
-                    trending_list.set(j, next);
-                    trending_list.set(j + 1, current);
-                }
+    /// Move an updated entry to its place in a leaderboard and keep the top entries
+    fn update_leaderboard(
+        env: &Env,
+        category: Option<String>,
+        period: TrendingPeriod,
+        entry: &LeaderboardEntry
+    ) {
+        let leaderboard = get_leaderboard(env, category.clone(), period);
+        let mut updated = Vec::new(env);
+        // Entries without a score drop off the board
+        let mut placed = entry.score == 0;
+
+        for existing in leaderboard.iter() {
+            if existing.content_id == entry.content_id {
+                continue;
             }
+            if !placed && entry.score >= Self::decayed_score(&existing, period, entry.updated_at) {
+                updated.push_back(entry.clone());
+                placed = true;
+            }
+            updated.push_back(existing);
+        }
+        if !placed {
+            updated.push_back(entry.clone());
+        }
+        while updated.len() > LEADERBOARD_SIZE {
+            updated.pop_back();
         }
+
+        save_leaderboard(env, category, period, &updated);
+    }
+
+    fn remove_from_leaderboard(env: &Env, category: Option<String>, period: TrendingPeriod, content_id: u64) {
+        let leaderboard = get_leaderboard(env, category.clone(), period);
+        if let Some(index) = leaderboard.iter().position(|entry| entry.content_id == content_id) {
+            let mut updated = leaderboard;
+            updated.remove(index as u32);
+            save_leaderboard(env, category, period, &updated);
+        }
+    }
+
+    /// Score of an entry decayed to `current_time`. Scores halve together at fixed
+    /// half-life boundaries, so a later decay shifts every entry by the same amount
+    /// and keeps the order of the entries; boards are only decayed when read or updated.
+    fn decayed_score(entry: &LeaderboardEntry, period: TrendingPeriod, current_time: u64) -> u64 {
+        let half_life = Self::get_time_window_for_period(period) / HALF_LIVES_PER_WINDOW;
+        let halvings = (current_time / half_life).saturating_sub(entry.updated_at / half_life);
+        if halvings >= 64 {
+            return 0;
+        }
+        entry.score >> halvings
+    }
+
+    /// Attach the stored score details to leaderboard entries
+    fn to_trending_content(
+        env: &Env,
+        leaderboard: Vec<LeaderboardEntry>,
+        period: TrendingPeriod
+    ) -> Vec<TrendingContent> {
+        let mut result = Vec::new(env);
+        for entry in leaderboard.iter() {
+            let details = get_trending_content(env, entry.content_id, period);
+            result.push_back(TrendingContent {
+                content_id: entry.content_id,
+                trending_score: Self::to_u32(entry.score),
+                velocity_score: details.as_ref().map_or(0, |d| d.velocity_score),
+                time_weighted_score: details.as_ref().map_or(0, |d| d.time_weighted_score),
+                period,
+                calculated_at: entry.updated_at,
+            });
+        }
+        result
+    }
+
+    fn to_u32(score: u64) -> u32 {
+        score.min(u32::MAX as u64) as u32
     }
-} 
\ No newline at end of file
+}
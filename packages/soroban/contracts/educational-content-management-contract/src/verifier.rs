@@ -0,0 +1,258 @@
+use soroban_sdk::{symbol_short, token, vec, Address, Env, IntoVal, Symbol, Vec};
+use crate::storage::{AdvDataKey, VerificationLevel, VerifierInfo};
+
+/// Slash rate used until one is configured: half of the stake
+pub const DEFAULT_SLASH_RATE: u32 = 5_000;
+/// Time a removed verifier waits before withdrawing its stake, so pending
+/// disputes can still slash it
+pub const UNBONDING_PERIOD: u64 = 14 * 24 * 60 * 60;
+
+// Record the account allowed to initialize the registry
+pub fn set_deployer(env: &Env, deployer: &Address) {
+    env.storage().instance().set(&AdvDataKey::Deployer, deployer);
+}
+
+pub fn get_deployer(env: &Env) -> Address {
+    env.storage().instance().get(&AdvDataKey::Deployer).unwrap_or_else(|| panic!("deployer not set"))
+}
+
+// Set up the verifier registry
+pub fn initialize(env: &Env, admin: Address, stake_token: Address, reputation_contract: Address) {
+    if env.storage().instance().has(&AdvDataKey::Admin) {
+        panic!("already initialized");
+    }
+    env.storage().instance().set(&AdvDataKey::Admin, &admin);
+    env.storage().instance().set(&AdvDataKey::StakeToken, &stake_token);
+    env.storage().instance().set(&AdvDataKey::ReputationContract, &reputation_contract);
+    env.events().publish((symbol_short!("INIT"), admin), (stake_token, reputation_contract));
+}
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage().instance().get(&AdvDataKey::Admin).unwrap_or_else(|| panic!("not initialized"))
+}
+
+// Let a governance contract manage verifiers alongside the admin
+pub fn set_governance(env: &Env, admin: Address, governance: Address) {
+    if admin != get_admin(env) {
+        panic!("only the admin can set governance");
+    }
+    env.storage().instance().set(&AdvDataKey::Governance, &governance);
+    env.events().publish((symbol_short!("GOVERN"), admin), governance);
+}
+
+// Check that `manager` is the admin or governance
+pub fn require_manager(env: &Env, manager: &Address) {
+    let governance: Option<Address> = env.storage().instance().get(&AdvDataKey::Governance);
+    if *manager != get_admin(env) && governance.as_ref() != Some(manager) {
+        panic!("only admin or governance can manage verifiers");
+    }
+}
+
+// Register a verifier for `level`, or move it to another level
+pub fn add_verifier(env: &Env, manager: Address, verifier: Address, level: VerificationLevel) {
+    require_manager(env, &manager);
+    if level == VerificationLevel::None {
+        panic!("verifier level cannot be None");
+    }
+
+    let info = match get_verifier(env, &verifier) {
+        Some(mut info) => {
+            remove_from_level(env, &verifier, info.level);
+            info.level = level;
+            info.removed_at = None;
+            info
+        }
+        None => VerifierInfo {
+            verifier: verifier.clone(),
+            level,
+            stake: 0,
+            registered_at: env.ledger().timestamp(),
+            removed_at: None,
+            slashed: 0,
+        },
+    };
+    save_verifier(env, &info);
+
+    let mut verifiers = get_verifiers_by_level(env, level);
+    verifiers.push_back(verifier.clone());
+    env.storage().instance().set(&AdvDataKey::VerifiersByLevel(level), &verifiers);
+
+    env.events().publish((symbol_short!("VRF_ADD"), verifier), level as u32);
+}
+
+// Remove a verifier from the registry. Its stake unbonds over `UNBONDING_PERIOD`.
+pub fn remove_verifier(env: &Env, manager: Address, verifier: Address) {
+    require_manager(env, &manager);
+    let mut info = get_verifier(env, &verifier).unwrap_or_else(|| panic!("verifier not registered"));
+    if info.removed_at.is_some() {
+        panic!("verifier already removed");
+    }
+
+    remove_from_level(env, &verifier, info.level);
+    info.removed_at = Some(env.ledger().timestamp());
+    save_verifier(env, &info);
+
+    env.events().publish((symbol_short!("VRF_REM"), verifier), ());
+}
+
+pub fn get_verifier(env: &Env, verifier: &Address) -> Option<VerifierInfo> {
+    env.storage().instance().get(&AdvDataKey::Verifier(verifier.clone()))
+}
+
+pub fn get_verifiers_by_level(env: &Env, level: VerificationLevel) -> Vec<Address> {
+    env.storage().instance().get(&AdvDataKey::VerifiersByLevel(level)).unwrap_or(Vec::new(env))
+}
+
+// Stake verifiers of `level` must keep to verify
+pub fn set_min_stake(env: &Env, manager: Address, level: VerificationLevel, amount: i128) {
+    require_manager(env, &manager);
+    if amount < 0 {
+        panic!("minimum stake cannot be negative");
+    }
+    env.storage().instance().set(&AdvDataKey::MinStake(level), &amount);
+}
+
+pub fn get_min_stake(env: &Env, level: VerificationLevel) -> i128 {
+    env.storage().instance().get(&AdvDataKey::MinStake(level)).unwrap_or(0)
+}
+
+// Reputation a verifier needs to verify at a level with `verify_content_advanced`
+pub fn set_min_reputation(env: &Env, manager: Address, level: VerificationLevel, reputation: u32) {
+    require_manager(env, &manager);
+    env.storage().instance().set(&AdvDataKey::MinReputation(level), &reputation);
+}
+
+pub fn get_min_reputation(env: &Env, level: VerificationLevel) -> u32 {
+    env.storage().instance().get(&AdvDataKey::MinReputation(level)).unwrap_or(0)
+}
+
+// Share of the stake slashed when a verification is overturned, in basis points
+pub fn set_slash_rate(env: &Env, manager: Address, rate: u32) {
+    require_manager(env, &manager);
+    if rate > 10_000 {
+        panic!("slash rate cannot exceed 10000 basis points");
+    }
+    env.storage().instance().set(&AdvDataKey::SlashRate, &rate);
+}
+
+pub fn get_slash_rate(env: &Env) -> u32 {
+    env.storage().instance().get(&AdvDataKey::SlashRate).unwrap_or(DEFAULT_SLASH_RATE)
+}
+
+// Deposit stake in the stake token
+pub fn stake(env: &Env, verifier: Address, amount: i128) -> i128 {
+    if amount <= 0 {
+        panic!("stake amount must be positive");
+    }
+    let mut info = get_verifier(env, &verifier).unwrap_or_else(|| panic!("verifier not registered"));
+
+    stake_token(env).transfer(&verifier, &env.current_contract_address(), &amount);
+    info.stake += amount;
+    save_verifier(env, &info);
+
+    env.events().publish((symbol_short!("STAKE"), verifier), (amount, info.stake));
+    info.stake
+}
+
+// Withdraw stake. Registered verifiers keep the minimum of their level, removed
+// verifiers withdraw once the unbonding period is over.
+pub fn unstake(env: &Env, verifier: Address, amount: i128) -> i128 {
+    if amount <= 0 {
+        panic!("unstake amount must be positive");
+    }
+    let mut info = get_verifier(env, &verifier).unwrap_or_else(|| panic!("verifier not registered"));
+    if amount > info.stake {
+        panic!("insufficient stake");
+    }
+
+    match info.removed_at {
+        Some(removed_at) => {
+            if env.ledger().timestamp() < removed_at + UNBONDING_PERIOD {
+                panic!("stake is still unbonding");
+            }
+        }
+        None => {
+            if info.stake - amount < get_min_stake(env, info.level) {
+                panic!("stake would fall below the level minimum");
+            }
+        }
+    }
+
+    info.stake -= amount;
+    save_verifier(env, &info);
+    stake_token(env).transfer(&env.current_contract_address(), &verifier, &amount);
+
+    env.events().publish((symbol_short!("UNSTAKE"), verifier), (amount, info.stake));
+    info.stake
+}
+
+// Check that `verifier` is registered for `level` or above and holds the minimum stake
+pub fn require_verifier(env: &Env, verifier: &Address, level: VerificationLevel) {
+    let info = get_verifier(env, verifier).unwrap_or_else(|| panic!("verifier not registered"));
+    if info.removed_at.is_some() {
+        panic!("verifier not registered");
+    }
+    if info.level < level {
+        panic!("verifier not registered for this level");
+    }
+    if info.stake < get_min_stake(env, info.level) {
+        panic!("verifier stake below the level minimum");
+    }
+}
+
+// Look up a reputation score in the configured reputation contract
+pub fn get_reputation(env: &Env, user: &Address) -> u32 {
+    let reputation_contract: Address = env
+        .storage()
+        .instance()
+        .get(&AdvDataKey::ReputationContract)
+        .unwrap_or_else(|| panic!("not initialized"));
+    env.invoke_contract::<u32>(
+        &reputation_contract,
+        &Symbol::new(env, "get_reputation"),
+        vec![env, user.into_val(env)],
+    )
+}
+
+// Slash a verifier whose verification was overturned. The slashed stake goes to the admin.
+pub fn slash(env: &Env, verifier: &Address, dispute_id: u64) -> i128 {
+    let mut info = match get_verifier(env, verifier) {
+        Some(info) => info,
+        None => return 0,
+    };
+
+    let amount = info.stake * get_slash_rate(env) as i128 / 10_000;
+    if amount == 0 {
+        return 0;
+    }
+
+    info.stake -= amount;
+    info.slashed += amount;
+    save_verifier(env, &info);
+    stake_token(env).transfer(&env.current_contract_address(), &get_admin(env), &amount);
+
+    env.events().publish((symbol_short!("SLASH"), verifier.clone()), (dispute_id, amount));
+    amount
+}
+
+fn stake_token(env: &Env) -> token::Client<'_> {
+    let token_address: Address = env
+        .storage()
+        .instance()
+        .get(&AdvDataKey::StakeToken)
+        .unwrap_or_else(|| panic!("not initialized"));
+    token::Client::new(env, &token_address)
+}
+
+fn save_verifier(env: &Env, info: &VerifierInfo) {
+    env.storage().instance().set(&AdvDataKey::Verifier(info.verifier.clone()), info);
+}
+
+fn remove_from_level(env: &Env, verifier: &Address, level: VerificationLevel) {
+    let verifiers = get_verifiers_by_level(env, level);
+    if let Some(index) = verifiers.first_index_of(verifier) {
+        let mut verifiers = verifiers;
+        verifiers.remove(index);
+        env.storage().instance().set(&AdvDataKey::VerifiersByLevel(level), &verifiers);
+    }
+}
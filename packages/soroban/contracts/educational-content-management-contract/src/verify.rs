@@ -1,5 +1,9 @@
 use soroban_sdk::{Address, Env, symbol_short, Vec};
-use crate::storage::{get_content, save_content, VerificationLevel, VerificationRecord, Delegation, AdvDataKey};
+use crate::storage::{content_exists, get_content, get_content_counter, is_removed, save_content, Content, VerificationLevel, VerificationRecord, Delegation, AdvDataKey};
+use crate::verifier;
+
+/// Renewal reminder window used until one is configured
+pub const DEFAULT_REMINDER_WINDOW: u64 = 7 * 24 * 60 * 60;
 
 // Verify educational content with different tiers
 pub fn verify_content(
@@ -8,13 +12,28 @@ pub fn verify_content(
     verifier: Address,
     level: VerificationLevel,
 ) -> VerificationLevel {
-    let mut content = get_content(env, content_id);
+    let mut content = with_effective_level(env, get_content(env, content_id));
+
+    // Only registered verifiers of the level can verify
+    verifier::require_verifier(env, &verifier, level);
 
     // Prevent overwriting a higher verification level with a lower one
     if level <= content.verification_level {
         panic!("cannot overwrite a higher or equal verification level");
     }
 
+    add_verification_record(env, content_id, VerificationRecord {
+        verifier: verifier.clone(),
+        level,
+        timestamp: env.ledger().timestamp(),
+        expiration: None,
+        delegated_by: None,
+        reputation_snapshot: None,
+        overturned: false,
+        reminder_sent: false,
+        expired: false,
+    });
+
     // Update the verification level
     content.verification_level = level;
 
@@ -40,33 +59,30 @@ pub fn verify_content_advanced(
     verifier: Address,
     level: VerificationLevel,
     delegated_by: Option<Address>,
-    min_reputation: u32,
     expiration_secs: Option<u64>,
 ) -> VerificationLevel {
-    // 1. Reputation check (mock: reputation always 100)
-    let reputation = 100u32;
-    if reputation < min_reputation {
+    // 1. Registry check on the verifier answerable for the verification
+    let accountable = delegated_by.clone().unwrap_or(verifier.clone());
+    verifier::require_verifier(env, &accountable, level);
+
+    // 2. Reputation check against the minimum configured for the level
+    let reputation = verifier::get_reputation(env, &verifier);
+    if reputation < verifier::get_min_reputation(env, level) {
         panic!("Verifier does not meet minimum reputation");
     }
 
-    // 2. Delegation check if applicable
+    // 3. Delegation check if applicable
     if let Some(ref delegator) = delegated_by {
-        let delegations: Vec<Delegation> = env.storage().instance().get(&AdvDataKey::Delegation(delegator.clone())).unwrap_or(Vec::new(env));
-        let now = env.ledger().timestamp();
-        let valid = delegations.iter().any(|d| d.delegatee == verifier && (d.until.is_none() || d.until.unwrap() > now));
-        if !valid {
-            panic!("Delegation not valid");
-        }
+        require_delegation(env, delegator, &verifier);
     }
 
-    // 3. Prevent downgrade
-    let mut content = get_content(env, content_id);
+    // 4. Prevent downgrade
+    let mut content = with_effective_level(env, get_content(env, content_id));
     if level <= content.verification_level {
         panic!("cannot overwrite a higher or equal verification level");
     }
 
-    // 4. Register VerificationRecord
-    let mut records: Vec<VerificationRecord> = env.storage().instance().get(&AdvDataKey::VerificationRecord(content_id)).unwrap_or(Vec::new(env));
+    // 5. Register VerificationRecord
     let now = env.ledger().timestamp();
     let expiration = expiration_secs.map(|secs| now + secs);
     let record = VerificationRecord {
@@ -76,15 +92,17 @@ pub fn verify_content_advanced(
         expiration,
         delegated_by: delegated_by.clone(),
         reputation_snapshot: Some(reputation),
+        overturned: false,
+        reminder_sent: false,
+        expired: false,
     };
-    records.push_back(record);
-    env.storage().instance().set(&AdvDataKey::VerificationRecord(content_id), &records);
+    add_verification_record(env, content_id, record);
 
-    // 5. Update verification_level in Content
+    // 6. Update verification_level in Content
     content.verification_level = level;
     save_content(env, &content);
 
-    // 6. Emit event
+    // 7. Emit event
     env.events().publish((symbol_short!("ADV_VRFY"), content_id, verifier), level as u32);
 
     level
@@ -97,13 +115,21 @@ pub fn renew_verification(
     verifier: Address,
     new_expiration_secs: u64,
 ) {
-    let mut records: Vec<VerificationRecord> = env.storage().instance().get(&AdvDataKey::VerificationRecord(content_id)).unwrap_or(Vec::new(env));
+    let mut records = get_verification_records(env, content_id);
     let now = env.ledger().timestamp();
     let mut found = false;
     for i in 0..records.len() {
         let mut rec = records.get(i).unwrap();
-        if rec.verifier == verifier {
+        if rec.verifier == verifier && !rec.overturned {
+            // The verifier answerable for the record must still be able to grant its level
+            let accountable = rec.delegated_by.clone().unwrap_or(verifier.clone());
+            verifier::require_verifier(env, &accountable, rec.level);
+            if let Some(ref delegator) = rec.delegated_by {
+                require_delegation(env, delegator, &verifier);
+            }
             rec.expiration = Some(now + new_expiration_secs);
+            rec.reminder_sent = false;
+            rec.expired = false;
             records.set(i, rec);
             found = true;
         }
@@ -112,6 +138,11 @@ pub fn renew_verification(
         panic!("No verification record found for this verifier");
     }
     env.storage().instance().set(&AdvDataKey::VerificationRecord(content_id), &records);
+
+    // A renewed record can lift the content back to its level
+    let mut content = get_content(env, content_id);
+    content.verification_level = effective_level(env, content_id, content.verification_level);
+    save_content(env, &content);
     env.events().publish((symbol_short!("RENEW_VRF"), content_id, verifier), new_expiration_secs);
 }
 
@@ -150,4 +181,138 @@ pub fn revoke_delegation(
     }
     env.storage().instance().set(&AdvDataKey::Delegation(delegator), &new_delegations);
     env.events().publish((symbol_short!("REVOKE_DG"), delegatee), ());
-}
\ No newline at end of file
+}
+
+// Get the verification records of a content
+pub fn get_verification_records(env: &Env, content_id: u64) -> Vec<VerificationRecord> {
+    env.storage().instance().get(&AdvDataKey::VerificationRecord(content_id)).unwrap_or(Vec::new(env))
+}
+
+// Overturn the standing verifications of a content after an upheld dispute:
+// the records are marked overturned, the verifiers answerable for them slashed
+// and the content loses its verification level
+pub fn overturn_verification(env: &Env, content_id: u64, dispute_id: u64) {
+    let mut records = get_verification_records(env, content_id);
+    for i in 0..records.len() {
+        let mut record = records.get(i).unwrap();
+        if record.overturned {
+            continue;
+        }
+        let accountable = record.delegated_by.clone().unwrap_or(record.verifier.clone());
+        verifier::slash(env, &accountable, dispute_id);
+        record.overturned = true;
+        records.set(i, record);
+    }
+    env.storage().instance().set(&AdvDataKey::VerificationRecord(content_id), &records);
+
+    let mut content = get_content(env, content_id);
+    content.verification_level = VerificationLevel::None;
+    save_content(env, &content);
+
+    env.events().publish((symbol_short!("OVERTURN"), content_id), dispute_id);
+}
+
+// Panics unless `delegator` currently delegates verification rights to `delegatee`
+fn require_delegation(env: &Env, delegator: &Address, delegatee: &Address) {
+    let delegations: Vec<Delegation> = env.storage().instance().get(&AdvDataKey::Delegation(delegator.clone())).unwrap_or(Vec::new(env));
+    let now = env.ledger().timestamp();
+    let valid = delegations.iter().any(|d| d.delegatee == *delegatee && (d.until.is_none() || d.until.unwrap() > now));
+    if !valid {
+        panic!("Delegation not valid");
+    }
+}
+
+fn add_verification_record(env: &Env, content_id: u64, record: VerificationRecord) {
+    let mut records = get_verification_records(env, content_id);
+    records.push_back(record);
+    env.storage().instance().set(&AdvDataKey::VerificationRecord(content_id), &records);
+}
+
+// Level a content is verified at right now: the highest level among its records
+// that are neither overturned nor expired. Content without records keeps its
+// stored level.
+pub fn effective_level(env: &Env, content_id: u64, stored_level: VerificationLevel) -> VerificationLevel {
+    let records = get_verification_records(env, content_id);
+    if records.is_empty() {
+        return stored_level;
+    }
+
+    let now = env.ledger().timestamp();
+    let mut level = VerificationLevel::None;
+    for record in records.iter() {
+        if is_active(&record, now) && record.level > level {
+            level = record.level;
+        }
+    }
+    level
+}
+
+// Replace the stored level of a content with its effective level
+pub fn with_effective_level(env: &Env, mut content: Content) -> Content {
+    content.verification_level = effective_level(env, content.id, content.verification_level);
+    content
+}
+
+// Seconds before expiry at which renewal reminders are emitted
+pub fn set_reminder_window(env: &Env, manager: Address, window_secs: u64) {
+    verifier::require_manager(env, &manager);
+    env.storage().instance().set(&AdvDataKey::ReminderWindow, &window_secs);
+}
+
+pub fn get_reminder_window(env: &Env) -> u64 {
+    env.storage().instance().get(&AdvDataKey::ReminderWindow).unwrap_or(DEFAULT_REMINDER_WINDOW)
+}
+
+// Keeper sweep over the content ids `start..start + limit`, skipping removed
+// contents so that pages stay stable: marks expired records, which stay
+// renewable, stores the effective level of each content, emits a downgrade
+// event when it fell and a renewal reminder for records about to expire.
+// Returns the number of contents downgraded.
+pub fn sweep_verifications(env: &Env, start: u32, limit: u32) -> u32 {
+    let now = env.ledger().timestamp();
+    let reminder_window = get_reminder_window(env);
+    let end = u64::from(start).saturating_add(u64::from(limit)).min(get_content_counter(env));
+
+    let mut downgraded = 0u32;
+    for content_id in u64::from(start)..end {
+        if !content_exists(env, content_id) || is_removed(env, content_id) {
+            continue;
+        }
+        let records = get_verification_records(env, content_id);
+        if records.is_empty() {
+            continue;
+        }
+
+        let mut swept = Vec::new(env);
+        for mut record in records.iter() {
+            if !record.overturned && !record.expired && !is_active(&record, now) {
+                env.events().publish((symbol_short!("VRF_EXP"), content_id, record.verifier.clone()), record.level as u32);
+                record.expired = true;
+            }
+            if let Some(expiration) = record.expiration {
+                if !record.overturned && !record.expired && !record.reminder_sent && expiration <= now.saturating_add(reminder_window) {
+                    env.events().publish((symbol_short!("RENEW_DUE"), content_id, record.verifier.clone()), expiration);
+                    record.reminder_sent = true;
+                }
+            }
+            swept.push_back(record);
+        }
+        env.storage().instance().set(&AdvDataKey::VerificationRecord(content_id), &swept);
+
+        let mut content = get_content(env, content_id);
+        let level = effective_level(env, content_id, VerificationLevel::None);
+        if level != content.verification_level {
+            if level < content.verification_level {
+                env.events().publish((symbol_short!("DOWNGRADE"), content_id), (content.verification_level as u32, level as u32));
+                downgraded += 1;
+            }
+            content.verification_level = level;
+            save_content(env, &content);
+        }
+    }
+    downgraded
+}
+
+fn is_active(record: &VerificationRecord, now: u64) -> bool {
+    !record.overturned && record.expiration.is_none_or(|expiration| expiration > now)
+}
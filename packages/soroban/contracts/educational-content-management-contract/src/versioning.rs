@@ -2,10 +2,14 @@ use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Vec};
 
 use crate::storage::{
     get_content, get_version_count, get_version_info, get_version_snapshot,
-    has_user_voted_on_version, record_version_vote, save_content, save_version_count,
-    save_version_info, save_version_snapshot, Content, ContentVersion, VerificationLevel,
-    VersionDiff,
+    has_user_voted_on_version, is_removed, record_version_vote, save_content, save_content_origin,
+    save_version_count, save_version_info, save_version_snapshot, Content, ContentOrigin,
+    ContentVersion, VerificationLevel, VersionDiff,
 };
+use crate::{licensing, publish, verifier};
+
+/// Versions a diff can walk through, both ends included
+pub const MAX_DIFF_VERSIONS: u32 = 50;
 
 pub fn create_version(
     env: &Env,
@@ -22,12 +26,33 @@ pub fn create_version(
         panic!("only the creator can create a new version");
     }
 
+    let mut updated_content = current_content.clone();
+    updated_content.title = title;
+    updated_content.content_hash = content_hash;
+    updated_content.subject_tags = subject_tags;
+
+    apply_version(env, &current_content, &updated_content, creator, change_notes, None, Vec::new(env))
+}
+
+/// Snapshot the current content and replace it with `updated_content` as a new
+/// version, attributed to the collaborator and approvers of the merged submission if any
+pub fn apply_version(
+    env: &Env,
+    current_content: &Content,
+    updated_content: &Content,
+    creator: Address,
+    change_notes: String,
+    contributor: Option<Address>,
+    approvers: Vec<Address>,
+) -> u32 {
+    let content_id = current_content.id;
+
     // Get version count
     let version_count = get_version_count(env, content_id);
     let new_version = version_count + 1;
 
     // Save current content as snapshot of previous version
-    save_version_snapshot(env, content_id, version_count, &current_content);
+    save_version_snapshot(env, content_id, version_count, current_content);
 
     // Create version record
     let version_record = ContentVersion {
@@ -37,6 +62,9 @@ pub fn create_version(
         change_notes,
         upvotes: 0,
         verification_level: VerificationLevel::None,
+        contributor,
+        approvers,
+        reverted_from: None,
     };
 
     // Save version record and count
@@ -44,12 +72,7 @@ pub fn create_version(
     save_version_count(env, content_id, new_version);
 
     // Update main content to new version
-    let mut updated_content = current_content;
-    updated_content.title = title;
-    updated_content.content_hash = content_hash;
-    updated_content.subject_tags = subject_tags;
-
-    save_content(env, &updated_content);
+    save_content(env, updated_content);
 
     env.events().publish(
         (symbol_short!("VERSION"),),
@@ -61,6 +84,7 @@ pub fn create_version(
 
 /// Get content as it was at a specific version
 pub fn get_content_at_version(env: &Env, content_id: u64, version: u32) -> Content {
+    require_not_removed(env, content_id);
     let total_versions = get_version_count(env, content_id);
 
     if version > total_versions {
@@ -76,6 +100,12 @@ pub fn get_content_at_version(env: &Env, content_id: u64, version: u32) -> Conte
     get_version_snapshot(env, content_id, version)
 }
 
+/// Get the record of a version of a content that was not removed
+pub fn get_content_version_info(env: &Env, content_id: u64, version: u32) -> ContentVersion {
+    require_not_removed(env, content_id);
+    get_version_info(env, content_id, version)
+}
+
 /// Upvote specific version
 pub fn upvote_version(env: &Env, content_id: u64, version: u32, voter: Address) -> u32 {
     if has_user_voted_on_version(env, &voter, content_id, version) {
@@ -106,6 +136,9 @@ pub fn verify_version(
 ) -> VerificationLevel {
     let mut version_info = get_version_info(env, content_id, version);
 
+    // Only registered verifiers of the level can verify
+    verifier::require_verifier(env, &verifier, level);
+
     if level <= version_info.verification_level {
         panic!("cannot downgrade verification");
     }
@@ -128,14 +161,134 @@ pub fn get_version_diff(
     from_version: u32,
     to_version: u32,
 ) -> VersionDiff {
+    require_not_removed(env, content_id);
+    if from_version.abs_diff(to_version) >= MAX_DIFF_VERSIONS {
+        panic!("version diff spans too many versions");
+    }
     let content1 = get_content_at_version(env, content_id, from_version);
     let content2 = get_content_at_version(env, content_id, to_version);
 
-    // Should more information be included?
+    let (_, from_upvotes, from_level) = version_metadata(env, &content1, from_version);
+    let (_, to_upvotes, to_level) = version_metadata(env, &content2, to_version);
+
+    // Walk the history between the two versions, in either direction
+    let mut versions = Vec::new(env);
+    let mut authors = Vec::new(env);
+    let mut version = from_version;
+    loop {
+        let (author, _, _) = version_metadata(env, &content1, version);
+        versions.push_back(version);
+        authors.push_back(author);
+        if version == to_version {
+            break;
+        }
+        version = if to_version > version { version + 1 } else { version - 1 };
+    }
+
     VersionDiff {
         from_version,
         to_version,
         title_changed: content1.title != content2.title,
         content_changed: content1.content_hash != content2.content_hash,
+        tags_added: missing_tags(env, &content2.subject_tags, &content1.subject_tags),
+        tags_removed: missing_tags(env, &content1.subject_tags, &content2.subject_tags),
+        from_verification_level: from_level,
+        to_verification_level: to_level,
+        upvote_delta: to_upvotes as i64 - from_upvotes as i64,
+        versions,
+        authors,
+    }
+}
+
+/// Restore an earlier version as a new head version
+pub fn revert_to_version(env: &Env, content_id: u64, creator: Address, version: u32) -> u32 {
+    let current_content = get_content(env, content_id);
+    if current_content.creator != creator {
+        panic!("only the creator can revert content");
+    }
+    if version >= get_version_count(env, content_id) {
+        panic!("can only revert to an earlier version");
+    }
+
+    let target = get_version_snapshot(env, content_id, version);
+    let mut updated_content = current_content.clone();
+    updated_content.title = target.title;
+    updated_content.content_hash = target.content_hash;
+    updated_content.subject_tags = target.subject_tags;
+
+    let new_version = apply_version(
+        env,
+        &current_content,
+        &updated_content,
+        creator.clone(),
+        String::from_str(env, "Reverted to an earlier version"),
+        None,
+        Vec::new(env),
+    );
+
+    let mut version_info = get_version_info(env, content_id, new_version);
+    version_info.reverted_from = Some(version);
+    save_version_info(env, content_id, new_version, &version_info);
+
+    env.events().publish(
+        (symbol_short!("REVERT"),),
+        (content_id, version, new_version, creator),
+    );
+
+    new_version
+}
+
+/// Publish a version of a content as a new content owned by `creator` that
+/// references its origin. Others than the creator can only fork content they can
+/// access and whose license permits derivative works.
+pub fn fork_content(env: &Env, content_id: u64, version: u32, creator: Address, title: String) -> u64 {
+    require_not_removed(env, content_id);
+    if get_content(env, content_id).creator != creator {
+        if !licensing::permits_derivatives(env, content_id) {
+            panic!("license does not permit derivative works");
+        }
+        if !licensing::has_access(env, &creator, content_id) {
+            panic!("no access to content");
+        }
+    }
+    let source = get_content_at_version(env, content_id, version);
+
+    let fork_id = publish::publish_content(env, creator.clone(), title, source.content_hash, source.subject_tags);
+    save_content_origin(env, fork_id, &ContentOrigin { content_id, version });
+
+    env.events().publish(
+        (symbol_short!("FORK"),),
+        (content_id, version, fork_id, creator),
+    );
+
+    fork_id
+}
+
+// Versions of removed content are hidden like the content itself
+fn require_not_removed(env: &Env, content_id: u64) {
+    if is_removed(env, content_id) {
+        panic!("content removed");
+    }
+}
+
+// Author, upvotes and verification level of a version. The author of a merged
+// submission is its contributor. Version 0 is the published content, which has
+// no version record.
+fn version_metadata(env: &Env, content: &Content, version: u32) -> (Address, u32, VerificationLevel) {
+    if version == 0 {
+        return (content.creator.clone(), 0, VerificationLevel::None);
+    }
+    let info = get_version_info(env, content.id, version);
+    (info.contributor.unwrap_or(info.creator), info.upvotes, info.verification_level)
+}
+
+// Tags of `tags` that are not in `other`
+fn missing_tags(env: &Env, tags: &Vec<String>, other: &Vec<String>) -> Vec<String> {
+    let mut missing = Vec::new(env);
+    for tag in tags.iter() {
+        if !other.contains(&tag) {
+            missing.push_back(tag);
+        }
     }
+    missing
 }
@@ -0,0 +1,275 @@
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Vec};
+use crate::datatype::{
+    AttesterKeyType, CredentialStatus, CrossChainAttestation, ExportedAttestation, SupportedChain,
+};
+use crate::storage::{ADMIN, CROSS_CHAIN_REGISTRY, DataKey};
+use crate::verification::VerificationSystem;
+
+/// Cross-chain credential attestation backed by per-chain attester keys
+pub struct CrossChainSystem;
+
+impl CrossChainSystem {
+    /// Register or update a supported chain and its attester public key
+    pub fn register_chain(
+        env: &Env,
+        admin: &Address,
+        chain_id: u32,
+        name: String,
+        key_type: AttesterKeyType,
+        attester_key: Bytes,
+    ) {
+        admin.require_auth();
+        VerificationSystem::verify_admin(env, admin);
+
+        Self::check_key_length(key_type, &attester_key);
+
+        let chain = SupportedChain {
+            chain_id,
+            name,
+            key_type,
+            attester_key,
+            active: true,
+        };
+
+        let mut chains = Self::get_chains(env);
+        chains.set(chain_id, chain);
+        env.storage().persistent().set(&CROSS_CHAIN_REGISTRY, &chains);
+    }
+
+    /// Stop accepting attestations from a chain
+    pub fn deactivate_chain(env: &Env, admin: &Address, chain_id: u32) {
+        admin.require_auth();
+        VerificationSystem::verify_admin(env, admin);
+
+        let mut chains = Self::get_chains(env);
+        let mut chain = chains.get(chain_id).unwrap_or_else(|| panic!("chain not supported"));
+        chain.active = false;
+        chains.set(chain_id, chain);
+        env.storage().persistent().set(&CROSS_CHAIN_REGISTRY, &chains);
+    }
+
+    pub fn get_supported_chain(env: &Env, chain_id: u32) -> Option<SupportedChain> {
+        Self::get_chains(env).get(chain_id)
+    }
+
+    /// Accept a signed attestation from a supported chain's attester and mark the credential
+    /// as cross-chain verified. Returns false if the credential does not exist.
+    pub fn verify_cross_chain(env: &Env, attestation: CrossChainAttestation) -> bool {
+        let chain = Self::get_active_chain(env, attestation.chain_id);
+
+        // Replay protection: each nonce is accepted once per chain, so attestations
+        // may arrive in any order
+        let nonce_key = DataKey::ChainNonce(attestation.chain_id, attestation.nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            panic!("attestation nonce already used");
+        }
+
+        let mut message = env.current_contract_address().to_xdr(env);
+        message.extend_from_array(&env.ledger().network_id().to_array());
+        message.extend_from_array(&attestation.credential_id.to_array());
+        message.extend_from_array(&attestation.chain_id.to_be_bytes());
+        message.extend_from_array(&attestation.nonce.to_be_bytes());
+
+        Self::check_signature(
+            env, chain.key_type, &chain.attester_key, &message,
+            &attestation.signature, attestation.recovery_id,
+        );
+
+        let mut credential = match VerificationSystem::get_credential_info(env, attestation.credential_id.clone()) {
+            Some(credential) => credential,
+            None => return false,
+        };
+        if credential.status != CredentialStatus::Active {
+            panic!("credential not active");
+        }
+
+        env.storage().persistent().set(&nonce_key, &true);
+
+        credential.cross_chain_verified = true;
+        VerificationSystem::store_credential(env, &credential);
+
+        let record_key = DataKey::CrossChainVerifications(attestation.credential_id.clone());
+        let mut verified_chains: Vec<u32> = env.storage().persistent()
+            .get(&record_key).unwrap_or_else(|| Vec::new(env));
+        if !verified_chains.contains(attestation.chain_id) {
+            verified_chains.push_back(attestation.chain_id);
+            env.storage().persistent().set(&record_key, &verified_chains);
+        }
+
+        env.events().publish(
+            (symbol_short!("xc_verify"), attestation.credential_id),
+            (attestation.chain_id, attestation.nonce),
+        );
+        true
+    }
+
+    /// Get the chains that have attested to a credential
+    pub fn get_cross_chain_verifications(env: &Env, credential_id: BytesN<32>) -> Vec<u32> {
+        env.storage().persistent()
+            .get(&DataKey::CrossChainVerifications(credential_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set the key that signs attestations exported to chains using `key_type`.
+    /// Target chains verify exported attestations against this key.
+    pub fn set_export_signer(env: &Env, admin: &Address, key_type: AttesterKeyType, public_key: Bytes) {
+        admin.require_auth();
+        VerificationSystem::verify_admin(env, admin);
+        Self::check_key_length(key_type, &public_key);
+        env.storage().persistent().set(&DataKey::ExportSigner(key_type), &public_key);
+    }
+
+    pub fn get_export_signer(env: &Env, key_type: AttesterKeyType) -> Option<Bytes> {
+        env.storage().persistent().get(&DataKey::ExportSigner(key_type))
+    }
+
+    /// Produce an attestation about a credential issued here for a target chain.
+    /// The payload is `network_id || contract (XDR) || credential_id || target_chain_id (u32 BE)
+    /// || status (u8) || expiration (u64 BE) || nonce (u64 BE) || timestamp (u64 BE)` and the
+    /// attestation is published as an event so the export signer can sign it with
+    /// `sign_exported_attestation` before relayers carry it to the target chain. Only the
+    /// credential's subject, its issuer or the admin, who manages the export signer, can
+    /// request an export.
+    pub fn export_attestation(
+        env: &Env,
+        requester: &Address,
+        credential_id: BytesN<32>,
+        target_chain_id: u32,
+    ) -> ExportedAttestation {
+        requester.require_auth();
+
+        let chain = Self::get_active_chain(env, target_chain_id);
+        let credential = VerificationSystem::get_credential_info(env, credential_id.clone())
+            .unwrap_or_else(|| panic!("credential not found"));
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        if *requester != credential.subject && *requester != credential.issuer && *requester != admin {
+            panic!("not authorized");
+        }
+
+        let nonce_key = DataKey::ExportNonce(target_chain_id);
+        let nonce: u64 = env.storage().persistent().get(&nonce_key).unwrap_or(0) + 1;
+        env.storage().persistent().set(&nonce_key, &nonce);
+
+        let mut attestation = ExportedAttestation {
+            network_id: env.ledger().network_id(),
+            contract: env.current_contract_address(),
+            credential_id: credential_id.clone(),
+            issuer: credential.issuer,
+            subject: credential.subject,
+            status: credential.status,
+            expiration: credential.expiration,
+            target_chain_id,
+            nonce,
+            timestamp: env.ledger().timestamp(),
+            digest: BytesN::from_array(env, &[0; 32]),
+            signature: None,
+            recovery_id: 0,
+        };
+        let payload = Self::export_payload(env, &attestation);
+        attestation.digest = match chain.key_type {
+            AttesterKeyType::Secp256k1 => env.crypto().keccak256(&payload).into(),
+            AttesterKeyType::Ed25519 => env.crypto().sha256(&payload).into(),
+        };
+        env.storage().persistent().set(&DataKey::ExportedAttestation(target_chain_id, nonce), &attestation);
+
+        env.events().publish(
+            (symbol_short!("xc_export"), credential_id, target_chain_id),
+            attestation.clone(),
+        );
+        attestation
+    }
+
+    /// Attach the export signer's signature to an exported attestation. Ed25519 signers
+    /// sign the payload and secp256k1 signers sign its keccak256 digest.
+    pub fn sign_exported_attestation(
+        env: &Env,
+        target_chain_id: u32,
+        nonce: u64,
+        signature: BytesN<64>,
+        recovery_id: u32,
+    ) -> ExportedAttestation {
+        let key = DataKey::ExportedAttestation(target_chain_id, nonce);
+        let mut attestation: ExportedAttestation = env.storage().persistent()
+            .get(&key).unwrap_or_else(|| panic!("attestation not found"));
+        if attestation.signature.is_some() {
+            panic!("attestation already signed");
+        }
+
+        let chain = Self::get_active_chain(env, target_chain_id);
+        let signer = Self::get_export_signer(env, chain.key_type)
+            .unwrap_or_else(|| panic!("export signer not set"));
+        let payload = Self::export_payload(env, &attestation);
+        Self::check_signature(env, chain.key_type, &signer, &payload, &signature, recovery_id);
+
+        attestation.signature = Some(signature);
+        attestation.recovery_id = recovery_id;
+        env.storage().persistent().set(&key, &attestation);
+
+        env.events().publish(
+            (symbol_short!("xc_signed"), attestation.credential_id.clone(), target_chain_id),
+            nonce,
+        );
+        attestation
+    }
+
+    pub fn get_exported_attestation(env: &Env, target_chain_id: u32, nonce: u64) -> Option<ExportedAttestation> {
+        env.storage().persistent().get(&DataKey::ExportedAttestation(target_chain_id, nonce))
+    }
+
+    fn export_payload(env: &Env, attestation: &ExportedAttestation) -> Bytes {
+        let mut payload = Bytes::from_array(env, &attestation.network_id.to_array());
+        payload.append(&attestation.contract.clone().to_xdr(env));
+        payload.extend_from_array(&attestation.credential_id.to_array());
+        payload.extend_from_array(&attestation.target_chain_id.to_be_bytes());
+        payload.push_back(attestation.status as u8);
+        payload.extend_from_array(&attestation.expiration.to_be_bytes());
+        payload.extend_from_array(&attestation.nonce.to_be_bytes());
+        payload.extend_from_array(&attestation.timestamp.to_be_bytes());
+        payload
+    }
+
+    /// Ed25519 keys sign `message` itself, secp256k1 keys sign its keccak256 digest
+    fn check_signature(
+        env: &Env,
+        key_type: AttesterKeyType,
+        key: &Bytes,
+        message: &Bytes,
+        signature: &BytesN<64>,
+        recovery_id: u32,
+    ) {
+        match key_type {
+            AttesterKeyType::Ed25519 => {
+                let public_key: BytesN<32> = key.clone().try_into().unwrap();
+                env.crypto().ed25519_verify(&public_key, message, signature);
+            }
+            AttesterKeyType::Secp256k1 => {
+                let digest = env.crypto().keccak256(message);
+                let recovered = env.crypto().secp256k1_recover(&digest, signature, recovery_id);
+                if Bytes::from(recovered) != *key {
+                    panic!("invalid attestation signature");
+                }
+            }
+        }
+    }
+
+    fn check_key_length(key_type: AttesterKeyType, key: &Bytes) {
+        let expected_len = match key_type {
+            AttesterKeyType::Ed25519 => 32,
+            AttesterKeyType::Secp256k1 => 65,
+        };
+        if key.len() != expected_len {
+            panic!("invalid attester key length");
+        }
+    }
+
+    fn get_chains(env: &Env) -> Map<u32, SupportedChain> {
+        env.storage().persistent().get(&CROSS_CHAIN_REGISTRY).unwrap_or_else(|| Map::new(env))
+    }
+
+    fn get_active_chain(env: &Env, chain_id: u32) -> SupportedChain {
+        match Self::get_supported_chain(env, chain_id) {
+            Some(chain) if chain.active => chain,
+            _ => panic!("chain not supported"),
+        }
+    }
+}
@@ -1,4 +1,14 @@
-use soroban_sdk::{contracttype, Address, Map, String, Vec, BytesN};
+use soroban_sdk::{contracttype, Address, Bytes, Map, String, Vec, BytesN};
+
+/// Ed25519 proof that an authorized institution issued a credential to a subject.
+/// The signed message is `contract (XDR) || network_id || digest || tier (u32 BE) || subject (XDR)`.
+#[contracttype]
+#[derive(Clone)]
+pub struct CredentialProof {
+    pub institution_id: String,   // Issuing institution
+    pub digest: BytesN<32>,       // SHA-256 digest of the off-chain verifiable credential
+    pub signature: BytesN<64>,    // Institution's ed25519 signature
+}
 
 /// Enhanced Credential structure with tiered verification and W3C compliance
 #[contracttype]
@@ -13,6 +23,110 @@ pub struct Credential {
     pub credential_hash: String,  // Hash of credential data
     pub cross_chain_verified: bool, // Cross-chain verification status
     pub renewal_count: u32,       // Number of times renewed
+    pub status: CredentialStatus, // Current revocation status
+    pub status_index: u32,        // Position in the issuer's status lists
+    pub attributes_root: Option<BytesN<32>>, // Merkle root of the credential's attributes
+}
+
+/// Revocation status of a credential
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CredentialStatus {
+    Active,
+    Suspended,  // Temporarily invalid, can be reinstated
+    Revoked,    // Permanently invalid
+}
+
+/// Purpose of an issuer status list, following W3C StatusList2021
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StatusPurpose {
+    Revocation,
+    Suspension,
+}
+
+/// Merkle inclusion proof for a single credential attribute.
+/// Leaves are `sha256(0x00 || salt || key length (u32 BE) || key || value)` and inner
+/// nodes are `sha256(0x01 || min(left, right) || max(left, right))`.
+#[contracttype]
+#[derive(Clone)]
+pub struct AttributeProof {
+    pub salt: BytesN<32>,         // Per-attribute salt, hides undisclosed values
+    pub siblings: Vec<BytesN<32>>, // Sibling hashes from leaf to root
+}
+
+/// Signature scheme used by a supported chain's attester
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AttesterKeyType {
+    Ed25519,    // 32-byte public key, signs the raw message
+    Secp256k1,  // 65-byte uncompressed public key, signs the keccak256 digest (EVM chains)
+}
+
+/// Chain whose attester may vouch for credentials issued here
+#[contracttype]
+#[derive(Clone)]
+pub struct SupportedChain {
+    pub chain_id: u32,
+    pub name: String,
+    pub key_type: AttesterKeyType,
+    pub attester_key: Bytes,
+    pub active: bool,
+}
+
+/// Signed statement from a supported chain's attester about a credential.
+/// The signed message is `contract (XDR) || network_id || credential_id || chain_id (u32 BE)
+/// || nonce (u64 BE)`.
+#[contracttype]
+#[derive(Clone)]
+pub struct CrossChainAttestation {
+    pub credential_id: BytesN<32>,
+    pub chain_id: u32,
+    pub nonce: u64,               // Each nonce is accepted once per chain, in any order
+    pub signature: BytesN<64>,
+    pub recovery_id: u32,         // Only used for secp256k1 signatures
+}
+
+/// Attestation about a credential issued here, for verification on another chain
+#[contracttype]
+#[derive(Clone)]
+pub struct ExportedAttestation {
+    pub network_id: BytesN<32>,   // Stellar network passphrase hash
+    pub contract: Address,        // This contract
+    pub credential_id: BytesN<32>,
+    pub issuer: Address,
+    pub subject: Address,
+    pub status: CredentialStatus,
+    pub expiration: u64,
+    pub target_chain_id: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub digest: BytesN<32>,       // keccak256 for secp256k1 targets, sha256 otherwise
+    pub signature: Option<BytesN<64>>, // Export signer's signature, once attached
+    pub recovery_id: u32,         // Only used for secp256k1 signatures
+}
+
+/// Record of the latest status change made to a credential
+#[contracttype]
+#[derive(Clone)]
+pub struct RevocationRecord {
+    pub credential_id: BytesN<32>,
+    pub status: CredentialStatus,
+    pub reason_code: u32,         // Issuer-defined reason, e.g. fraud or issuance error
+    pub timestamp: u64,
+    pub authority: Address,       // Issuer or admin who made the change
+}
+
+/// Authorized issuing institution with its DID and signing key
+#[contracttype]
+#[derive(Clone)]
+pub struct Institution {
+    pub id: String,
+    pub did: String,              // Decentralized identifier, e.g. did:web:university.edu
+    pub public_key: BytesN<32>,   // Ed25519 public key used to sign credentials
+    pub account: Address,         // Recorded as the issuer of the credentials it signs
+    pub active: bool,
+    pub added_at: u64,
 }
 
 /// Enhanced NFT structure with dynamic metadata and templates
@@ -29,6 +143,17 @@ pub struct NFT {
     pub upgrade_level: u32,       // Current upgrade level
 }
 
+/// Non-fungible verification token minted when an educator is verified
+#[contracttype]
+#[derive(Clone)]
+pub struct VerificationNFT {
+    pub id: BytesN<32>,           // Unique token identifier
+    pub owner: Address,           // Verified educator holding the token
+    pub level: VerificationLevel, // Verification level at mint time
+    pub specialties: Vec<String>, // Specialty areas at mint time
+    pub minted_at: u64,           // Mint timestamp
+}
+
 /// Visual template for NFT rendering
 #[contracttype]
 #[derive(Clone)]
@@ -62,7 +187,7 @@ pub struct Educator {
     pub name: String,
     pub credentials: Vec<Credential>,
     pub verification_status: bool,
-    pub nft_token_id: Option<String>,
+    pub nft_token_id: Option<BytesN<32>>,
     pub verification_timestamp: u64,
     pub specialty_areas: Vec<String>,
     pub verification_level: VerificationLevel,
@@ -0,0 +1,100 @@
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, String};
+use crate::datatype::{AttributeProof, CredentialStatus};
+use crate::utils::Utils;
+use crate::verification::VerificationSystem;
+
+/// Selective disclosure of credential attributes through Merkle inclusion proofs
+pub struct DisclosureSystem;
+
+impl DisclosureSystem {
+    /// Commit a credential to the Merkle root of its attributes. The root of an
+    /// institution-signed credential must carry the institution's signature over
+    /// `contract (XDR) || network_id || digest || attributes_root` and may be submitted
+    /// by anyone; other credentials are committed by their issuer. The root cannot be
+    /// changed once set.
+    pub fn commit_attributes(
+        env: &Env,
+        submitter: &Address,
+        credential_id: BytesN<32>,
+        attributes_root: BytesN<32>,
+        signature: Option<BytesN<64>>,
+    ) {
+        submitter.require_auth();
+
+        let mut credential = VerificationSystem::get_credential_info(env, credential_id.clone())
+            .unwrap_or_else(|| panic!("credential not found"));
+        if credential.attributes_root.is_some() {
+            panic!("attributes already committed");
+        }
+
+        match VerificationSystem::get_credential_proof(env, credential_id.clone()) {
+            Some(proof) => {
+                let signature = signature.unwrap_or_else(|| panic!("attributes root requires an institution signature"));
+                let institution = match VerificationSystem::get_institution(env, proof.institution_id) {
+                    Some(institution) if institution.active => institution,
+                    _ => panic!("institution not authorized"),
+                };
+                let message = Utils::attributes_root_message(
+                    env, &env.current_contract_address(), &proof.digest, &attributes_root,
+                );
+                env.crypto().ed25519_verify(&institution.public_key, &message, &signature);
+            }
+            None => {
+                if credential.issuer != *submitter {
+                    panic!("not authorized issuer");
+                }
+            }
+        }
+
+        credential.attributes_root = Some(attributes_root.clone());
+        VerificationSystem::store_credential(env, &credential);
+
+        env.events().publish((symbol_short!("attr_root"), credential_id), attributes_root);
+    }
+
+    /// Check that `attribute_key = value` is one of the attributes committed by an active,
+    /// unexpired credential. Keys or values over 256 bytes never verify.
+    pub fn verify_credential_attribute(
+        env: &Env,
+        credential_id: BytesN<32>,
+        attribute_key: String,
+        value: String,
+        proof: AttributeProof,
+    ) -> bool {
+        let credential = VerificationSystem::get_credential_info(env, credential_id)
+            .unwrap_or_else(|| panic!("credential not found"));
+        let root = credential.attributes_root.clone()
+            .unwrap_or_else(|| panic!("attributes not committed"));
+
+        if credential.status != CredentialStatus::Active || Utils::is_credential_expired(env, &credential) {
+            return false;
+        }
+
+        let mut node = match Self::hash_leaf(env, &proof.salt, &attribute_key, &value) {
+            Some(leaf) => leaf,
+            None => return false,
+        };
+        for sibling in proof.siblings.iter() {
+            node = Self::hash_node(env, &node, &sibling);
+        }
+        node == root
+    }
+
+    fn hash_leaf(env: &Env, salt: &BytesN<32>, key: &String, value: &String) -> Option<BytesN<32>> {
+        let mut data = Bytes::from_array(env, &[0x00]);
+        data.extend_from_array(&salt.to_array());
+        data.extend_from_array(&key.len().to_be_bytes());
+        data.append(&Utils::string_to_bytes(env, key)?);
+        data.append(&Utils::string_to_bytes(env, value)?);
+        Some(env.crypto().sha256(&data).into())
+    }
+
+    fn hash_node(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        // Sorted pairs make proofs independent of leaf position
+        let (first, second) = if left <= right { (left, right) } else { (right, left) };
+        let mut data = Bytes::from_array(env, &[0x01]);
+        data.extend_from_array(&first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).into()
+    }
+}
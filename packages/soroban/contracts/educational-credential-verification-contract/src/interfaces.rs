@@ -1,7 +1,9 @@
-use soroban_sdk::{Address, Env, Map, String, Vec, BytesN};
+use soroban_sdk::{Address, Bytes, Env, Map, String, Vec, BytesN};
 use crate::datatype::{
     AnalyticsData, Educator, VerificationLevel, Review, Dispute, ReviewerPerformance, 
     Credential, NFT, NFTTemplate, AchievementBadge, SecurityConfig, 
+    VerificationNFT, CredentialProof, Institution, RevocationRecord, StatusPurpose,
+    AttesterKeyType, SupportedChain, CrossChainAttestation, ExportedAttestation, AttributeProof,
     ReputationStake, ContractVersion
 };
 
@@ -10,7 +12,9 @@ pub trait EducatorVerificationInterface {
     fn initialize(env: Env, admin: Address);
     fn add_reviewer(env: Env, admin: Address, reviewer: Address);
     fn remove_reviewer(env: Env, admin: Address, reviewer: Address);
-    fn add_authorized_institution(env: Env, admin: Address, institution_id: String);
+    fn add_authorized_institution(env: Env, admin: Address, institution_id: String, did: String, public_key: BytesN<32>, account: Address);
+    fn remove_authorized_institution(env: Env, admin: Address, institution_id: String);
+    fn get_institution(env: Env, institution_id: String) -> Option<Institution>;
     
     // --- Educator Functions ---
     fn register_educator(env: Env, educator_address: Address, name: String, credential_hashes: Vec<String>, specialty_areas: Vec<String>) -> Address;
@@ -35,11 +39,35 @@ pub trait EducatorVerificationInterface {
     fn get_educator_reviews(env: Env, educator_address: Address) -> Vec<Review>;
 
     // --- Enhanced Credential Functions ---
-    fn create_credential(env: Env, issuer: Address, subject: Address, credential_hash: String, tier: u32, w3c_compliant: bool) -> BytesN<32>;
+    fn create_credential(env: Env, issuer: Address, subject: Address, credential_hash: String, tier: u32, w3c_compliant: bool, proof: Option<CredentialProof>) -> BytesN<32>;
     fn renew_credential(env: Env, issuer: Address, credential_id: BytesN<32>) -> bool;
-    fn verify_cross_chain(env: Env, verifier: Address, credential_id: BytesN<32>, chain_id: u32, verification_hash: String) -> bool;
     fn get_credential_info(env: Env, credential_id: BytesN<32>) -> Option<Credential>;
     fn get_credentials_by_subject(env: Env, subject: Address) -> Vec<Credential>;
+    fn get_credential_proof(env: Env, credential_id: BytesN<32>) -> Option<CredentialProof>;
+    fn verify_credential_proof(env: Env, credential_id: BytesN<32>) -> bool;
+
+    // --- Selective Disclosure Functions ---
+    fn commit_attributes(env: Env, submitter: Address, credential_id: BytesN<32>, attributes_root: BytesN<32>, signature: Option<BytesN<64>>);
+    fn verify_credential_attribute(env: Env, credential_id: BytesN<32>, attribute_key: String, value: String, proof: AttributeProof) -> bool;
+
+    // --- Revocation Functions ---
+    fn suspend_credential(env: Env, authority: Address, credential_id: BytesN<32>, reason_code: u32);
+    fn revoke_credential(env: Env, authority: Address, credential_id: BytesN<32>, reason_code: u32);
+    fn reinstate_credential(env: Env, authority: Address, credential_id: BytesN<32>);
+    fn get_revocation_record(env: Env, credential_id: BytesN<32>) -> Option<RevocationRecord>;
+    fn get_status_list(env: Env, issuer: Address, purpose: StatusPurpose) -> Bytes;
+
+    // --- Cross-Chain Functions ---
+    fn register_chain(env: Env, admin: Address, chain_id: u32, name: String, key_type: AttesterKeyType, attester_key: Bytes);
+    fn deactivate_chain(env: Env, admin: Address, chain_id: u32);
+    fn get_supported_chain(env: Env, chain_id: u32) -> Option<SupportedChain>;
+    fn verify_cross_chain(env: Env, attestation: CrossChainAttestation) -> bool;
+    fn get_cross_chain_verifications(env: Env, credential_id: BytesN<32>) -> Vec<u32>;
+    fn export_attestation(env: Env, requester: Address, credential_id: BytesN<32>, target_chain_id: u32) -> ExportedAttestation;
+    fn set_export_signer(env: Env, admin: Address, key_type: AttesterKeyType, public_key: Bytes);
+    fn get_export_signer(env: Env, key_type: AttesterKeyType) -> Option<Bytes>;
+    fn sign_exported_attestation(env: Env, target_chain_id: u32, nonce: u64, signature: BytesN<64>, recovery_id: u32) -> ExportedAttestation;
+    fn get_exported_attestation(env: Env, target_chain_id: u32, nonce: u64) -> Option<ExportedAttestation>;
 
     // --- Dynamic NFT Functions ---
     fn create_dynamic_nft(env: Env, admin: Address, owner: Address, template_id: u32, is_badge: bool, initial_metadata: Map<String, String>) -> BytesN<32>;
@@ -48,6 +76,11 @@ pub trait EducatorVerificationInterface {
     fn list_nfts(env: Env, owner: Address) -> Vec<NFT>;
     fn get_nft_info(env: Env, nft_id: BytesN<32>) -> Option<NFT>;
 
+    // --- Verification NFT Functions ---
+    fn burn_nft(env: Env, caller: Address, nft_id: BytesN<32>);
+    fn get_verification_nft(env: Env, nft_id: BytesN<32>) -> Option<VerificationNFT>;
+    fn get_verification_nfts(env: Env, owner: Address) -> Vec<VerificationNFT>;
+
     // --- NFT Template Functions ---
     fn create_nft_template(env: Env, admin: Address, name: String, description: String, image_url: String, attributes: Map<String, String>, is_badge_template: bool) -> u32;
     fn get_nft_template(env: Env, template_id: u32) -> Option<NFTTemplate>;
@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, Map, String, Vec, BytesN};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, Map, String, Vec, BytesN};
 
 mod datatype;
 mod interfaces;
@@ -11,16 +11,23 @@ mod storage;
 mod utils;
 mod security;
 mod upgrade;
+mod revocation;
+mod cross_chain;
+mod disclosure;
 #[cfg(test)]
 mod test;
 #[cfg(test)]
 mod test_security;
 #[cfg(test)]
 mod test_upgrade;
+#[cfg(test)]
+mod test_credentials;
 
 use datatype::{
     AnalyticsData, Educator, VerificationLevel, Review, Dispute, ReviewerPerformance, 
     Credential, NFT, NFTTemplate, AchievementBadge, SecurityConfig, MultiSigProposal, 
+    VerificationNFT, CredentialProof, Institution, RevocationRecord, StatusPurpose,
+    AttesterKeyType, SupportedChain, CrossChainAttestation, ExportedAttestation, AttributeProof,
     TimeLockOperation, FraudReport, ReputationStake, ContractVersion, MigrationState
 };
 use interfaces::EducatorVerificationInterface;
@@ -30,6 +37,9 @@ use analytics::AnalyticsSystem;
 use nft::NFTImplementation;
 use security::SecuritySystem;
 use upgrade::UpgradeSystem;
+use revocation::RevocationSystem;
+use cross_chain::CrossChainSystem;
+use disclosure::DisclosureSystem;
 use storage::{EDUCATORS, ADMIN, REVOKED, DISPUTES, DataKey};
 
 
@@ -193,7 +203,9 @@ impl EducatorVerificationInterface for EducatorVerificationContract {
                 env.storage().persistent().set(&REVOKED, &revocations);
                 
                 if let Some(nft_id) = educator.nft_token_id.clone() {
-                    nft::NFTImplementation::burn_nft(env.clone(), nft_id);
+                    if let Some(nft) = NFTImplementation::get_verification_nft(env.clone(), nft_id) {
+                        NFTImplementation::remove_verification_nft(&env, &nft);
+                    }
                     educator.nft_token_id = None;
                 }
                 
@@ -227,10 +239,19 @@ impl EducatorVerificationInterface for EducatorVerificationContract {
         VerificationSystem::add_verified_credential(&env, credential, &reviewer);
     }
     
-    fn add_authorized_institution(env: Env, admin: Address, institution_id: String) {
+    fn add_authorized_institution(env: Env, admin: Address, institution_id: String, did: String, public_key: BytesN<32>, account: Address) {
         admin.require_auth();
         VerificationSystem::verify_admin(&env, &admin);
-        VerificationSystem::add_authorized_institution(&env, &admin, institution_id);
+        VerificationSystem::add_authorized_institution(&env, &admin, institution_id, did, public_key, account);
+    }
+
+    fn remove_authorized_institution(env: Env, admin: Address, institution_id: String) {
+        admin.require_auth();
+        VerificationSystem::remove_authorized_institution(&env, &admin, institution_id);
+    }
+
+    fn get_institution(env: Env, institution_id: String) -> Option<Institution> {
+        VerificationSystem::get_institution(&env, institution_id)
     }
 
     fn submit_review(
@@ -283,30 +304,133 @@ impl EducatorVerificationInterface for EducatorVerificationContract {
         credential_hash: String,
         tier: u32,
         w3c_compliant: bool,
+        proof: Option<CredentialProof>,
     ) -> BytesN<32> {
-        VerificationSystem::create_credential(&env, &issuer, &subject, credential_hash, tier, w3c_compliant)
+        VerificationSystem::create_credential(&env, &issuer, &subject, credential_hash, tier, w3c_compliant, proof)
     }
 
     fn renew_credential(env: Env, issuer: Address, credential_id: BytesN<32>) -> bool {
         VerificationSystem::renew_credential(&env, &issuer, credential_id)
     }
 
-    fn verify_cross_chain(
+    fn get_credential_info(env: Env, credential_id: BytesN<32>) -> Option<Credential> {
+        VerificationSystem::get_credential_info(&env, credential_id)
+    }
+
+    fn get_credentials_by_subject(env: Env, subject: Address) -> Vec<Credential> {
+        VerificationSystem::get_credentials_by_subject(&env, &subject)
+    }
+
+    fn get_credential_proof(env: Env, credential_id: BytesN<32>) -> Option<CredentialProof> {
+        VerificationSystem::get_credential_proof(&env, credential_id)
+    }
+
+    fn verify_credential_proof(env: Env, credential_id: BytesN<32>) -> bool {
+        VerificationSystem::verify_credential_proof(&env, credential_id)
+    }
+
+    // --- Selective Disclosure Functions ---
+
+    fn commit_attributes(
         env: Env,
-        verifier: Address,
+        submitter: Address,
         credential_id: BytesN<32>,
-        chain_id: u32,
-        verification_hash: String,
+        attributes_root: BytesN<32>,
+        signature: Option<BytesN<64>>,
+    ) {
+        DisclosureSystem::commit_attributes(&env, &submitter, credential_id, attributes_root, signature)
+    }
+
+    fn verify_credential_attribute(
+        env: Env,
+        credential_id: BytesN<32>,
+        attribute_key: String,
+        value: String,
+        proof: AttributeProof,
     ) -> bool {
-        VerificationSystem::verify_cross_chain(&env, &verifier, credential_id, chain_id, verification_hash)
+        DisclosureSystem::verify_credential_attribute(&env, credential_id, attribute_key, value, proof)
     }
 
-    fn get_credential_info(env: Env, credential_id: BytesN<32>) -> Option<Credential> {
-        VerificationSystem::get_credential_info(&env, credential_id)
+    // --- Revocation Functions ---
+
+    fn suspend_credential(env: Env, authority: Address, credential_id: BytesN<32>, reason_code: u32) {
+        RevocationSystem::suspend_credential(&env, &authority, credential_id, reason_code)
     }
 
-    fn get_credentials_by_subject(env: Env, subject: Address) -> Vec<Credential> {
-        VerificationSystem::get_credentials_by_subject(&env, &subject)
+    fn revoke_credential(env: Env, authority: Address, credential_id: BytesN<32>, reason_code: u32) {
+        RevocationSystem::revoke_credential(&env, &authority, credential_id, reason_code)
+    }
+
+    fn reinstate_credential(env: Env, authority: Address, credential_id: BytesN<32>) {
+        RevocationSystem::reinstate_credential(&env, &authority, credential_id)
+    }
+
+    fn get_revocation_record(env: Env, credential_id: BytesN<32>) -> Option<RevocationRecord> {
+        RevocationSystem::get_revocation_record(&env, credential_id)
+    }
+
+    fn get_status_list(env: Env, issuer: Address, purpose: StatusPurpose) -> Bytes {
+        RevocationSystem::get_status_list(&env, issuer, purpose)
+    }
+
+    // --- Cross-Chain Functions ---
+
+    fn register_chain(
+        env: Env,
+        admin: Address,
+        chain_id: u32,
+        name: String,
+        key_type: AttesterKeyType,
+        attester_key: Bytes,
+    ) {
+        CrossChainSystem::register_chain(&env, &admin, chain_id, name, key_type, attester_key)
+    }
+
+    fn deactivate_chain(env: Env, admin: Address, chain_id: u32) {
+        CrossChainSystem::deactivate_chain(&env, &admin, chain_id)
+    }
+
+    fn get_supported_chain(env: Env, chain_id: u32) -> Option<SupportedChain> {
+        CrossChainSystem::get_supported_chain(&env, chain_id)
+    }
+
+    fn verify_cross_chain(env: Env, attestation: CrossChainAttestation) -> bool {
+        CrossChainSystem::verify_cross_chain(&env, attestation)
+    }
+
+    fn get_cross_chain_verifications(env: Env, credential_id: BytesN<32>) -> Vec<u32> {
+        CrossChainSystem::get_cross_chain_verifications(&env, credential_id)
+    }
+
+    fn export_attestation(
+        env: Env,
+        requester: Address,
+        credential_id: BytesN<32>,
+        target_chain_id: u32,
+    ) -> ExportedAttestation {
+        CrossChainSystem::export_attestation(&env, &requester, credential_id, target_chain_id)
+    }
+
+    fn set_export_signer(env: Env, admin: Address, key_type: AttesterKeyType, public_key: Bytes) {
+        CrossChainSystem::set_export_signer(&env, &admin, key_type, public_key)
+    }
+
+    fn get_export_signer(env: Env, key_type: AttesterKeyType) -> Option<Bytes> {
+        CrossChainSystem::get_export_signer(&env, key_type)
+    }
+
+    fn sign_exported_attestation(
+        env: Env,
+        target_chain_id: u32,
+        nonce: u64,
+        signature: BytesN<64>,
+        recovery_id: u32,
+    ) -> ExportedAttestation {
+        CrossChainSystem::sign_exported_attestation(&env, target_chain_id, nonce, signature, recovery_id)
+    }
+
+    fn get_exported_attestation(env: Env, target_chain_id: u32, nonce: u64) -> Option<ExportedAttestation> {
+        CrossChainSystem::get_exported_attestation(&env, target_chain_id, nonce)
     }
 
     // --- Dynamic NFT Functions ---
@@ -348,6 +472,20 @@ impl EducatorVerificationInterface for EducatorVerificationContract {
         NFTImplementation::get_nft_info(env, nft_id)
     }
 
+    // --- Verification NFT Functions ---
+
+    fn burn_nft(env: Env, caller: Address, nft_id: BytesN<32>) {
+        NFTImplementation::burn_nft(env, caller, nft_id)
+    }
+
+    fn get_verification_nft(env: Env, nft_id: BytesN<32>) -> Option<VerificationNFT> {
+        NFTImplementation::get_verification_nft(env, nft_id)
+    }
+
+    fn get_verification_nfts(env: Env, owner: Address) -> Vec<VerificationNFT> {
+        NFTImplementation::get_verification_nfts(env, owner)
+    }
+
     // --- NFT Template Functions ---
     
     fn create_nft_template(
@@ -1,80 +1,114 @@
 use soroban_sdk::{
-    Address, Env, String, Vec, Map, BytesN, symbol_short,
+    Address, Env, String, Vec, Map, BytesN,
+};
+use crate::datatype::{Educator, VerificationLevel, VerificationNFT, NFT, NFTTemplate, AchievementBadge};
+use crate::storage::{
+    ADMIN, EDUCATORS, NFTS, NFT_TEMPLATES, ACHIEVEMENT_BADGES, NFT_COUNTER, TEMPLATE_COUNTER, BADGE_COUNTER, DataKey,
 };
-use crate::datatype::{VerificationLevel, NFT, NFTTemplate, AchievementBadge};
-use crate::storage::{NFTS, NFT_TEMPLATES, ACHIEVEMENT_BADGES, NFT_COUNTER, TEMPLATE_COUNTER, BADGE_COUNTER};
 use crate::utils::Utils;
 
+/// Template identifier reserved for verification NFTs
+const VERIFICATION_TEMPLATE_ID: u32 = 0;
+
 /// NFT implementation for educator verification credentials
 pub struct NFTImplementation;
 
 impl NFTImplementation {
-    /// Initialize the NFT contract and return its address
-    pub fn initialize_nft(env: Env, _admin: Address) -> Address {
-        // Check that it's not already initialized
-        if env.storage().instance().has(&symbol_short!("token")) {
-            panic!("already initialized");
-        }
+    /// Mint a verification NFT for an educator with a specific verification level
+    pub fn mint_nft(
+        env: Env,
+        recipient: Address,
+        level: VerificationLevel,
+        specialties: Vec<String>,
+    ) -> BytesN<32> {
+        let nonce = Self::next_nft_nonce(&env);
+        let nft_id = Utils::generate_nft_id(&env, &recipient, VERIFICATION_TEMPLATE_ID, nonce);
 
-        let contract_address = env.current_contract_address();
-        // We don't use token here, but save it for future use
-        
-        // Store the token_id
-        env.storage().instance().set(&symbol_short!("token"), &contract_address);
-        contract_address
+        let nft = VerificationNFT {
+            id: nft_id.clone(),
+            owner: recipient.clone(),
+            level,
+            specialties,
+            minted_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::VerificationNFT(nft_id.clone()), &nft);
+
+        // Index the token under its owner
+        let owner_key = DataKey::OwnerNFTs(recipient);
+        let mut owned: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&owner_key).unwrap_or_else(|| Vec::new(&env));
+        owned.push_back(nft_id.clone());
+        env.storage().persistent().set(&owner_key, &owned);
+
+        nft_id
     }
 
-    /// Mint a new NFT for an educator with a specific verification level
-    pub fn mint_nft(
-        env: Env, 
-        admin: Address, 
-        _recipient: Address, 
-        level: VerificationLevel,
-        specialties: Vec<String>
-    ) -> String {
-        admin.require_auth();
+    /// Burn a verification NFT. Only the token owner or the contract admin may burn it.
+    pub fn burn_nft(env: Env, caller: Address, nft_id: BytesN<32>) {
+        caller.require_auth();
 
-        let _token_id = env.storage().instance()
-            .get(&symbol_short!("token"))
-            .unwrap_or_else(|| NFTImplementation::initialize_nft(env.clone(), admin.clone()));
-        
-        // Create a unique ID using a counter
-        let counter: u32 = env.storage().instance().get(&symbol_short!("counter")).unwrap_or(0);
-        let nft_id = String::from_str(&env, "NFT");
-        env.storage().instance().set(&symbol_short!("counter"), &(counter + 1));
-        
-        #[cfg(not(test))]
-        {
-            let token_client = soroban_sdk::token::Client::new(&env, &_token_id);
-            token_client.transfer(&admin, &_recipient, &1);
+        let nft = Self::get_verification_nft(env.clone(), nft_id.clone())
+            .unwrap_or_else(|| panic!("NFT not found"));
+
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        if caller != nft.owner && caller != admin {
+            panic!("not authorized");
+        }
+
+        Self::remove_verification_nft(&env, &nft);
+
+        // Keep the educator profile consistent with the burned token
+        let mut educators: Map<Address, Educator> = env.storage().persistent()
+            .get(&EDUCATORS).unwrap_or_else(|| Map::new(&env));
+        if let Some(mut educator) = educators.get(nft.owner.clone()) {
+            if educator.nft_token_id == Some(nft_id) {
+                educator.nft_token_id = None;
+                educators.set(nft.owner, educator);
+                env.storage().persistent().set(&EDUCATORS, &educators);
+            }
         }
-        
-        // Store the metadata
-        let metadata = (level, specialties);
-        env.storage().persistent().set(&nft_id, &metadata);
-        
-        nft_id
     }
 
-    /// Burn an NFT by its ID, removing it from circulation
-    pub fn burn_nft(env: Env, nft_id: String) {
-        let _token_id: Address = env.storage().instance()
-            .get(&symbol_short!("token"))
-            .unwrap_or_else(|| panic!("Token not initialized"));
-        
-        #[cfg(not(test))]
-        {
-            let token_client = soroban_sdk::token::Client::new(&env, &_token_id);
-            let burn_address = env.current_contract_address();
-            
-            burn_address.require_auth();
-            
-            // Transfer to the burn address
-            token_client.transfer(&burn_address, &burn_address, &1);
+    /// Remove a verification NFT and its owner index entry, without authorization checks
+    pub fn remove_verification_nft(env: &Env, nft: &VerificationNFT) {
+        env.storage().persistent().remove(&DataKey::VerificationNFT(nft.id.clone()));
+
+        let owner_key = DataKey::OwnerNFTs(nft.owner.clone());
+        let owned: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&owner_key).unwrap_or_else(|| Vec::new(env));
+        let mut remaining = Vec::new(env);
+        for id in owned.iter() {
+            if id != nft.id {
+                remaining.push_back(id);
+            }
         }
-        
-        // Remove the metadata
-        env.storage().persistent().remove(&nft_id);
+        env.storage().persistent().set(&owner_key, &remaining);
+    }
+
+    /// Get a verification NFT by its ID
+    pub fn get_verification_nft(env: Env, nft_id: BytesN<32>) -> Option<VerificationNFT> {
+        env.storage().persistent().get(&DataKey::VerificationNFT(nft_id))
+    }
+
+    /// List all verification NFTs held by an owner
+    pub fn get_verification_nfts(env: Env, owner: Address) -> Vec<VerificationNFT> {
+        let owned: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&DataKey::OwnerNFTs(owner)).unwrap_or_else(|| Vec::new(&env));
+
+        let mut nfts = Vec::new(&env);
+        for id in owned.iter() {
+            if let Some(nft) = env.storage().persistent().get(&DataKey::VerificationNFT(id)) {
+                nfts.push_back(nft);
+            }
+        }
+        nfts
+    }
+
+    /// Consume the next value of the NFT counter, used as an ID nonce
+    fn next_nft_nonce(env: &Env) -> u32 {
+        let counter: u32 = env.storage().persistent().get(&NFT_COUNTER).unwrap_or(0);
+        env.storage().persistent().set(&NFT_COUNTER, &(counter + 1));
+        counter
     }
 
     /// Create a new dynamic NFT with metadata and template (internal function)
@@ -88,7 +122,8 @@ impl NFTImplementation {
     ) -> BytesN<32> {
         admin.require_auth();
 
-        let nft_id = Utils::generate_nft_id(&env, &owner, template_id);
+        let nonce = Self::next_nft_nonce(&env);
+        let nft_id = Utils::generate_nft_id(&env, &owner, template_id, nonce);
         let current_time = env.ledger().timestamp();
 
         let nft = NFT {
@@ -111,10 +146,6 @@ impl NFTImplementation {
         nfts.set(nft_id.clone(), nft);
         env.storage().persistent().set(&NFTS, &nfts);
 
-        // Increment NFT counter
-        let counter: u32 = env.storage().persistent().get(&NFT_COUNTER).unwrap_or(0);
-        env.storage().persistent().set(&NFT_COUNTER, &(counter + 1));
-
         nft_id
     }
 
@@ -0,0 +1,136 @@
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env};
+use crate::datatype::{Credential, CredentialStatus, RevocationRecord, StatusPurpose};
+use crate::storage::{ADMIN, DataKey};
+use crate::verification::VerificationSystem;
+
+/// Issuer-scoped credential revocation registry with StatusList2021-style bitstrings
+pub struct RevocationSystem;
+
+impl RevocationSystem {
+    /// Reserve the next position in an issuer's status lists
+    pub fn allocate_status_index(env: &Env, issuer: &Address) -> u32 {
+        let size_key = DataKey::StatusListSize(issuer.clone());
+        let index: u32 = env.storage().persistent().get(&size_key).unwrap_or(0);
+        env.storage().persistent().set(&size_key, &(index + 1));
+        index
+    }
+
+    /// Temporarily suspend a credential; it can later be reinstated
+    pub fn suspend_credential(env: &Env, authority: &Address, credential_id: BytesN<32>, reason_code: u32) {
+        let mut credential = Self::load_authorized(env, authority, &credential_id);
+        match credential.status {
+            CredentialStatus::Active => {}
+            CredentialStatus::Suspended => panic!("credential already suspended"),
+            CredentialStatus::Revoked => panic!("credential revoked"),
+        }
+
+        Self::set_status_bit(env, &credential, StatusPurpose::Suspension, true);
+        credential.status = CredentialStatus::Suspended;
+        Self::record_change(env, authority, &credential, reason_code);
+
+        env.events().publish(
+            (symbol_short!("suspended"), credential_id),
+            (authority.clone(), reason_code),
+        );
+    }
+
+    /// Permanently revoke a credential
+    pub fn revoke_credential(env: &Env, authority: &Address, credential_id: BytesN<32>, reason_code: u32) {
+        let mut credential = Self::load_authorized(env, authority, &credential_id);
+        if credential.status == CredentialStatus::Revoked {
+            panic!("credential already revoked");
+        }
+
+        Self::set_status_bit(env, &credential, StatusPurpose::Suspension, false);
+        Self::set_status_bit(env, &credential, StatusPurpose::Revocation, true);
+        credential.status = CredentialStatus::Revoked;
+        Self::record_change(env, authority, &credential, reason_code);
+
+        env.events().publish(
+            (symbol_short!("revoked"), credential_id),
+            (authority.clone(), reason_code),
+        );
+    }
+
+    /// Lift a suspension and return the credential to active status
+    pub fn reinstate_credential(env: &Env, authority: &Address, credential_id: BytesN<32>) {
+        let mut credential = Self::load_authorized(env, authority, &credential_id);
+        if credential.status != CredentialStatus::Suspended {
+            panic!("credential not suspended");
+        }
+
+        Self::set_status_bit(env, &credential, StatusPurpose::Suspension, false);
+        credential.status = CredentialStatus::Active;
+        Self::record_change(env, authority, &credential, 0);
+
+        env.events().publish(
+            (symbol_short!("reinstate"), credential_id),
+            authority.clone(),
+        );
+    }
+
+    /// Get the latest status change recorded for a credential
+    pub fn get_revocation_record(env: &Env, credential_id: BytesN<32>) -> Option<RevocationRecord> {
+        env.storage().persistent().get(&DataKey::Revocation(credential_id))
+    }
+
+    /// Get an issuer's full status bitstring. Bit `i` (most significant bit first)
+    /// is set when the credential with `status_index == i` has the given status.
+    pub fn get_status_list(env: &Env, issuer: Address, purpose: StatusPurpose) -> Bytes {
+        let size: u32 = env.storage().persistent()
+            .get(&DataKey::StatusListSize(issuer.clone())).unwrap_or(0);
+        let mut list: Bytes = env.storage().persistent()
+            .get(&DataKey::StatusList(issuer, purpose)).unwrap_or_else(|| Bytes::new(env));
+
+        // Pad so verifiers always receive one bit per issued credential
+        let byte_len = size.div_ceil(8);
+        while list.len() < byte_len {
+            list.push_back(0);
+        }
+        list
+    }
+
+    /// Load a credential and check that the caller is its issuer or the admin. For
+    /// institution-signed credentials the issuer is the account the institution signed
+    /// with, never the account that submitted the credential. The issuer also owns the
+    /// status lists the credential's bits live in.
+    fn load_authorized(env: &Env, authority: &Address, credential_id: &BytesN<32>) -> Credential {
+        authority.require_auth();
+
+        let credential: Credential = env.storage().persistent()
+            .get(credential_id).unwrap_or_else(|| panic!("credential not found"));
+
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        if *authority != credential.issuer && *authority != admin {
+            panic!("not authorized");
+        }
+        credential
+    }
+
+    fn set_status_bit(env: &Env, credential: &Credential, purpose: StatusPurpose, value: bool) {
+        let key = DataKey::StatusList(credential.issuer.clone(), purpose);
+        let mut list: Bytes = env.storage().persistent().get(&key).unwrap_or_else(|| Bytes::new(env));
+
+        let byte_index = credential.status_index / 8;
+        while list.len() <= byte_index {
+            list.push_back(0);
+        }
+
+        let mask = 0x80u8 >> (credential.status_index % 8);
+        let byte = list.get(byte_index).unwrap();
+        list.set(byte_index, if value { byte | mask } else { byte & !mask });
+        env.storage().persistent().set(&key, &list);
+    }
+
+    fn record_change(env: &Env, authority: &Address, credential: &Credential, reason_code: u32) {
+        let record = RevocationRecord {
+            credential_id: credential.id.clone(),
+            status: credential.status,
+            reason_code,
+            timestamp: env.ledger().timestamp(),
+            authority: authority.clone(),
+        };
+        env.storage().persistent().set(&DataKey::Revocation(credential.id.clone()), &record);
+        VerificationSystem::store_credential(env, credential);
+    }
+}
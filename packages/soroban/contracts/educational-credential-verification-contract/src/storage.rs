@@ -1,4 +1,5 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Symbol, BytesN, String};
+use crate::datatype::{AttesterKeyType, StatusPurpose};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,6 +10,21 @@ pub enum DataKey {
     NFT(BytesN<32>),
     Template(u32),
     Badge(BytesN<32>),
+    VerificationNFT(BytesN<32>),
+    OwnerNFTs(Address),
+    Institution(String),
+    CredentialProof(BytesN<32>),
+    UsedProofDigest(BytesN<32>),
+    // Revocation related keys
+    Revocation(BytesN<32>),
+    StatusListSize(Address),
+    StatusList(Address, StatusPurpose),
+    // Cross-chain related keys
+    ChainNonce(u32, u64),
+    ExportNonce(u32),
+    ExportSigner(AttesterKeyType),
+    ExportedAttestation(u32, u64),
+    CrossChainVerifications(BytesN<32>),
     // Security related keys
     MultiSigProposal(BytesN<32>),
     TimeLockOperation(BytesN<32>),
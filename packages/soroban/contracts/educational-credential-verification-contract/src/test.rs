@@ -214,3 +214,77 @@ fn test_verify_and_dispute_review() {
     let review_after_resolve = reviews_after_resolve.get_unchecked(0);
     assert_eq!(review_after_resolve.dispute_status, DisputeStatus::Resolved);
 }
+
+#[test]
+fn test_verification_nfts_are_unique_per_educator() {
+    let (env, client, _admin, reviewer, educator) = setup_test();
+    let other_educator = Address::generate(&env);
+
+    let specialties = vec![&env, String::from_str(&env, "Math")];
+    client.register_educator(&educator, &"E1".into_val(&env), &Vec::new(&env), &specialties);
+    client.register_educator(&other_educator, &"E2".into_val(&env), &Vec::new(&env), &Vec::new(&env));
+
+    // Both verifications happen at the same ledger timestamp
+    client.verify_educator(&reviewer, &educator, &VerificationLevel::Expert);
+    client.verify_educator(&reviewer, &other_educator, &VerificationLevel::Basic);
+
+    let first_id = client.get_educator(&educator).unwrap().nft_token_id.unwrap();
+    let second_id = client.get_educator(&other_educator).unwrap().nft_token_id.unwrap();
+    assert_ne!(first_id, second_id);
+
+    let first_nft = client.get_verification_nft(&first_id).unwrap();
+    assert_eq!(first_nft.owner, educator);
+    assert_eq!(first_nft.level, VerificationLevel::Expert);
+    assert_eq!(first_nft.specialties, specialties);
+
+    let second_nft = client.get_verification_nft(&second_id).unwrap();
+    assert_eq!(second_nft.owner, other_educator);
+    assert_eq!(second_nft.level, VerificationLevel::Basic);
+
+    let owned = client.get_verification_nfts(&educator);
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned.get_unchecked(0).id, first_id);
+}
+
+#[test]
+fn test_owner_can_burn_verification_nft() {
+    let (env, client, _admin, reviewer, educator) = setup_test();
+    client.register_educator(&educator, &"E".into_val(&env), &Vec::new(&env), &Vec::new(&env));
+    client.verify_educator(&reviewer, &educator, &VerificationLevel::Basic);
+
+    let nft_id = client.get_educator(&educator).unwrap().nft_token_id.unwrap();
+    client.burn_nft(&educator, &nft_id);
+
+    assert!(client.get_verification_nft(&nft_id).is_none());
+    assert_eq!(client.get_verification_nfts(&educator).len(), 0);
+    assert!(client.get_educator(&educator).unwrap().nft_token_id.is_none());
+}
+
+#[test]
+#[should_panic(expected = "not authorized")]
+fn test_burn_verification_nft_unauthorized() {
+    let (env, client, _admin, reviewer, educator) = setup_test();
+    client.register_educator(&educator, &"E".into_val(&env), &Vec::new(&env), &Vec::new(&env));
+    client.verify_educator(&reviewer, &educator, &VerificationLevel::Basic);
+
+    let nft_id = client.get_educator(&educator).unwrap().nft_token_id.unwrap();
+    let stranger = Address::generate(&env);
+    client.burn_nft(&stranger, &nft_id);
+}
+
+#[test]
+fn test_revoke_removes_verification_nft() {
+    let (env, client, admin, reviewer, educator) = setup_test();
+    client.register_educator(&educator, &"E".into_val(&env), &Vec::new(&env), &Vec::new(&env));
+    client.verify_educator(&reviewer, &educator, &VerificationLevel::Basic);
+    let first_id = client.get_educator(&educator).unwrap().nft_token_id.unwrap();
+
+    client.revoke_verification(&admin, &educator, &"Reason".into_val(&env));
+    assert!(client.get_verification_nft(&first_id).is_none());
+
+    // Re-verification mints a fresh token rather than reusing the old ID
+    client.verify_educator(&reviewer, &educator, &VerificationLevel::Advanced);
+    let second_id = client.get_educator(&educator).unwrap().nft_token_id.unwrap();
+    assert_ne!(first_id, second_id);
+    assert_eq!(client.get_verification_nfts(&educator).len(), 1);
+}
@@ -0,0 +1,823 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{
+    datatype::{AttesterKeyType, AttributeProof, CredentialProof, CredentialStatus, CrossChainAttestation, StatusPurpose},
+    utils::Utils,
+    EducatorVerificationContract, EducatorVerificationContractClient,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, Address, Bytes, BytesN, Env, String};
+
+fn setup_credential_test() -> (
+    Env,
+    EducatorVerificationContractClient<'static>,
+    Address,
+    Address,
+    SigningKey,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EducatorVerificationContract, ());
+    let client = EducatorVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_reviewer(&admin, &reviewer);
+
+    // University signing key registered by the admin
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    client.add_authorized_institution(
+        &admin,
+        &String::from_str(&env, "uni-1"),
+        &String::from_str(&env, "did:web:uni.example.edu"),
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &Address::generate(&env),
+    );
+
+    (env, client, admin, reviewer, signing_key)
+}
+
+fn hex_string(env: &Env, digest: &BytesN<32>) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = [0u8; 64];
+    for (i, byte) in digest.to_array().iter().enumerate() {
+        hex[2 * i] = HEX[(byte >> 4) as usize];
+        hex[2 * i + 1] = HEX[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex)
+}
+
+fn sign_credential(
+    env: &Env,
+    client: &EducatorVerificationContractClient,
+    key: &SigningKey,
+    institution_id: &str,
+    digest: &BytesN<32>,
+    tier: u32,
+    subject: &Address,
+) -> CredentialProof {
+    let message = Utils::credential_proof_message(env, &client.address, digest, tier, subject);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    let signature = key.sign(&message).to_bytes();
+
+    CredentialProof {
+        institution_id: String::from_str(env, institution_id),
+        digest: digest.clone(),
+        signature: BytesN::from_array(env, &signature),
+    }
+}
+
+#[test]
+fn test_register_institution_with_key() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+
+    let institution = client.get_institution(&String::from_str(&env, "uni-1")).unwrap();
+    assert_eq!(institution.did, String::from_str(&env, "did:web:uni.example.edu"));
+    assert_eq!(institution.public_key.to_array(), signing_key.verifying_key().to_bytes());
+}
+
+#[test]
+fn test_create_w3c_credential_with_institution_proof() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+
+    // A relayer that is not a reviewer can submit a credential signed by the institution
+    let credential_id = client.create_credential(
+        &relayer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof),
+    );
+
+    let credential = client.get_credential_info(&credential_id).unwrap();
+    assert!(credential.w3c_compliant);
+    assert_eq!(credential.subject, subject);
+    // The institution, not the relayer, is the issuer
+    let institution = client.get_institution(&String::from_str(&env, "uni-1")).unwrap();
+    assert_eq!(credential.issuer, institution.account);
+    let proof = client.get_credential_proof(&credential_id).unwrap();
+    assert_eq!(proof.institution_id, String::from_str(&env, "uni-1"));
+    assert_eq!(proof.digest, digest);
+    assert!(client.verify_credential_proof(&credential_id));
+}
+
+#[test]
+#[should_panic]
+fn test_create_credential_rejects_forged_signature() {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let forger = SigningKey::from_bytes(&[9u8; 32]);
+    let proof = sign_credential(&env, &client, &forger, "uni-1", &digest, 2, &subject);
+
+    client.create_credential(&reviewer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof));
+}
+
+#[test]
+#[should_panic]
+fn test_proof_is_bound_to_subject() {
+    let (env, client, _, reviewer, signing_key) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let other_subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+
+    client.create_credential(&reviewer, &other_subject, &hex_string(&env, &digest), &2, &true, &Some(proof));
+}
+
+#[test]
+#[should_panic(expected = "credential hash does not match signed digest")]
+fn test_w3c_hash_must_match_signed_digest() {
+    let (env, client, _, reviewer, signing_key) = setup_credential_test();
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let other_hash = hex_string(&env, &BytesN::from_array(&env, &[0xcd; 32]));
+
+    client.create_credential(&reviewer, &subject, &other_hash, &2, &true, &Some(proof));
+}
+
+#[test]
+#[should_panic(expected = "credential hash does not match signed digest")]
+fn test_non_w3c_hash_must_match_signed_digest() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let other_hash = hex_string(&env, &BytesN::from_array(&env, &[0xcd; 32]));
+
+    client.create_credential(&relayer, &subject, &other_hash, &2, &false, &Some(proof));
+}
+
+#[test]
+#[should_panic]
+fn test_proof_is_bound_to_tier() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 1, &subject);
+
+    client.create_credential(&relayer, &subject, &hex_string(&env, &digest), &4, &true, &Some(proof));
+}
+
+#[test]
+#[should_panic(expected = "proof already used")]
+fn test_proof_cannot_be_replayed() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let hash = hex_string(&env, &digest);
+
+    client.create_credential(&relayer, &subject, &hash, &2, &true, &Some(proof.clone()));
+    client.create_credential(&relayer, &subject, &hash, &2, &true, &Some(proof));
+}
+
+#[test]
+fn test_removed_institution_proofs_fail() {
+    let (env, client, admin, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let credential_id = client.create_credential(
+        &relayer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof),
+    );
+
+    client.remove_authorized_institution(&admin, &String::from_str(&env, "uni-1"));
+    assert!(!client.get_institution(&String::from_str(&env, "uni-1")).unwrap().active);
+    assert!(client.try_verify_credential_proof(&credential_id).is_err());
+
+    let digest = BytesN::from_array(&env, &[0xcd; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let result = client.try_create_credential(
+        &relayer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "institution not authorized")]
+fn test_proof_from_unknown_institution() {
+    let (env, client, _, reviewer, signing_key) = setup_credential_test();
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-unknown", &digest, 2, &subject);
+
+    client.create_credential(&reviewer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof));
+}
+
+#[test]
+#[should_panic(expected = "not authorized issuer")]
+fn test_unsigned_credential_requires_reviewer() {
+    let (env, client, _, _, _) = setup_credential_test();
+    let stranger = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+
+    client.create_credential(&stranger, &subject, &hash, &1, &false, &None);
+}
+
+#[test]
+fn test_credential_ids_are_unique() {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+
+    let first = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+    let second = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    assert_ne!(first, second);
+    assert_eq!(client.get_credentials_by_subject(&subject).len(), 2);
+    assert!(!client.verify_credential_proof(&first));
+}
+
+// --- Revocation Registry Tests ---
+
+#[test]
+fn test_suspend_and_reinstate_credential() {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    client.suspend_credential(&reviewer, &credential_id, &2);
+    assert_eq!(env.events().all().len(), 1);
+    assert_eq!(client.get_credential_info(&credential_id).unwrap().status, CredentialStatus::Suspended);
+    assert_eq!(client.get_status_list(&reviewer, &StatusPurpose::Suspension).get(0), Some(0x80));
+
+    let record = client.get_revocation_record(&credential_id).unwrap();
+    assert_eq!(record.status, CredentialStatus::Suspended);
+    assert_eq!(record.reason_code, 2);
+    assert_eq!(record.authority, reviewer);
+
+    client.reinstate_credential(&reviewer, &credential_id);
+    assert_eq!(client.get_credential_info(&credential_id).unwrap().status, CredentialStatus::Active);
+    assert_eq!(client.get_status_list(&reviewer, &StatusPurpose::Suspension).get(0), Some(0));
+}
+
+#[test]
+fn test_revocation_status_list_bits() {
+    let (env, client, admin, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for _ in 0..10 {
+        ids.push_back(client.create_credential(&reviewer, &subject, &hash, &1, &false, &None));
+    }
+
+    // Issuer revokes index 1, admin revokes index 9
+    client.revoke_credential(&reviewer, &ids.get_unchecked(1), &1);
+    client.revoke_credential(&admin, &ids.get_unchecked(9), &3);
+
+    let list = client.get_status_list(&reviewer, &StatusPurpose::Revocation);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.get(0), Some(0b0100_0000));
+    assert_eq!(list.get(1), Some(0b0100_0000));
+
+    assert_eq!(client.get_credential_info(&ids.get_unchecked(9)).unwrap().status, CredentialStatus::Revoked);
+    assert_eq!(client.get_credential_info(&ids.get_unchecked(0)).unwrap().status, CredentialStatus::Active);
+    assert_eq!(client.get_revocation_record(&ids.get_unchecked(9)).unwrap().authority, admin);
+
+    // Unused status lists are still sized to the issuer's credential count
+    let suspensions = client.get_status_list(&reviewer, &StatusPurpose::Suspension);
+    assert_eq!(suspensions.len(), 2);
+}
+
+#[test]
+fn test_revoke_suspended_credential_clears_suspension() {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    client.suspend_credential(&reviewer, &credential_id, &2);
+    client.revoke_credential(&reviewer, &credential_id, &1);
+
+    assert_eq!(client.get_status_list(&reviewer, &StatusPurpose::Suspension).get(0), Some(0));
+    assert_eq!(client.get_status_list(&reviewer, &StatusPurpose::Revocation).get(0), Some(0x80));
+}
+
+#[test]
+#[should_panic(expected = "not authorized")]
+fn test_revoke_credential_by_other_issuer() {
+    let (env, client, admin, reviewer, _) = setup_credential_test();
+    let other_reviewer = Address::generate(&env);
+    client.add_reviewer(&admin, &other_reviewer);
+
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    client.revoke_credential(&other_reviewer, &credential_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "credential revoked")]
+fn test_revoked_credential_cannot_be_reinstated_or_renewed() {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    client.revoke_credential(&reviewer, &credential_id, &1);
+    assert!(client.try_reinstate_credential(&reviewer, &credential_id).is_err());
+    assert_eq!(client.get_credential_info(&credential_id).unwrap().status, CredentialStatus::Revoked);
+    client.renew_credential(&reviewer, &credential_id);
+}
+
+#[test]
+fn test_signing_institution_controls_relayed_credential() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let credential_id = client.create_credential(
+        &relayer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof),
+    );
+
+    // The relayer that submitted the credential has no authority over it
+    assert!(client.try_suspend_credential(&relayer, &credential_id, &2).is_err());
+
+    let institution = client.get_institution(&String::from_str(&env, "uni-1")).unwrap();
+    client.suspend_credential(&institution.account, &credential_id, &2);
+    client.reinstate_credential(&institution.account, &credential_id);
+    client.revoke_credential(&institution.account, &credential_id, &1);
+    assert_eq!(client.get_credential_info(&credential_id).unwrap().status, CredentialStatus::Revoked);
+}
+
+#[test]
+fn test_status_changes_follow_the_issuer_status_list() {
+    let (env, client, admin, _, signing_key) = setup_credential_test();
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let credential_id = client.create_credential(
+        &Address::generate(&env), &subject, &hex_string(&env, &digest), &2, &true, &Some(proof),
+    );
+    let issuer = client.get_credential_info(&credential_id).unwrap().issuer;
+
+    // Rotating the institution's account does not move the credential to another status list
+    let new_account = Address::generate(&env);
+    client.add_authorized_institution(
+        &admin,
+        &String::from_str(&env, "uni-1"),
+        &String::from_str(&env, "did:web:uni.example.edu"),
+        &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()),
+        &new_account,
+    );
+    assert!(client.try_suspend_credential(&new_account, &credential_id, &2).is_err());
+
+    client.suspend_credential(&issuer, &credential_id, &2);
+    assert_eq!(client.get_status_list(&issuer, &StatusPurpose::Suspension).get(0), Some(0x80));
+}
+
+#[test]
+#[should_panic(expected = "credential suspended")]
+fn test_suspended_credential_cannot_be_renewed() {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    client.suspend_credential(&reviewer, &credential_id, &2);
+    client.renew_credential(&reviewer, &credential_id);
+}
+
+// --- Cross-Chain Attestation Tests ---
+
+const EVM_CHAIN: u32 = 1;
+const COSMOS_CHAIN: u32 = 2;
+
+fn attestation_message(env: &Env, contract: &Address, credential_id: &BytesN<32>, chain_id: u32, nonce: u64) -> Bytes {
+    let mut message = soroban_sdk::xdr::ToXdr::to_xdr(contract.clone(), env);
+    message.extend_from_array(&env.ledger().network_id().to_array());
+    message.extend_from_array(&credential_id.to_array());
+    message.extend_from_array(&chain_id.to_be_bytes());
+    message.extend_from_array(&nonce.to_be_bytes());
+    message
+}
+
+fn sign_ed25519_attestation(
+    env: &Env,
+    contract: &Address,
+    key: &SigningKey,
+    credential_id: &BytesN<32>,
+    chain_id: u32,
+    nonce: u64,
+) -> CrossChainAttestation {
+    let message: std::vec::Vec<u8> = attestation_message(env, contract, credential_id, chain_id, nonce).iter().collect();
+    CrossChainAttestation {
+        credential_id: credential_id.clone(),
+        chain_id,
+        nonce,
+        signature: BytesN::from_array(env, &key.sign(&message).to_bytes()),
+        recovery_id: 0,
+    }
+}
+
+fn sign_secp256k1_attestation(
+    env: &Env,
+    contract: &Address,
+    key: &k256::ecdsa::SigningKey,
+    credential_id: &BytesN<32>,
+    chain_id: u32,
+    nonce: u64,
+) -> CrossChainAttestation {
+    let digest = env.crypto().keccak256(&attestation_message(env, contract, credential_id, chain_id, nonce));
+    let (signature, recovery_id) = key.sign_prehash_recoverable(&digest.to_array()).unwrap();
+    CrossChainAttestation {
+        credential_id: credential_id.clone(),
+        chain_id,
+        nonce,
+        signature: BytesN::from_array(env, &signature.to_bytes().into()),
+        recovery_id: recovery_id.to_byte() as u32,
+    }
+}
+
+fn setup_cross_chain_test() -> (
+    Env,
+    EducatorVerificationContractClient<'static>,
+    Address,
+    BytesN<32>,
+    SigningKey,
+    k256::ecdsa::SigningKey,
+) {
+    let (env, client, admin, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    let ed_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.register_chain(
+        &admin,
+        &COSMOS_CHAIN,
+        &String::from_str(&env, "cosmos"),
+        &AttesterKeyType::Ed25519,
+        &Bytes::from_array(&env, &ed_key.verifying_key().to_bytes()),
+    );
+
+    let evm_key = k256::ecdsa::SigningKey::from_slice(&[5u8; 32]).unwrap();
+    let evm_public = evm_key.verifying_key().to_encoded_point(false);
+    client.register_chain(
+        &admin,
+        &EVM_CHAIN,
+        &String::from_str(&env, "ethereum"),
+        &AttesterKeyType::Secp256k1,
+        &Bytes::from_slice(&env, evm_public.as_bytes()),
+    );
+
+    (env, client, admin, credential_id, ed_key, evm_key)
+}
+
+#[test]
+fn test_verify_cross_chain_ed25519_attestation() {
+    let (env, client, _, credential_id, ed_key, _) = setup_cross_chain_test();
+
+    let attestation = sign_ed25519_attestation(&env, &client.address, &ed_key, &credential_id, COSMOS_CHAIN, 1);
+    assert!(client.verify_cross_chain(&attestation));
+
+    assert!(client.get_credential_info(&credential_id).unwrap().cross_chain_verified);
+    assert_eq!(client.get_cross_chain_verifications(&credential_id), soroban_sdk::vec![&env, COSMOS_CHAIN]);
+}
+
+#[test]
+fn test_verify_cross_chain_secp256k1_attestation() {
+    let (env, client, _, credential_id, _, evm_key) = setup_cross_chain_test();
+
+    let attestation = sign_secp256k1_attestation(&env, &client.address, &evm_key, &credential_id, EVM_CHAIN, 7);
+    assert!(client.verify_cross_chain(&attestation));
+    assert_eq!(client.get_cross_chain_verifications(&credential_id), soroban_sdk::vec![&env, EVM_CHAIN]);
+}
+
+#[test]
+#[should_panic(expected = "invalid attestation signature")]
+fn test_verify_cross_chain_rejects_wrong_secp256k1_signer() {
+    let (env, client, _, credential_id, _, _) = setup_cross_chain_test();
+
+    let impostor = k256::ecdsa::SigningKey::from_slice(&[6u8; 32]).unwrap();
+    let attestation = sign_secp256k1_attestation(&env, &client.address, &impostor, &credential_id, EVM_CHAIN, 1);
+    client.verify_cross_chain(&attestation);
+}
+
+#[test]
+#[should_panic(expected = "attestation nonce already used")]
+fn test_verify_cross_chain_rejects_replay() {
+    let (env, client, _, credential_id, ed_key, _) = setup_cross_chain_test();
+
+    let attestation = sign_ed25519_attestation(&env, &client.address, &ed_key, &credential_id, COSMOS_CHAIN, 1);
+    client.verify_cross_chain(&attestation);
+    client.verify_cross_chain(&attestation);
+}
+
+#[test]
+fn test_verify_cross_chain_accepts_out_of_order_nonces() {
+    let (env, client, _, credential_id, ed_key, _) = setup_cross_chain_test();
+
+    let later = sign_ed25519_attestation(&env, &client.address, &ed_key, &credential_id, COSMOS_CHAIN, 5);
+    let earlier = sign_ed25519_attestation(&env, &client.address, &ed_key, &credential_id, COSMOS_CHAIN, 3);
+    assert!(client.verify_cross_chain(&later));
+    assert!(client.verify_cross_chain(&earlier));
+    assert!(client.try_verify_cross_chain(&later).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_verify_cross_chain_rejects_attestation_for_other_contract() {
+    let (env, client, _, credential_id, ed_key, _) = setup_cross_chain_test();
+
+    let other_contract = Address::generate(&env);
+    let attestation = sign_ed25519_attestation(&env, &other_contract, &ed_key, &credential_id, COSMOS_CHAIN, 1);
+    client.verify_cross_chain(&attestation);
+}
+
+#[test]
+#[should_panic(expected = "chain not supported")]
+fn test_verify_cross_chain_rejects_deactivated_chain() {
+    let (env, client, admin, credential_id, ed_key, _) = setup_cross_chain_test();
+
+    client.deactivate_chain(&admin, &COSMOS_CHAIN);
+    let attestation = sign_ed25519_attestation(&env, &client.address, &ed_key, &credential_id, COSMOS_CHAIN, 1);
+    client.verify_cross_chain(&attestation);
+}
+
+#[test]
+#[should_panic(expected = "invalid attester key length")]
+fn test_register_chain_validates_key_length() {
+    let (env, client, admin, _, _) = setup_credential_test();
+    client.register_chain(
+        &admin,
+        &EVM_CHAIN,
+        &String::from_str(&env, "ethereum"),
+        &AttesterKeyType::Secp256k1,
+        &Bytes::from_array(&env, &[1u8; 32]),
+    );
+}
+
+#[test]
+fn test_export_attestation() {
+    let (env, client, _, credential_id, _, _) = setup_cross_chain_test();
+    let requester = client.get_credential_info(&credential_id).unwrap().subject;
+
+    let first = client.export_attestation(&requester, &credential_id, &EVM_CHAIN);
+    assert_eq!(first.credential_id, credential_id);
+    assert_eq!(first.status, CredentialStatus::Active);
+    assert_eq!(first.target_chain_id, EVM_CHAIN);
+    assert_eq!(first.nonce, 1);
+    assert_eq!(first.network_id, env.ledger().network_id());
+
+    // Rebuild the payload the way a verifier on the target chain would
+    let mut payload = Bytes::from_array(&env, &first.network_id.to_array());
+    payload.append(&soroban_sdk::xdr::ToXdr::to_xdr(first.contract.clone(), &env));
+    payload.extend_from_array(&credential_id.to_array());
+    payload.extend_from_array(&EVM_CHAIN.to_be_bytes());
+    payload.push_back(0);
+    payload.extend_from_array(&first.expiration.to_be_bytes());
+    payload.extend_from_array(&first.nonce.to_be_bytes());
+    payload.extend_from_array(&first.timestamp.to_be_bytes());
+    let expected: BytesN<32> = env.crypto().keccak256(&payload).into();
+    assert_eq!(first.digest, expected);
+
+    let second = client.export_attestation(&requester, &credential_id, &EVM_CHAIN);
+    assert_eq!(second.nonce, 2);
+    assert_ne!(first.digest, second.digest);
+    assert!(second.signature.is_none());
+}
+
+#[test]
+fn test_export_attestation_requester_restricted() {
+    let (env, client, admin, credential_id, _, _) = setup_cross_chain_test();
+
+    let outsider = Address::generate(&env);
+    assert!(client.try_export_attestation(&outsider, &credential_id, &EVM_CHAIN).is_err());
+    assert!(client.get_exported_attestation(&EVM_CHAIN, &1).is_none());
+
+    assert_eq!(client.export_attestation(&admin, &credential_id, &EVM_CHAIN).nonce, 1);
+}
+
+#[test]
+fn test_sign_exported_attestation() {
+    let (env, client, admin, credential_id, _, _) = setup_cross_chain_test();
+    let requester = client.get_credential_info(&credential_id).unwrap().issuer;
+
+    let export_key = k256::ecdsa::SigningKey::from_slice(&[8u8; 32]).unwrap();
+    let export_public = export_key.verifying_key().to_encoded_point(false);
+    client.set_export_signer(&admin, &AttesterKeyType::Secp256k1, &Bytes::from_slice(&env, export_public.as_bytes()));
+
+    let exported = client.export_attestation(&requester, &credential_id, &EVM_CHAIN);
+    let (signature, recovery_id) = export_key.sign_prehash_recoverable(&exported.digest.to_array()).unwrap();
+    let signature = BytesN::from_array(&env, &signature.to_bytes().into());
+
+    let signed = client.sign_exported_attestation(&EVM_CHAIN, &exported.nonce, &signature, &(recovery_id.to_byte() as u32));
+    assert_eq!(signed.signature, Some(signature.clone()));
+    assert_eq!(client.get_exported_attestation(&EVM_CHAIN, &exported.nonce).unwrap().signature, Some(signature));
+}
+
+#[test]
+#[should_panic(expected = "invalid attestation signature")]
+fn test_sign_exported_attestation_rejects_other_signer() {
+    let (env, client, admin, credential_id, _, _) = setup_cross_chain_test();
+    let requester = client.get_credential_info(&credential_id).unwrap().issuer;
+
+    let export_key = k256::ecdsa::SigningKey::from_slice(&[8u8; 32]).unwrap();
+    let export_public = export_key.verifying_key().to_encoded_point(false);
+    client.set_export_signer(&admin, &AttesterKeyType::Secp256k1, &Bytes::from_slice(&env, export_public.as_bytes()));
+
+    let exported = client.export_attestation(&requester, &credential_id, &EVM_CHAIN);
+    let impostor = k256::ecdsa::SigningKey::from_slice(&[9u8; 32]).unwrap();
+    let (signature, recovery_id) = impostor.sign_prehash_recoverable(&exported.digest.to_array()).unwrap();
+    client.sign_exported_attestation(
+        &EVM_CHAIN, &exported.nonce, &BytesN::from_array(&env, &signature.to_bytes().into()), &(recovery_id.to_byte() as u32),
+    );
+}
+
+// --- Selective Disclosure Tests ---
+
+fn attribute_leaf(env: &Env, salt: u8, key: &str, value: &str) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &[0x00]);
+    data.extend_from_array(&[salt; 32]);
+    data.extend_from_array(&(key.len() as u32).to_be_bytes());
+    data.extend_from_slice(key.as_bytes());
+    data.extend_from_slice(value.as_bytes());
+    env.crypto().sha256(&data).into()
+}
+
+fn merkle_node(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &[0x01]);
+    data.extend_from_array(&first.to_array());
+    data.extend_from_array(&second.to_array());
+    env.crypto().sha256(&data).into()
+}
+
+/// Commits a four-attribute tree and returns the leaves alongside the credential
+fn setup_disclosure_test() -> (
+    Env,
+    EducatorVerificationContractClient<'static>,
+    Address,
+    BytesN<32>,
+    [BytesN<32>; 4],
+) {
+    let (env, client, _, reviewer, _) = setup_credential_test();
+    let subject = Address::generate(&env);
+    let hash = hex_string(&env, &BytesN::from_array(&env, &[0xab; 32]));
+    let credential_id = client.create_credential(&reviewer, &subject, &hash, &1, &false, &None);
+
+    let leaves = [
+        attribute_leaf(&env, 1, "degree", "BSc Computer Science"),
+        attribute_leaf(&env, 2, "grade", "A"),
+        attribute_leaf(&env, 3, "birth_date", "1990-01-01"),
+        attribute_leaf(&env, 4, "institution_id", "uni-1"),
+    ];
+    let root = merkle_node(
+        &env,
+        &merkle_node(&env, &leaves[0], &leaves[1]),
+        &merkle_node(&env, &leaves[2], &leaves[3]),
+    );
+    client.commit_attributes(&reviewer, &credential_id, &root, &None);
+
+    (env, client, reviewer, credential_id, leaves)
+}
+
+#[test]
+fn test_verify_disclosed_attribute() {
+    let (env, client, _, credential_id, leaves) = setup_disclosure_test();
+
+    let proof = AttributeProof {
+        salt: BytesN::from_array(&env, &[1; 32]),
+        siblings: soroban_sdk::vec![&env, leaves[1].clone(), merkle_node(&env, &leaves[2], &leaves[3])],
+    };
+    assert!(client.verify_credential_attribute(
+        &credential_id,
+        &String::from_str(&env, "degree"),
+        &String::from_str(&env, "BSc Computer Science"),
+        &proof,
+    ));
+
+    // The same proof does not verify a different value
+    assert!(!client.verify_credential_attribute(
+        &credential_id,
+        &String::from_str(&env, "degree"),
+        &String::from_str(&env, "PhD Computer Science"),
+        &proof,
+    ));
+}
+
+#[test]
+fn test_verify_attribute_rejects_wrong_salt() {
+    let (env, client, _, credential_id, leaves) = setup_disclosure_test();
+
+    let proof = AttributeProof {
+        salt: BytesN::from_array(&env, &[9; 32]),
+        siblings: soroban_sdk::vec![&env, leaves[3].clone(), merkle_node(&env, &leaves[0], &leaves[1])],
+    };
+    assert!(!client.verify_credential_attribute(
+        &credential_id,
+        &String::from_str(&env, "birth_date"),
+        &String::from_str(&env, "1990-01-01"),
+        &proof,
+    ));
+}
+
+#[test]
+fn test_revoked_credential_attributes_do_not_verify() {
+    let (env, client, reviewer, credential_id, leaves) = setup_disclosure_test();
+    client.revoke_credential(&reviewer, &credential_id, &1);
+
+    let proof = AttributeProof {
+        salt: BytesN::from_array(&env, &[2; 32]),
+        siblings: soroban_sdk::vec![&env, leaves[0].clone(), merkle_node(&env, &leaves[2], &leaves[3])],
+    };
+    assert!(!client.verify_credential_attribute(
+        &credential_id,
+        &String::from_str(&env, "grade"),
+        &String::from_str(&env, "A"),
+        &proof,
+    ));
+}
+
+#[test]
+#[should_panic(expected = "attributes already committed")]
+fn test_attributes_root_is_immutable() {
+    let (env, client, reviewer, credential_id, _) = setup_disclosure_test();
+    client.commit_attributes(&reviewer, &credential_id, &BytesN::from_array(&env, &[0; 32]), &None);
+}
+
+#[test]
+fn test_expired_credential_attributes_do_not_verify() {
+    let (env, client, _, credential_id, leaves) = setup_disclosure_test();
+    let expiration = client.get_credential_info(&credential_id).unwrap().expiration;
+    env.ledger().set_timestamp(expiration + 1);
+
+    let proof = AttributeProof {
+        salt: BytesN::from_array(&env, &[2; 32]),
+        siblings: soroban_sdk::vec![&env, leaves[0].clone(), merkle_node(&env, &leaves[2], &leaves[3])],
+    };
+    assert!(!client.verify_credential_attribute(
+        &credential_id,
+        &String::from_str(&env, "grade"),
+        &String::from_str(&env, "A"),
+        &proof,
+    ));
+}
+
+#[test]
+fn test_oversized_attribute_value_does_not_verify() {
+    let (env, client, _, credential_id, leaves) = setup_disclosure_test();
+
+    let proof = AttributeProof {
+        salt: BytesN::from_array(&env, &[2; 32]),
+        siblings: soroban_sdk::vec![&env, leaves[0].clone(), merkle_node(&env, &leaves[2], &leaves[3])],
+    };
+    let long_value = String::from_bytes(&env, &[b'a'; 300]);
+    assert!(!client.verify_credential_attribute(
+        &credential_id,
+        &String::from_str(&env, "grade"),
+        &long_value,
+        &proof,
+    ));
+}
+
+#[test]
+fn test_signed_credential_attributes_need_institution_signature() {
+    let (env, client, _, _, signing_key) = setup_credential_test();
+    let relayer = Address::generate(&env);
+    let subject = Address::generate(&env);
+
+    let digest = BytesN::from_array(&env, &[0xab; 32]);
+    let proof = sign_credential(&env, &client, &signing_key, "uni-1", &digest, 2, &subject);
+    let credential_id = client.create_credential(
+        &relayer, &subject, &hex_string(&env, &digest), &2, &true, &Some(proof),
+    );
+    let root = attribute_leaf(&env, 1, "degree", "BSc Computer Science");
+
+    // The relayer cannot commit a root on its own
+    assert!(client.try_commit_attributes(&relayer, &credential_id, &root, &None).is_err());
+    let forger = SigningKey::from_bytes(&[9u8; 32]);
+    let message: std::vec::Vec<u8> = Utils::attributes_root_message(&env, &client.address, &digest, &root).iter().collect();
+    let forged = BytesN::from_array(&env, &forger.sign(&message).to_bytes());
+    assert!(client.try_commit_attributes(&relayer, &credential_id, &root, &Some(forged)).is_err());
+
+    let signature = BytesN::from_array(&env, &signing_key.sign(&message).to_bytes());
+    client.commit_attributes(&relayer, &credential_id, &root, &Some(signature));
+    assert_eq!(client.get_credential_info(&credential_id).unwrap().attributes_root, Some(root));
+}
@@ -1,74 +1,92 @@
-use soroban_sdk::{Env, String, BytesN, Address};
+use soroban_sdk::{xdr::ToXdr, Env, String, Bytes, BytesN, Address};
 use crate::datatype::{Credential, VerificationLevel};
 
 pub struct Utils;
 
 impl Utils {
-    /// Generate a unique credential ID using timestamp and hash
-    pub fn generate_credential_id(env: &Env, credential_hash: &String, issuer: &Address) -> BytesN<32> {
-        let timestamp = env.ledger().timestamp();
-        let mut bytes = [0u8; 32];
-        
-        // Use timestamp for first 8 bytes
-        bytes[0] = (timestamp >> 56) as u8;
-        bytes[1] = (timestamp >> 48) as u8;
-        bytes[2] = (timestamp >> 40) as u8;
-        bytes[3] = (timestamp >> 32) as u8;
-        bytes[4] = (timestamp >> 24) as u8;
-        bytes[5] = (timestamp >> 16) as u8;
-        bytes[6] = (timestamp >> 8) as u8;
-        bytes[7] = timestamp as u8;
-        
-        // Use credential hash for next 16 bytes (simplified)
-        // Note: Soroban String doesn't have direct to_string or as_bytes methods
-        // We'll use a simpler approach with length-based hashing
-        let hash_len = credential_hash.len();
-        bytes[8] = (hash_len >> 8) as u8;
-        bytes[9] = hash_len as u8;
-        
-        // Use issuer address for last 8 bytes (simplified)
-        // Since Soroban String doesn't have as_bytes(), we'll use a simple approach
-        let issuer_len = issuer.to_string().len();
-        bytes[24] = (issuer_len >> 8) as u8;
-        bytes[25] = issuer_len as u8;
-        
-        BytesN::from_array(env, &bytes)
+    /// Generate a unique credential ID from the credential hash, issuer and a monotonically increasing nonce
+    pub fn generate_credential_id(env: &Env, credential_hash: &String, issuer: &Address, nonce: u32) -> BytesN<32> {
+        let mut data = credential_hash.clone().to_xdr(env);
+        data.append(&issuer.clone().to_xdr(env));
+        data.extend_from_array(&env.ledger().timestamp().to_be_bytes());
+        data.extend_from_array(&nonce.to_be_bytes());
+
+        env.crypto().sha256(&data).into()
     }
 
-    /// Generate a unique NFT ID
-    pub fn generate_nft_id(env: &Env, owner: &Address, template_id: u32) -> BytesN<32> {
-        let timestamp = env.ledger().timestamp();
-        let mut bytes = [0u8; 32];
-        
-        // Use timestamp for first 8 bytes
-        bytes[0] = (timestamp >> 56) as u8;
-        bytes[1] = (timestamp >> 48) as u8;
-        bytes[2] = (timestamp >> 40) as u8;
-        bytes[3] = (timestamp >> 32) as u8;
-        bytes[4] = (timestamp >> 24) as u8;
-        bytes[5] = (timestamp >> 16) as u8;
-        bytes[6] = (timestamp >> 8) as u8;
-        bytes[7] = timestamp as u8;
-        
-        // Use template ID for next 4 bytes
-        bytes[8] = (template_id >> 24) as u8;
-        bytes[9] = (template_id >> 16) as u8;
-        bytes[10] = (template_id >> 8) as u8;
-        bytes[11] = template_id as u8;
-        
-        // Use owner address for remaining bytes (simplified)
-        let owner_len = owner.to_string().len();
-        bytes[12] = (owner_len >> 8) as u8;
-        bytes[13] = owner_len as u8;
-        
-        BytesN::from_array(env, &bytes)
+    /// Generate a unique NFT ID from the owner, template and a monotonically increasing nonce
+    pub fn generate_nft_id(env: &Env, owner: &Address, template_id: u32, nonce: u32) -> BytesN<32> {
+        let mut data = owner.clone().to_xdr(env);
+        data.extend_from_array(&env.ledger().timestamp().to_be_bytes());
+        data.extend_from_array(&template_id.to_be_bytes());
+        data.extend_from_array(&nonce.to_be_bytes());
+
+        env.crypto().sha256(&data).into()
+    }
+
+    /// Validate that a W3C credential hash is the hex encoding of the signed credential digest
+    pub fn validate_w3c_credential(credential_hash: &String, digest: &BytesN<32>) -> bool {
+        if credential_hash.len() != 64 {
+            return false;
+        }
+
+        let mut hash_hex = [0u8; 64];
+        credential_hash.copy_into_slice(&mut hash_hex);
+
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        for (i, byte) in digest.to_array().iter().enumerate() {
+            if hash_hex[2 * i].to_ascii_lowercase() != HEX[(byte >> 4) as usize]
+                || hash_hex[2 * i + 1].to_ascii_lowercase() != HEX[(byte & 0x0f) as usize]
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Build the message an institution signs when issuing a credential. The contract
+    /// address and network id keep a signature from being used on another deployment.
+    pub fn credential_proof_message(
+        env: &Env,
+        contract: &Address,
+        digest: &BytesN<32>,
+        tier: u32,
+        subject: &Address,
+    ) -> Bytes {
+        let mut message = contract.clone().to_xdr(env);
+        message.extend_from_array(&env.ledger().network_id().to_array());
+        message.extend_from_array(&digest.to_array());
+        message.extend_from_array(&tier.to_be_bytes());
+        message.append(&subject.clone().to_xdr(env));
+        message
+    }
+
+    /// Build the message an institution signs to commit a credential's attribute root
+    pub fn attributes_root_message(
+        env: &Env,
+        contract: &Address,
+        digest: &BytesN<32>,
+        attributes_root: &BytesN<32>,
+    ) -> Bytes {
+        let mut message = contract.clone().to_xdr(env);
+        message.extend_from_array(&env.ledger().network_id().to_array());
+        message.extend_from_array(&digest.to_array());
+        message.extend_from_array(&attributes_root.to_array());
+        message
     }
 
-    /// Validate W3C verifiable credential format
-    pub fn validate_w3c_credential(credential_hash: &String) -> bool {
-        // Basic validation for W3C credential format
-        // In a real implementation, this would validate the actual W3C VC structure
-        credential_hash.len() >= 64 && credential_hash.len() <= 128
+    /// Copy a string's UTF-8 bytes into a `Bytes` value. Returns `None` for strings
+    /// longer than 256 bytes.
+    pub fn string_to_bytes(env: &Env, value: &String) -> Option<Bytes> {
+        const MAX_LEN: usize = 256;
+        let len = value.len() as usize;
+        if len > MAX_LEN {
+            return None;
+        }
+
+        let mut buffer = [0u8; MAX_LEN];
+        value.copy_into_slice(&mut buffer[..len]);
+        Some(Bytes::from_slice(env, &buffer[..len]))
     }
 
     /// Check if credential has expired
@@ -136,13 +154,6 @@ impl Utils {
         }
     }
 
-    /// Validate cross-chain verification data
-    pub fn validate_cross_chain_data(chain_id: u32, verification_hash: &String) -> bool {
-        // Basic validation for cross-chain data
-        // In a real implementation, this would validate against specific chain formats
-        chain_id > 0 && verification_hash.len() == 64
-    }
-
     /// Generate achievement badge criteria hash
     pub fn generate_badge_criteria_hash(
         env: &Env,
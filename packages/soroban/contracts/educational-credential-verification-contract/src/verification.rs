@@ -1,7 +1,8 @@
 use soroban_sdk::{Address, Env, Vec, Map, String, BytesN};
-use crate::datatype::{Educator, VerificationLevel, Credential};
+use crate::datatype::{Educator, VerificationLevel, Credential, CredentialProof, CredentialStatus, Institution};
 use crate::nft::NFTImplementation;
-use crate::storage::{ADMIN, REVIEWERS, VERIFIED_CREDS, SIGNATURES, AUTH_INST, CREDENTIALS, EXPIRED_CREDENTIALS, CROSS_CHAIN_REGISTRY, CREDENTIAL_COUNTER};
+use crate::revocation::RevocationSystem;
+use crate::storage::{ADMIN, REVIEWERS, VERIFIED_CREDS, SIGNATURES, AUTH_INST, CREDENTIALS, EXPIRED_CREDENTIALS, CREDENTIAL_COUNTER, DataKey};
 use crate::utils::Utils;
 
 pub struct VerificationSystem;
@@ -96,13 +97,69 @@ impl VerificationSystem {
         }
     }
 
-    pub fn add_authorized_institution(env: &Env, admin: &Address, institution_id: String) {
+    pub fn add_authorized_institution(
+        env: &Env,
+        admin: &Address,
+        institution_id: String,
+        did: String,
+        public_key: BytesN<32>,
+        account: Address,
+    ) {
         Self::verify_admin(env, admin);
         let mut authorized_institutions: Vec<String> = env.storage().persistent().get(&AUTH_INST).unwrap_or_else(|| Vec::new(env));
         if !authorized_institutions.contains(&institution_id) {
-            authorized_institutions.push_back(institution_id);
+            authorized_institutions.push_back(institution_id.clone());
             env.storage().persistent().set(&AUTH_INST, &authorized_institutions);
         }
+
+        // Registering an existing institution again rotates its DID and signing key
+        let institution = Institution {
+            id: institution_id.clone(),
+            did,
+            public_key,
+            account,
+            active: true,
+            added_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::Institution(institution_id), &institution);
+    }
+
+    /// Stop accepting proofs signed by an institution. Its existing credentials no
+    /// longer pass `verify_credential_proof`.
+    pub fn remove_authorized_institution(env: &Env, admin: &Address, institution_id: String) {
+        Self::verify_admin(env, admin);
+        let mut institution = Self::get_institution(env, institution_id.clone())
+            .unwrap_or_else(|| panic!("institution not found"));
+        institution.active = false;
+        env.storage().persistent().set(&DataKey::Institution(institution_id.clone()), &institution);
+
+        let authorized_institutions: Vec<String> = env.storage().persistent().get(&AUTH_INST).unwrap_or_else(|| Vec::new(env));
+        let mut remaining = Vec::new(env);
+        for id in authorized_institutions.iter() {
+            if id != institution_id {
+                remaining.push_back(id);
+            }
+        }
+        env.storage().persistent().set(&AUTH_INST, &remaining);
+    }
+
+    pub fn get_institution(env: &Env, institution_id: String) -> Option<Institution> {
+        env.storage().persistent().get(&DataKey::Institution(institution_id))
+    }
+
+    /// Check an institution's ed25519 signature over a credential digest, tier and subject.
+    /// Panics if the institution is unknown or removed, or the signature is invalid.
+    pub fn verify_proof(env: &Env, proof: &CredentialProof, tier: u32, subject: &Address) -> Institution {
+        let institution = match Self::get_institution(env, proof.institution_id.clone()) {
+            Some(institution) if institution.active => institution,
+            _ => panic!("institution not authorized"),
+        };
+
+        let message = Utils::credential_proof_message(
+            env, &env.current_contract_address(), &proof.digest, tier, subject,
+        );
+        env.crypto().ed25519_verify(&institution.public_key, &message, &proof.signature);
+        institution
     }
 
     pub fn mint_verification_nft(
@@ -110,9 +167,8 @@ impl VerificationSystem {
         recipient: &Address, 
         level: &VerificationLevel,
         specialties: &Vec<String>
-    ) -> String {
-        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
-        NFTImplementation::mint_nft(env.clone(), admin, recipient.clone(), level.clone(), specialties.clone())
+    ) -> BytesN<32> {
+        NFTImplementation::mint_nft(env.clone(), recipient.clone(), level.clone(), specialties.clone())
     }
 
     pub fn calculate_verification_level(_env: &Env, educators: &Map<Address, Educator>, educator_address: &Address) -> VerificationLevel {
@@ -150,7 +206,10 @@ impl VerificationSystem {
         }
     }
 
-    /// Create a new credential with tiered verification and W3C compliance
+    /// Create a new credential with tiered verification and W3C compliance.
+    /// Credentials carrying a valid institution proof may be submitted by any account
+    /// (e.g. a relayer) and are issued by the institution's account; credentials
+    /// without one must be issued by a reviewer. Each signed digest can be used once.
     pub fn create_credential(
         env: &Env,
         issuer: &Address,
@@ -158,10 +217,11 @@ impl VerificationSystem {
         credential_hash: String,
         tier: u32,
         w3c_compliant: bool,
+        proof: Option<CredentialProof>,
     ) -> BytesN<32> {
         issuer.require_auth();
         
-        if !Self::is_reviewer(env, issuer) {
+        if proof.is_none() && !Self::is_reviewer(env, issuer) {
             panic!("not authorized issuer");
         }
 
@@ -169,11 +229,29 @@ impl VerificationSystem {
             panic!("invalid credential hash format");
         }
 
-        if w3c_compliant && !Utils::validate_w3c_credential(&credential_hash) {
-            panic!("invalid W3C credential format");
-        }
+        let credential_issuer = match &proof {
+            Some(proof) => {
+                if !Utils::validate_w3c_credential(&credential_hash, &proof.digest) {
+                    panic!("credential hash does not match signed digest");
+                }
+                let used_key = DataKey::UsedProofDigest(proof.digest.clone());
+                if env.storage().persistent().has(&used_key) {
+                    panic!("proof already used");
+                }
+                let institution = Self::verify_proof(env, proof, tier, subject);
+                env.storage().persistent().set(&used_key, &true);
+                institution.account
+            }
+            None => {
+                if w3c_compliant {
+                    panic!("W3C credential requires an issuer proof");
+                }
+                issuer.clone()
+            }
+        };
 
-        let credential_id = Utils::generate_credential_id(env, &credential_hash, issuer);
+        let counter: u32 = env.storage().persistent().get(&CREDENTIAL_COUNTER).unwrap_or(0);
+        let credential_id = Utils::generate_credential_id(env, &credential_hash, &credential_issuer, counter);
         let expiration = Utils::calculate_expiration_timestamp(env, tier);
         
         let credential = Credential {
@@ -181,11 +259,14 @@ impl VerificationSystem {
             tier,
             expiration,
             w3c_compliant,
-            issuer: issuer.clone(),
+            issuer: credential_issuer.clone(),
             subject: subject.clone(),
             credential_hash,
             cross_chain_verified: false,
             renewal_count: 0,
+            status: CredentialStatus::Active,
+            status_index: RevocationSystem::allocate_status_index(env, &credential_issuer),
+            attributes_root: None,
         };
 
         // Store the credential
@@ -197,8 +278,11 @@ impl VerificationSystem {
         credentials.set(credential_id.clone(), credential);
         env.storage().persistent().set(&CREDENTIALS, &credentials);
 
+        if let Some(proof) = proof {
+            env.storage().persistent().set(&DataKey::CredentialProof(credential_id.clone()), &proof);
+        }
+
         // Increment credential counter
-        let counter: u32 = env.storage().persistent().get(&CREDENTIAL_COUNTER).unwrap_or(0);
         env.storage().persistent().set(&CREDENTIAL_COUNTER, &(counter + 1));
 
         credential_id
@@ -220,6 +304,13 @@ impl VerificationSystem {
             .get(&CREDENTIALS).unwrap_or_else(|| Map::new(env));
         
         if let Some(mut credential) = credentials.get(credential_id.clone()) {
+            if credential.status == CredentialStatus::Revoked {
+                panic!("credential revoked");
+            }
+            if credential.status == CredentialStatus::Suspended {
+                panic!("credential suspended");
+            }
+
             // Update expiration and renewal count
             credential.expiration = Utils::calculate_expiration_timestamp(env, credential.tier);
             credential.renewal_count += 1;
@@ -246,45 +337,13 @@ impl VerificationSystem {
         }
     }
 
-    /// Verify credentials across compatible blockchains
-    pub fn verify_cross_chain(
-        env: &Env,
-        verifier: &Address,
-        credential_id: BytesN<32>,
-        chain_id: u32,
-        verification_hash: String,
-    ) -> bool {
-        verifier.require_auth();
-        
-        if !Self::is_reviewer(env, verifier) {
-            panic!("not authorized verifier");
-        }
-
-        if !Utils::validate_cross_chain_data(chain_id, &verification_hash) {
-            panic!("invalid cross-chain verification data");
-        }
-
+    /// Persist an updated credential in both the credential map and its own entry
+    pub fn store_credential(env: &Env, credential: &Credential) {
         let mut credentials: Map<BytesN<32>, Credential> = env.storage().persistent()
             .get(&CREDENTIALS).unwrap_or_else(|| Map::new(env));
-        
-        if let Some(mut credential) = credentials.get(credential_id.clone()) {
-            credential.cross_chain_verified = true;
-            
-            // Store updated credential
-            credentials.set(credential_id.clone(), credential.clone());
-            env.storage().persistent().set(&CREDENTIALS, &credentials);
-            env.storage().persistent().set(&credential_id, &credential);
-            
-            // Store cross-chain verification record
-            let mut cross_chain_registry: Map<BytesN<32>, (u32, String)> = env.storage().persistent()
-                .get(&CROSS_CHAIN_REGISTRY).unwrap_or_else(|| Map::new(env));
-            cross_chain_registry.set(credential_id, (chain_id, verification_hash));
-            env.storage().persistent().set(&CROSS_CHAIN_REGISTRY, &cross_chain_registry);
-            
-            true
-        } else {
-            false
-        }
+        credentials.set(credential.id.clone(), credential.clone());
+        env.storage().persistent().set(&CREDENTIALS, &credentials);
+        env.storage().persistent().set(&credential.id, credential);
     }
 
     /// Get credential information including tier and expiration
@@ -292,6 +351,27 @@ impl VerificationSystem {
         env.storage().persistent().get(&credential_id)
     }
 
+    /// Get the institution proof a credential was issued with, if any
+    pub fn get_credential_proof(env: &Env, credential_id: BytesN<32>) -> Option<CredentialProof> {
+        env.storage().persistent().get(&DataKey::CredentialProof(credential_id))
+    }
+
+    /// Re-check a credential's institution signature against the institution's registered key.
+    /// Returns false if the credential does not exist or carries no proof; panics on an invalid signature.
+    pub fn verify_credential_proof(env: &Env, credential_id: BytesN<32>) -> bool {
+        let credential = match Self::get_credential_info(env, credential_id.clone()) {
+            Some(credential) => credential,
+            None => return false,
+        };
+        match Self::get_credential_proof(env, credential_id) {
+            Some(proof) => {
+                Self::verify_proof(env, &proof, credential.tier, &credential.subject);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Check and mark expired credentials
     pub fn check_expired_credentials(env: &Env) {
         let credentials: Map<BytesN<32>, Credential> = env.storage().persistent()
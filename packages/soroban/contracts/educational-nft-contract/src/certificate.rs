@@ -0,0 +1,134 @@
+use crate::error::ContractError;
+use crate::events::*;
+use crate::storage;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Metadata of a course-completion certificate, supplied by the minting LMS contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseCertificate {
+    pub course_id: u64,
+    pub learner: Address,       // Learner who completed the course, kept across transfers
+    pub progress_token_id: u64, // Learning progress entry in the issuing LMS contract
+    pub platform: Address,      // Platform that issued the certificate
+    pub grade: u32,             // Final grade (0-100)
+    pub completed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CertificateKey {
+    Certificate(u64),                    // token_id -> CourseCertificate
+    Minter(u64),                         // token_id -> minting contract
+    Owner(u64),                          // token_id -> owner
+    OwnerTokens(Address),                // owner -> Vec<token_id>
+    OwnerCourseTokens(Address, u64),     // (owner, course_id) -> Vec<token_id>
+}
+
+pub fn add_minter(env: &Env, admin: &Address, minter: &Address) -> Result<(), ContractError> {
+    if !storage::is_admin(env, admin) {
+        return Err(ContractError::AdminOnly);
+    }
+    storage::add_certificate_minter(env, minter);
+    Ok(())
+}
+
+pub fn remove_minter(env: &Env, admin: &Address, minter: &Address) -> Result<(), ContractError> {
+    if !storage::is_admin(env, admin) {
+        return Err(ContractError::AdminOnly);
+    }
+    storage::remove_certificate_minter(env, minter);
+    Ok(())
+}
+
+/// Mint a certificate to `to`, its learner. Only minters approved by the admin (LMS contracts)
+/// can mint, and the minter is recorded so that readers can decide which issuers they trust.
+pub fn mint(
+    env: &Env,
+    minter: &Address,
+    to: &Address,
+    metadata: CourseCertificate,
+) -> Result<u64, ContractError> {
+    if !storage::is_certificate_minter(env, minter) {
+        return Err(ContractError::NotAuthorizedMinter);
+    }
+    if metadata.grade > 100 || metadata.learner != *to {
+        return Err(ContractError::InvalidInput);
+    }
+
+    let token_id = storage::get_next_certificate_id(env);
+    env.storage().persistent().set(&CertificateKey::Certificate(token_id), &metadata);
+    env.storage().persistent().set(&CertificateKey::Minter(token_id), minter);
+    set_owner(env, token_id, metadata.course_id, to);
+
+    emit_certificate_minted(env, token_id, to.clone(), minter.clone(), metadata.course_id);
+
+    Ok(token_id)
+}
+
+pub fn transfer(env: &Env, from: &Address, to: &Address, token_id: u64) -> Result<(), ContractError> {
+    let owner = owner_of(env, token_id)?;
+    if owner != *from {
+        return Err(ContractError::Unauthorized);
+    }
+    let course_id = get_certificate(env, token_id)?.course_id;
+
+    remove_token(env, &CertificateKey::OwnerTokens(from.clone()), token_id);
+    remove_token(env, &CertificateKey::OwnerCourseTokens(from.clone(), course_id), token_id);
+    set_owner(env, token_id, course_id, to);
+
+    Ok(())
+}
+
+pub fn get_certificate(env: &Env, token_id: u64) -> Result<CourseCertificate, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&CertificateKey::Certificate(token_id))
+        .ok_or(ContractError::CertificateNotFound)
+}
+
+pub fn minter_of(env: &Env, token_id: u64) -> Result<Address, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&CertificateKey::Minter(token_id))
+        .ok_or(ContractError::CertificateNotFound)
+}
+
+pub fn owner_of(env: &Env, token_id: u64) -> Result<Address, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&CertificateKey::Owner(token_id))
+        .ok_or(ContractError::CertificateNotFound)
+}
+
+pub fn tokens_of(env: &Env, owner: &Address) -> Vec<u64> {
+    get_tokens(env, &CertificateKey::OwnerTokens(owner.clone()))
+}
+
+pub fn course_tokens_of(env: &Env, owner: &Address, course_id: u64) -> Vec<u64> {
+    get_tokens(env, &CertificateKey::OwnerCourseTokens(owner.clone(), course_id))
+}
+
+fn set_owner(env: &Env, token_id: u64, course_id: u64, owner: &Address) {
+    add_token(env, &CertificateKey::OwnerTokens(owner.clone()), token_id);
+    add_token(env, &CertificateKey::OwnerCourseTokens(owner.clone(), course_id), token_id);
+    env.storage().persistent().set(&CertificateKey::Owner(token_id), owner);
+}
+
+fn get_tokens(env: &Env, key: &CertificateKey) -> Vec<u64> {
+    env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+}
+
+fn add_token(env: &Env, key: &CertificateKey, token_id: u64) {
+    let mut tokens = get_tokens(env, key);
+    tokens.push_back(token_id);
+    env.storage().persistent().set(key, &tokens);
+}
+
+fn remove_token(env: &Env, key: &CertificateKey, token_id: u64) {
+    let mut tokens = get_tokens(env, key);
+    if let Some(index) = tokens.first_index_of(token_id) {
+        tokens.remove(index);
+        env.storage().persistent().set(key, &tokens);
+    }
+}
@@ -29,4 +29,8 @@ pub enum ContractError {
     
     // General errors
     OperationNotAllowed = 16,
+
+    // Course certificate errors
+    NotAuthorizedMinter = 17,
+    CertificateNotFound = 18,
 }
\ No newline at end of file
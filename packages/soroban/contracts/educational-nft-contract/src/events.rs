@@ -30,6 +30,15 @@ pub struct CertificationIssuedEvent {
     pub average_score: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateMintedEvent {
+    pub token_id: u64,
+    pub owner: Address,
+    pub minter: Address,
+    pub course_id: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EducatorAddedEvent {
@@ -99,6 +108,16 @@ pub fn emit_certification_issued(
     env.events().publish(("certification_issued",), event);
 }
 
+pub fn emit_certificate_minted(env: &Env, token_id: u64, owner: Address, minter: Address, course_id: u64) {
+    let event = CertificateMintedEvent {
+        token_id,
+        owner,
+        minter,
+        course_id,
+    };
+    env.events().publish(("certificate_minted",), event);
+}
+
 pub fn emit_educator_added(env: &Env, educator: Address, admin: Address) {
     let event = EducatorAddedEvent { educator, admin };
     env.events().publish(("educator_added",), event);
@@ -5,6 +5,7 @@ mod events;
 mod storage;
 mod achievement_storage;
 mod achievement;
+mod certificate;
 mod test;
 
 use soroban_sdk::{
@@ -14,6 +15,7 @@ use soroban_sdk::{
 pub use error::*;
 pub use events::*;
 pub use achievement_storage::*;
+pub use certificate::CourseCertificate;
 
 #[contract]
 pub struct EducationalNFTContract;
@@ -124,4 +126,71 @@ impl EducationalNFTContract {
     pub fn is_educator(env: Env, educator: Address) -> bool {
         achievement::is_educator(&env, &educator)
     }
-}
\ No newline at end of file
+
+    /// Approve a contract (e.g. a learning management contract) to mint course certificates
+    pub fn add_certificate_minter(
+        env: Env,
+        admin: Address,
+        minter: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        certificate::add_minter(&env, &admin, &minter)
+    }
+
+    /// Revoke a contract's permission to mint course certificates
+    pub fn remove_certificate_minter(
+        env: Env,
+        admin: Address,
+        minter: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        certificate::remove_minter(&env, &admin, &minter)
+    }
+
+    /// Mint a course-completion certificate; `minter` is the calling LMS contract
+    pub fn mint_course_certificate(
+        env: Env,
+        minter: Address,
+        to: Address,
+        metadata: CourseCertificate,
+    ) -> Result<u64, ContractError> {
+        minter.require_auth();
+        certificate::mint(&env, &minter, &to, metadata)
+    }
+
+    /// Transfer a course certificate
+    pub fn transfer_certificate(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+    ) -> Result<(), ContractError> {
+        from.require_auth();
+        certificate::transfer(&env, &from, &to, token_id)
+    }
+
+    /// Get a course certificate's metadata
+    pub fn get_certificate(env: Env, token_id: u64) -> Result<CourseCertificate, ContractError> {
+        certificate::get_certificate(&env, token_id)
+    }
+
+    /// Get the contract that minted a course certificate
+    pub fn minter_of(env: Env, token_id: u64) -> Result<Address, ContractError> {
+        certificate::minter_of(&env, token_id)
+    }
+
+    /// Get the current owner of a course certificate
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, ContractError> {
+        certificate::owner_of(&env, token_id)
+    }
+
+    /// Get all course certificates held by an address
+    pub fn tokens_of(env: Env, owner: Address) -> Vec<u64> {
+        certificate::tokens_of(&env, &owner)
+    }
+
+    /// Get the certificates an address holds for one course
+    pub fn course_tokens_of(env: Env, owner: Address, course_id: u64) -> Vec<u64> {
+        certificate::course_tokens_of(&env, &owner, course_id)
+    }
+}
@@ -2,6 +2,8 @@ use soroban_sdk::{Address, Env, Vec};
 
 const ADMIN_KEY: &str = "admin";
 const EDUCATORS_KEY: &str = "educators";
+const CERTIFICATE_MINTERS_KEY: &str = "cert_minters";
+const CERTIFICATE_COUNTER_KEY: &str = "cert_counter";
 
 pub fn has_admin(env: &Env) -> bool {
     env.storage().instance().has(&ADMIN_KEY)
@@ -51,4 +53,41 @@ pub fn remove_educator(env: &Env, educator: &Address) {
 pub fn is_educator(env: &Env, educator: &Address) -> bool {
     let educators = get_educators(env);
     educators.contains(educator)
-}
\ No newline at end of file
+}
+
+// Course certificate minters (LMS contracts)
+pub fn get_certificate_minters(env: &Env) -> Vec<Address> {
+    env.storage().instance().get(&CERTIFICATE_MINTERS_KEY).unwrap_or(Vec::new(env))
+}
+
+pub fn add_certificate_minter(env: &Env, minter: &Address) {
+    let mut minters = get_certificate_minters(env);
+    if !minters.contains(minter) {
+        minters.push_back(minter.clone());
+        env.storage().instance().set(&CERTIFICATE_MINTERS_KEY, &minters);
+    }
+}
+
+pub fn remove_certificate_minter(env: &Env, minter: &Address) {
+    let minters = get_certificate_minters(env);
+    let mut new_minters = Vec::new(env);
+
+    for addr in minters.iter() {
+        if addr != *minter {
+            new_minters.push_back(addr);
+        }
+    }
+
+    env.storage().instance().set(&CERTIFICATE_MINTERS_KEY, &new_minters);
+}
+
+pub fn is_certificate_minter(env: &Env, minter: &Address) -> bool {
+    get_certificate_minters(env).contains(minter)
+}
+
+// Auto-incrementing course certificate IDs
+pub fn get_next_certificate_id(env: &Env) -> u64 {
+    let next = env.storage().instance().get(&CERTIFICATE_COUNTER_KEY).unwrap_or(0u64) + 1;
+    env.storage().instance().set(&CERTIFICATE_COUNTER_KEY, &next);
+    next
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::{EducationalNFTContract, EducationalNFTContractClient};
+use crate::{CourseCertificate, EducationalNFTContract, EducationalNFTContractClient};
 use crate::error::ContractError;
 use soroban_sdk::{
     vec,
@@ -456,4 +456,76 @@ fn test_empty_quiz_results_certification() {
     let achievement = test.contract.get_achievement(&token_id);
     assert!(achievement.certified);
     assert_eq!(achievement.quiz_results.len(), 0);
-}
\ No newline at end of file
+}
+// Course certificate tests
+
+fn certificate_metadata(env: &Env, learner: &Address, course_id: u64, grade: u32) -> CourseCertificate {
+    CourseCertificate {
+        course_id,
+        learner: learner.clone(),
+        progress_token_id: 1,
+        platform: Address::generate(env),
+        grade,
+        completed_at: 0,
+    }
+}
+
+#[test]
+fn test_mint_course_certificate() {
+    let test = NFTTest::setup();
+    let lms = Address::generate(&test.env);
+    test.contract.add_certificate_minter(&test.admin, &lms);
+
+    let metadata = certificate_metadata(&test.env, &test.user1, 7, 88);
+    let token_id = test.contract.mint_course_certificate(&lms, &test.user1, &metadata);
+
+    assert_eq!(test.contract.get_certificate(&token_id), metadata);
+    assert_eq!(test.contract.minter_of(&token_id), lms);
+    assert_eq!(test.contract.owner_of(&token_id), test.user1);
+    assert_eq!(test.contract.tokens_of(&test.user1), vec![&test.env, token_id]);
+    assert_eq!(test.contract.course_tokens_of(&test.user1, &7), vec![&test.env, token_id]);
+    assert_eq!(test.contract.course_tokens_of(&test.user1, &8).len(), 0);
+}
+
+#[test]
+fn test_mint_course_certificate_requires_approved_minter() {
+    let test = NFTTest::setup();
+    let lms = Address::generate(&test.env);
+
+    let metadata = certificate_metadata(&test.env, &test.user1, 7, 88);
+    let result = test.contract.try_mint_course_certificate(&lms, &test.user1, &metadata);
+    assert_eq!(result.unwrap_err(), Ok(ContractError::NotAuthorizedMinter));
+
+    test.contract.add_certificate_minter(&test.admin, &lms);
+    test.contract.remove_certificate_minter(&test.admin, &lms);
+    let result = test.contract.try_mint_course_certificate(&lms, &test.user1, &metadata);
+    assert_eq!(result.unwrap_err(), Ok(ContractError::NotAuthorizedMinter));
+}
+
+#[test]
+fn test_transfer_course_certificate() {
+    let test = NFTTest::setup();
+    let lms = Address::generate(&test.env);
+    test.contract.add_certificate_minter(&test.admin, &lms);
+
+    let token_id = test.contract.mint_course_certificate(&lms, &test.user1, &certificate_metadata(&test.env, &test.user1, 7, 88));
+    test.contract.transfer_certificate(&test.user1, &test.user2, &token_id);
+
+    assert_eq!(test.contract.owner_of(&token_id), test.user2);
+    assert_eq!(test.contract.course_tokens_of(&test.user1, &7).len(), 0);
+    assert_eq!(test.contract.course_tokens_of(&test.user2, &7), vec![&test.env, token_id]);
+
+    let result = test.contract.try_transfer_certificate(&test.user1, &test.user2, &token_id);
+    assert_eq!(result.unwrap_err(), Ok(ContractError::Unauthorized));
+}
+
+#[test]
+fn test_course_certificate_minted_to_its_learner() {
+    let test = NFTTest::setup();
+    let lms = Address::generate(&test.env);
+    test.contract.add_certificate_minter(&test.admin, &lms);
+
+    let metadata = certificate_metadata(&test.env, &test.user1, 7, 88);
+    let result = test.contract.try_mint_course_certificate(&lms, &test.user2, &metadata);
+    assert_eq!(result.unwrap_err(), Ok(ContractError::InvalidInput));
+}
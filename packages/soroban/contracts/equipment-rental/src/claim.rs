@@ -0,0 +1,203 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+use crate::rental::{get_rental_by_rental_id, complete_rental, claim_deadline};
+use crate::payment::{lock_deposit, split_deposit};
+use crate::equipment::require_equipment_owner;
+use crate::utils::require_admin;
+
+pub const CLAIM_KEY: Symbol = symbol_short!("claim");
+pub const ARBITER_KEY: Symbol = symbol_short!("arbiter");
+pub const RESPONSE_WINDOW: Symbol = symbol_short!("resp_win");
+pub const DEFAULT_RESPONSE_WINDOW: u64 = 3 * 24 * 3600; // 3 days
+
+pub const CLAIM_FILED: Symbol = symbol_short!("clm_filed");
+pub const CLAIM_ACCEPTED: Symbol = symbol_short!("clm_acc");
+pub const CLAIM_DISPUTED: Symbol = symbol_short!("clm_disp");
+pub const CLAIM_RESOLVED: Symbol = symbol_short!("clm_res");
+pub const CLAIM_UNANSWERED: Symbol = symbol_short!("clm_unans");
+pub const ARBITER_ADDED: Symbol = symbol_short!("arb_add");
+pub const ARBITER_REMOVED: Symbol = symbol_short!("arb_rem");
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClaimStatus {
+    Open,        // Filed, waiting for the renter to respond
+    Accepted,    // Renter agreed, the owner got the claimed amount
+    Unanswered,  // Response window lapsed, the owner got the claimed amount
+    Disputed,    // Renter contested, waiting for an arbiter
+    Resolved,    // An arbiter split the deposit
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Claim {
+    pub rental_id: u64,
+    pub owner: Address,
+    pub renter: Address,
+    pub amount: i128,                       // Part of the deposit claimed by the owner
+    pub evidence_hash: BytesN<32>,          // Hash of the owner's off-chain evidence
+    pub response_hash: Option<BytesN<32>>,  // Hash of the renter's counter-evidence
+    pub filed_at: u64,
+    pub response_deadline: u64,
+    pub status: ClaimStatus,
+    pub arbiter: Option<Address>,
+    pub owner_award: i128,                  // Deposit paid to the owner once settled
+}
+
+/// The owner claims `amount` of the escrowed deposit for damaged or unreturned equipment.
+/// The rent is paid out and the deposit stays locked until the claim is settled.
+pub fn file_claim(env: &Env, owner: Address, rental_id: u64, amount: i128, evidence_hash: BytesN<32>) {
+    let rental = get_rental_by_rental_id(env, rental_id).expect("Rental not found");
+    require_equipment_owner(env, &owner, rental.equipment_id);
+
+    // The equipment was not handed over before the start of the reservation
+    if env.ledger().timestamp() < rental.start_time {
+        panic!("Rental has not started");
+    }
+    if env.ledger().timestamp() >= claim_deadline(env, &rental) {
+        panic!("Claim window closed");
+    }
+
+    let deposit = lock_deposit(env, rental_id);
+    if amount <= 0 || amount > deposit.amount {
+        panic!("Invalid claim amount");
+    }
+    complete_rental(env, &rental);
+
+    let filed_at = env.ledger().timestamp();
+    let claim = Claim {
+        rental_id,
+        owner: owner.clone(),
+        renter: rental.renter,
+        amount,
+        evidence_hash: evidence_hash.clone(),
+        response_hash: None,
+        filed_at,
+        response_deadline: filed_at + get_response_window(env),
+        status: ClaimStatus::Open,
+        arbiter: None,
+        owner_award: 0,
+    };
+    save_claim(env, &claim);
+
+    env.events().publish((CLAIM_FILED, rental_id), (owner, amount, evidence_hash));
+}
+
+/// The renter accepts the claim, the claimed amount goes to the owner
+pub fn accept_claim(env: &Env, renter: Address, rental_id: u64) {
+    let mut claim = require_open_claim(env, &renter, rental_id);
+
+    let amount = claim.amount;
+    claim.status = ClaimStatus::Accepted;
+    settle_claim(env, &mut claim, amount);
+
+    env.events().publish((CLAIM_ACCEPTED, rental_id), (renter, amount));
+}
+
+/// The renter contests the claim with counter-evidence and it goes to arbitration
+pub fn dispute_claim(env: &Env, renter: Address, rental_id: u64, response_hash: BytesN<32>) {
+    let mut claim = require_open_claim(env, &renter, rental_id);
+
+    claim.status = ClaimStatus::Disputed;
+    claim.response_hash = Some(response_hash.clone());
+    save_claim(env, &claim);
+
+    env.events().publish((CLAIM_DISPUTED, rental_id), (renter, response_hash));
+}
+
+/// Settle a claim the renter did not answer in time in the owner's favour. Anyone can trigger it.
+pub fn finalize_unanswered_claim(env: &Env, rental_id: u64) {
+    let mut claim = get_claim(env, rental_id).expect("Claim not found");
+    if claim.status != ClaimStatus::Open {
+        panic!("Claim is not open");
+    }
+    if env.ledger().timestamp() < claim.response_deadline {
+        panic!("Response window still open");
+    }
+
+    let amount = claim.amount;
+    claim.status = ClaimStatus::Unanswered;
+    settle_claim(env, &mut claim, amount);
+
+    env.events().publish((CLAIM_UNANSWERED, rental_id), amount);
+}
+
+/// An arbiter settles a disputed claim, awarding `owner_award` of the deposit, at most
+/// the claimed amount, to the owner and the rest to the payer
+pub fn resolve_claim(env: &Env, arbiter: Address, rental_id: u64, owner_award: i128) {
+    arbiter.require_auth();
+    if !is_arbiter(env, &arbiter) {
+        panic!("Not an arbiter");
+    }
+
+    let mut claim = get_claim(env, rental_id).expect("Claim not found");
+    if claim.status != ClaimStatus::Disputed {
+        panic!("Claim is not disputed");
+    }
+    if arbiter == claim.owner || arbiter == claim.renter {
+        panic!("Arbiter is a party to the claim");
+    }
+    if owner_award < 0 || owner_award > claim.amount {
+        panic!("Invalid owner award");
+    }
+
+    claim.status = ClaimStatus::Resolved;
+    claim.arbiter = Some(arbiter.clone());
+    settle_claim(env, &mut claim, owner_award);
+
+    env.events().publish((CLAIM_RESOLVED, rental_id), (arbiter, owner_award));
+}
+
+pub fn get_claim(env: &Env, rental_id: u64) -> Option<Claim> {
+    env.storage().persistent().get(&(CLAIM_KEY, rental_id))
+}
+
+pub fn add_arbiter(env: &Env, admin: Address, arbiter: Address) {
+    require_admin(env, &admin);
+    env.storage().persistent().set(&(ARBITER_KEY, arbiter.clone()), &true);
+    env.events().publish((ARBITER_ADDED,), arbiter);
+}
+
+pub fn remove_arbiter(env: &Env, admin: Address, arbiter: Address) {
+    require_admin(env, &admin);
+    env.storage().persistent().remove(&(ARBITER_KEY, arbiter.clone()));
+    env.events().publish((ARBITER_REMOVED,), arbiter);
+}
+
+pub fn is_arbiter(env: &Env, arbiter: &Address) -> bool {
+    env.storage().persistent().get(&(ARBITER_KEY, arbiter.clone())).unwrap_or(false)
+}
+
+pub fn set_response_window(env: &Env, admin: Address, response_window: u64) {
+    require_admin(env, &admin);
+    env.storage().persistent().set(&RESPONSE_WINDOW, &response_window);
+}
+
+pub fn get_response_window(env: &Env) -> u64 {
+    env.storage().persistent().get(&RESPONSE_WINDOW).unwrap_or(DEFAULT_RESPONSE_WINDOW)
+}
+
+fn require_open_claim(env: &Env, renter: &Address, rental_id: u64) -> Claim {
+    renter.require_auth();
+
+    let claim = get_claim(env, rental_id).expect("Claim not found");
+    if claim.renter != *renter {
+        panic!("Only the renter can respond to the claim");
+    }
+    if claim.status != ClaimStatus::Open {
+        panic!("Claim is not open");
+    }
+    if env.ledger().timestamp() >= claim.response_deadline {
+        panic!("Response window closed");
+    }
+    claim
+}
+
+fn settle_claim(env: &Env, claim: &mut Claim, owner_award: i128) {
+    split_deposit(env, claim.rental_id, &claim.owner, owner_award);
+    claim.owner_award = owner_award;
+    save_claim(env, claim);
+}
+
+fn save_claim(env: &Env, claim: &Claim) {
+    env.storage().persistent().set(&(CLAIM_KEY, claim.rental_id), claim);
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+pub const EQUIPMENT_KEY: Symbol = symbol_short!("equipment");
+pub const EQUIPMENT_COUNT: Symbol = symbol_short!("eq_count");
+pub const EQUIPMENT_REGISTERED: Symbol = symbol_short!("eq_reg");
+pub const EQUIPMENT_UPDATED: Symbol = symbol_short!("eq_update");
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum EquipmentCondition {
+    New,
+    Good,
+    Fair,
+    Worn,
+    Damaged,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Equipment {
+    pub equipment_id: u64,
+    pub owner: Address,              // Receives the rent payouts
+    pub description: String,
+    pub price_per_hour: i128,        // Rent per started hour in Stroops
+    pub security_deposit: i128,      // Escrowed with every rental payment
+    pub condition: EquipmentCondition,
+    pub registered_at: u64,
+}
+
+pub fn register_equipment(
+    env: &Env,
+    owner: Address,
+    description: String,
+    price_per_hour: i128,
+    security_deposit: i128,
+    condition: EquipmentCondition,
+) -> u64 {
+    owner.require_auth();
+    validate_price(price_per_hour);
+    validate_deposit(security_deposit);
+
+    let equipment_id: u64 = env.storage().persistent().get(&EQUIPMENT_COUNT).unwrap_or(0) + 1;
+    env.storage().persistent().set(&EQUIPMENT_COUNT, &equipment_id);
+
+    let equipment = Equipment {
+        equipment_id,
+        owner: owner.clone(),
+        description,
+        price_per_hour,
+        security_deposit,
+        condition,
+        registered_at: env.ledger().timestamp(),
+    };
+    save_equipment(env, &equipment);
+
+    env.events().publish((EQUIPMENT_REGISTERED, equipment_id), (owner, price_per_hour, security_deposit));
+    equipment_id
+}
+
+pub fn set_equipment_price(env: &Env, owner: Address, equipment_id: u64, price_per_hour: i128) {
+    validate_price(price_per_hour);
+    let mut equipment = require_equipment_owner(env, &owner, equipment_id);
+
+    equipment.price_per_hour = price_per_hour;
+    save_equipment(env, &equipment);
+    env.events().publish((EQUIPMENT_UPDATED, equipment_id), symbol_short!("price"));
+}
+
+pub fn set_security_deposit(env: &Env, owner: Address, equipment_id: u64, security_deposit: i128) {
+    validate_deposit(security_deposit);
+    let mut equipment = require_equipment_owner(env, &owner, equipment_id);
+
+    equipment.security_deposit = security_deposit;
+    save_equipment(env, &equipment);
+    env.events().publish((EQUIPMENT_UPDATED, equipment_id), symbol_short!("deposit"));
+}
+
+pub fn set_equipment_condition(env: &Env, owner: Address, equipment_id: u64, condition: EquipmentCondition) {
+    let mut equipment = require_equipment_owner(env, &owner, equipment_id);
+
+    equipment.condition = condition;
+    save_equipment(env, &equipment);
+    env.events().publish((EQUIPMENT_UPDATED, equipment_id), symbol_short!("condition"));
+}
+
+pub fn get_equipment(env: &Env, equipment_id: u64) -> Option<Equipment> {
+    env.storage().persistent().get(&(EQUIPMENT_KEY, equipment_id))
+}
+
+pub fn get_equipment_price(env: &Env, equipment_id: u64) -> i128 {
+    get_equipment(env, equipment_id).expect("Equipment not registered").price_per_hour
+}
+
+/// Load the equipment after checking that `owner` registered it
+pub fn require_equipment_owner(env: &Env, owner: &Address, equipment_id: u64) -> Equipment {
+    owner.require_auth();
+
+    let equipment = get_equipment(env, equipment_id).expect("Equipment not registered");
+    if equipment.owner != *owner {
+        panic!("Only the equipment owner can do this");
+    }
+    equipment
+}
+
+fn save_equipment(env: &Env, equipment: &Equipment) {
+    env.storage().persistent().set(&(EQUIPMENT_KEY, equipment.equipment_id), equipment);
+}
+
+fn validate_price(price_per_hour: i128) {
+    if price_per_hour < 0 {
+        panic!("Price Per Hour Can't be negative");
+    }
+}
+
+fn validate_deposit(security_deposit: i128) {
+    if security_deposit < 0 {
+        panic!("Security deposit Can't be negative");
+    }
+}
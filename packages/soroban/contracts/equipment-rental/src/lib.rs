@@ -1,15 +1,20 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 mod rental;
 mod payment;
+mod equipment;
+mod claim;
 mod utils;
 
 #[cfg(test)]
 mod test;
 
 use rental::{Rental, MAX_DURATION};
-use payment::{Payment};
+use payment::{Payment, Deposit};
+use equipment::{Equipment, EquipmentCondition};
+use claim::Claim;
+use utils::ADMIN;
 
 #[contract]
 pub struct EquipmentRentalContract;
@@ -17,21 +22,74 @@ pub struct EquipmentRentalContract;
 #[contractimpl]
 impl EquipmentRentalContract {
 
-    pub fn initialize(env: Env, max_duration: u64) {
+    pub fn initialize(env: Env, admin: Address, max_duration: u64) {
+        if env.storage().persistent().has(&ADMIN) {
+            panic!("Already initialized");
+        }
+        admin.require_auth();
+
+        env.storage().persistent().set(&ADMIN, &admin);
         env.storage().persistent().set(&MAX_DURATION, &max_duration);
     }
 
-    pub fn create_rental(env: &Env, renter: Address, equipment_id: u64, duration: u64) -> u64 {
-        rental::create_rental(&env, renter, equipment_id, duration)
+    pub fn register_equipment(
+        env: Env,
+        owner: Address,
+        description: String,
+        price_per_hour: i128,
+        security_deposit: i128,
+        condition: EquipmentCondition,
+    ) -> u64 {
+        equipment::register_equipment(&env, owner, description, price_per_hour, security_deposit, condition)
+    }
+
+    pub fn set_security_deposit(env: Env, owner: Address, equipment_id: u64, security_deposit: i128) {
+        equipment::set_security_deposit(&env, owner, equipment_id, security_deposit)
+    }
+
+    pub fn set_equipment_condition(env: Env, owner: Address, equipment_id: u64, condition: EquipmentCondition) {
+        equipment::set_equipment_condition(&env, owner, equipment_id, condition)
+    }
+
+    pub fn get_equipment(env: Env, equipment_id: u64) -> Option<Equipment> {
+        equipment::get_equipment(&env, equipment_id)
+    }
+
+    pub fn create_rental(env: &Env, renter: Address, equipment_id: u64, start_time: u64, end_time: u64) -> u64 {
+        rental::create_rental(&env, renter, equipment_id, start_time, end_time)
     }
 
     pub fn cancel_rental(env: &Env, renter: Address, rental_id: u64) {
         rental::cancel_rental(env, renter, rental_id)
     }
 
+    pub fn confirm_return(env: &Env, owner: Address, rental_id: u64) {
+        rental::confirm_return(env, owner, rental_id)
+    }
+
+    pub fn release_deposit(env: &Env, rental_id: u64) {
+        rental::release_deposit(env, rental_id)
+    }
+
+    pub fn set_claim_window(env: &Env, admin: Address, claim_window: u64) {
+        rental::set_claim_window(env, admin, claim_window)
+    }
+
+    pub fn get_claim_window(env: &Env) -> u64 {
+        rental::get_claim_window(env)
+    }
+
+
+    pub fn check_availability(env: &Env, equipment_id: u64, start_time: u64, end_time: u64) -> bool {
+        rental::check_availability(&env, equipment_id, start_time, end_time)
+    }
+
+    pub fn get_equipment_calendar(env: &Env, equipment_id: u64, from: u64, to: u64) -> Vec<Rental> {
+        rental::get_equipment_calendar(env, equipment_id, from, to)
+    }
 
-    pub fn check_availability(env: &Env, equipment_id: u64) -> bool {
-        rental::check_availability(&env, equipment_id)
+    pub fn get_next_available_slot(env: &Env, equipment_id: u64, from: u64, duration: u64) -> u64 {
+        rental::get_next_available_slot(env, equipment_id, from, duration)
     }
 
     pub fn get_rentals_by_equipment_id(env: &Env, equipment_id: u64) -> Vec<Rental> {
@@ -46,23 +104,78 @@ impl EquipmentRentalContract {
         payment::process_payment(env, rental_id, payer, amount)
     }
 
-    pub fn set_token_address(env: &Env, token: Address) {
-        payment::set_token_address(&env, token)
+    pub fn file_claim(env: &Env, owner: Address, rental_id: u64, amount: i128, evidence_hash: BytesN<32>) {
+        claim::file_claim(env, owner, rental_id, amount, evidence_hash)
+    }
+
+    pub fn accept_claim(env: &Env, renter: Address, rental_id: u64) {
+        claim::accept_claim(env, renter, rental_id)
+    }
+
+    pub fn dispute_claim(env: &Env, renter: Address, rental_id: u64, response_hash: BytesN<32>) {
+        claim::dispute_claim(env, renter, rental_id, response_hash)
+    }
+
+    pub fn finalize_unanswered_claim(env: &Env, rental_id: u64) {
+        claim::finalize_unanswered_claim(env, rental_id)
+    }
+
+    pub fn resolve_claim(env: &Env, arbiter: Address, rental_id: u64, owner_award: i128) {
+        claim::resolve_claim(env, arbiter, rental_id, owner_award)
+    }
+
+    pub fn get_claim(env: &Env, rental_id: u64) -> Option<Claim> {
+        claim::get_claim(env, rental_id)
+    }
+
+    pub fn add_arbiter(env: &Env, admin: Address, arbiter: Address) {
+        claim::add_arbiter(env, admin, arbiter)
+    }
+
+    pub fn remove_arbiter(env: &Env, admin: Address, arbiter: Address) {
+        claim::remove_arbiter(env, admin, arbiter)
+    }
+
+    pub fn is_arbiter(env: &Env, arbiter: Address) -> bool {
+        claim::is_arbiter(env, &arbiter)
+    }
+
+    pub fn set_response_window(env: &Env, admin: Address, response_window: u64) {
+        claim::set_response_window(env, admin, response_window)
+    }
+
+    pub fn get_response_window(env: &Env) -> u64 {
+        claim::get_response_window(env)
+    }
+
+    pub fn set_token_address(env: &Env, admin: Address, token: Address) {
+        payment::set_token_address(env, admin, token)
+    }
+
+    pub fn set_platform_fee(env: &Env, admin: Address, fee_bps: u32) {
+        payment::set_platform_fee(env, admin, fee_bps)
     }
 
-    pub fn set_equipment_price(env: &Env, equipment_id: u64, price_per_hour: i128) {
-        payment::set_equipment_price(env, equipment_id, price_per_hour)
+    pub fn get_platform_fee(env: &Env) -> u32 {
+        payment::get_platform_fee(env)
+    }
 
+    pub fn set_equipment_price(env: &Env, owner: Address, equipment_id: u64, price_per_hour: i128) {
+        equipment::set_equipment_price(env, owner, equipment_id, price_per_hour)
     }
 
     pub fn get_equipment_price(env: &Env, equipment_id: u64) -> i128 {
-        payment::get_equipment_price(env, equipment_id)
+        equipment::get_equipment_price(env, equipment_id)
     }
 
     pub fn get_payment_by_rental_id(env: &Env, rental_id: u64) -> Option<Payment>{
         payment::get_payment_by_rental_id(env, rental_id)
     }
 
+    pub fn get_deposit(env: &Env, rental_id: u64) -> Option<Deposit> {
+        payment::get_deposit(env, rental_id)
+    }
+
     pub fn refund_payment(env: Env, rental_id: u64, amount: i128) -> bool {
         payment::refund_payment(env, rental_id, amount)
     }
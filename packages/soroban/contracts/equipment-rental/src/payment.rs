@@ -1,9 +1,17 @@
 use soroban_sdk::{contracttype, token, Address, Env, Symbol, symbol_short, Vec};
-use crate::rental::{Rental, update_rental_status, get_rental_by_rental_id, check_availability, RENTAL_FAILED, RentalStatus};
+use crate::rental::{Rental, update_rental_status, get_rental_by_rental_id, check_availability, is_expired, rental_cost, RENTAL_FAILED, RentalStatus};
+use crate::equipment::get_equipment;
+use crate::utils::{get_admin, require_admin};
 
 pub const PAYMENT_KEY: Symbol = symbol_short!("payments");
 pub const PAYMENT_SUCCESS: Symbol = symbol_short!("p_success");
 pub const CALCULATED_RENT_AMOUNT_PER_RENTAL_ID: Symbol = symbol_short!("c_amount"); // rent_per_hour * duration for each rental id
+pub const DEPOSIT_KEY: Symbol = symbol_short!("deposit");
+pub const PLATFORM_FEE: Symbol = symbol_short!("fee_bps"); // share of the rent kept by the platform, in basis points
+pub const PAYOUT: Symbol = symbol_short!("payout");
+pub const DEPOSIT_RETURNED: Symbol = symbol_short!("dep_ret");
+pub const DEPOSIT_SPLIT: Symbol = symbol_short!("dep_split");
+pub const MAX_FEE_BPS: u32 = 10_000;
 
 
 #[derive(Clone)]
@@ -20,26 +28,53 @@ pub struct Payment {
     pub amount: i128,          // Payment amount in XLM (Stroops)
     pub payer: Address,        // Stellar address of the payer
     pub timestamp: u64,        // Payment timestamp
+    pub refunded: i128,        // Overpayment already returned with refund_payment
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DepositStatus {
+    Held,       // Escrowed by the contract until the return is settled
+    Claimed,    // Locked by a damage claim
+    Returned,   // Paid back to the payer
+    Settled,    // Split between the owner and the payer after a claim
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Deposit {
+    pub rental_id: u64,
+    pub payer: Address,        // Receives the deposit back
+    pub amount: i128,          // Security deposit in Stroops
+    pub status: DepositStatus,
 }
 
 pub const TOKEN: Symbol = symbol_short!("token");
-pub const EQUIPMENT_PRICE: Symbol = symbol_short!("eq_price");
 
-pub fn set_token_address(env: &Env, token: Address) {
+pub fn set_token_address(env: &Env, admin: Address, token: Address) {
+    require_admin(env, &admin);
     env.storage().persistent().set(&TOKEN, &token);
 }
 
-pub fn set_equipment_price(env: &Env, equipment_id: u64, price_per_hour: i128) {
-    if price_per_hour < 0 {
-        panic!("Price Per Hour Can't be negative");
+pub fn set_platform_fee(env: &Env, admin: Address, fee_bps: u32) {
+    require_admin(env, &admin);
+    if fee_bps > MAX_FEE_BPS {
+        panic!("Invalid platform fee");
     }
-    let key = (EQUIPMENT_PRICE, equipment_id);
-    env.storage().persistent().set(&key, &price_per_hour);
+    env.storage().persistent().set(&PLATFORM_FEE, &fee_bps);
 }
 
-pub fn get_equipment_price(env: &Env, equipment_id: u64) -> i128 {
-    let key = (EQUIPMENT_PRICE, equipment_id);
-    env.storage().persistent().get(&key).expect("NO PRICE FOR EQUIPMENT")
+pub fn get_platform_fee(env: &Env) -> u32 {
+    env.storage().persistent().get(&PLATFORM_FEE).unwrap_or(0)
+}
+
+/// Rent charged for a paid rental, without the security deposit
+pub fn get_rent_amount(env: &Env, rental_id: u64) -> i128 {
+    env.storage().persistent().get(&(CALCULATED_RENT_AMOUNT_PER_RENTAL_ID, rental_id)).unwrap_or(0)
+}
+
+pub fn get_deposit(env: &Env, rental_id: u64) -> Option<Deposit> {
+    env.storage().persistent().get(&(DEPOSIT_KEY, rental_id))
 }
 
 pub fn get_payments(env: &Env, ) -> Vec<Payment> {
@@ -64,17 +99,25 @@ pub fn process_payment(env: &Env, rental_id: u64, payer: Address, amount: i128)
     let token_client = token::Client::new(&env, &token);
     let timestamp = env.ledger().timestamp();
 
-    let rental_data: Rental = get_rental_by_rental_id(&env, rental_id).expect("REASON");
+    let rental_data: Rental = get_rental_by_rental_id(&env, rental_id).expect("Rental not found");
+    if is_expired(env, &rental_data) {
+        panic!("Reservation expired");
+    }
+    // Only a reservation waiting for payment can be paid; a finished rental would
+    // get a fresh deposit over its settled one
+    if rental_data.status != RentalStatus::Pending {
+        panic!("Rental is not awaiting payment");
+    }
 
-    let rental_duration = rental_data.duration;
     let rental_equipment_id = rental_data.equipment_id;
-    let rent_per_hour = get_equipment_price(&env, rental_equipment_id);
-    let total_rental_amount = rental_duration as i128 * rent_per_hour;
+    let rent_amount = rental_cost(env, &rental_data);
+    let deposit_amount = get_equipment(env, rental_equipment_id).expect("Equipment not registered").security_deposit;
+    let total_rental_amount = rent_amount + deposit_amount;
 
     let key = (CALCULATED_RENT_AMOUNT_PER_RENTAL_ID, rental_id);
-    env.storage().persistent().set(&key, &total_rental_amount);
+    env.storage().persistent().set(&key, &rent_amount);
 
-    if check_availability(&env, rental_equipment_id) == false {
+    if !check_availability(&env, rental_equipment_id, rental_data.start_time, rental_data.end_time) {
         env.events().publish((RENTAL_FAILED, rental_equipment_id), "equipment_unavailable");
         panic!("Equipment Already Paid For");
     }
@@ -88,13 +131,22 @@ pub fn process_payment(env: &Env, rental_id: u64, payer: Address, amount: i128)
                 rental_id, 
                 amount,
                 payer: payer.clone(),
-                timestamp,  
+                timestamp,
+                refunded: 0,
             };
     
     let mut payments: Vec<Payment> = env.storage().persistent().get(&PAYMENT_KEY).unwrap_or(Vec::new(env));
     payments.push_back(payment.clone());
     env.storage().persistent().set(&PAYMENT_KEY, &payments);
 
+    let deposit = Deposit {
+        rental_id,
+        payer: payer.clone(),
+        amount: deposit_amount,
+        status: DepositStatus::Held,
+    };
+    env.storage().persistent().set(&(DEPOSIT_KEY, rental_id), &deposit);
+
     let _ = update_rental_status(&env, rental_data.rental_id, RentalStatus::Active);
 
     env.events().publish((PAYMENT_SUCCESS, rental_id), (payer, amount));
@@ -106,31 +158,125 @@ pub fn refund_payment(env: Env, rental_id: u64, amount: i128) -> bool {
         panic!("Refunded Amount Can't be negative");
     }
 
-    let payments: Option<Vec<Payment>> = env.storage().persistent().get(&PAYMENT_KEY);
-    let payment = payments
-        .and_then(|ps| ps.iter().find(|p| p.rental_id == rental_id))
-        .unwrap_or_else(|| panic!("Payment not found"));
+    let mut payments: Vec<Payment> = env.storage().persistent().get(&PAYMENT_KEY).unwrap_or(Vec::new(&env));
+    let index = payments
+        .iter()
+        .position(|p| p.rental_id == rental_id)
+        .unwrap_or_else(|| panic!("Payment not found")) as u32;
+    let mut payment = payments.get(index).unwrap();
     payment.payer.require_auth();
-    
+
     let token = env.storage().persistent().get(&TOKEN).expect("TOKEN NOT YET INITIALIZED");
     let token_client = token::Client::new(&env, &token);
 
     let key = (CALCULATED_RENT_AMOUNT_PER_RENTAL_ID, rental_id);
     let calculated_amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let deposit_amount = get_deposit(&env, rental_id).map(|deposit| deposit.amount).unwrap_or(0);
 
     if token_client.balance(&env.current_contract_address()) < amount {
         env.events().publish((symbol_short!("refund_f1"), rental_id), "insufficient_contract_balance");
         panic!("Insufficient contract balance");
     }
 
-    let max_refundable_amount: i128 = payment.amount.saturating_sub(calculated_amount);
+    // Only the overpayment can be refunded, once
+    let max_refundable_amount: i128 = payment.amount.saturating_sub(calculated_amount + deposit_amount + payment.refunded);
 
     if max_refundable_amount < amount {
         env.events().publish((symbol_short!("refund_f2"), rental_id), "calculated_amount_is_less");
         panic!("Max Refundable Amount: {}", max_refundable_amount);
     }
 
-    token_client.transfer(&env.current_contract_address(), &payment.payer, &amount);
-    env.events().publish((symbol_short!("refund_s"), rental_id), (payment.payer, amount));
+    payment.refunded += amount;
+    payments.set(index, payment.clone());
+    env.storage().persistent().set(&PAYMENT_KEY, &payments);
+
+    send_refund(&env, &payment, amount);
     true
 }
+
+/// Return `amount` of a payment to its payer
+pub fn send_refund(env: &Env, payment: &Payment, amount: i128) {
+    let token = env.storage().persistent().get(&TOKEN).expect("TOKEN NOT YET INITIALIZED");
+    let token_client = token::Client::new(env, &token);
+
+    token_client.transfer(&env.current_contract_address(), &payment.payer, &amount);
+    env.events().publish((symbol_short!("refund_s"), payment.rental_id), (payment.payer.clone(), amount));
+}
+
+/// Pay `rent` to the equipment owner, minus the platform fee which goes to the admin
+pub fn pay_owner(env: &Env, rental: &Rental, rent: i128) {
+    if rent <= 0 {
+        return;
+    }
+
+    let token = env.storage().persistent().get(&TOKEN).expect("TOKEN NOT YET INITIALIZED");
+    let token_client = token::Client::new(env, &token);
+    let owner = get_equipment(env, rental.equipment_id).expect("Equipment not registered").owner;
+
+    let fee = rent * get_platform_fee(env) as i128 / MAX_FEE_BPS as i128;
+    let owner_amount = rent - fee;
+
+    if fee > 0 {
+        token_client.transfer(&env.current_contract_address(), &get_admin(env), &fee);
+    }
+    token_client.transfer(&env.current_contract_address(), &owner, &owner_amount);
+    env.events().publish((PAYOUT, rental.rental_id), (owner, owner_amount, fee));
+}
+
+/// Load a deposit that is still escrowed and free of claims
+pub fn get_held_deposit(env: &Env, rental_id: u64) -> Deposit {
+    let deposit = get_deposit(env, rental_id).expect("Deposit not found");
+    match deposit.status {
+        DepositStatus::Held => deposit,
+        DepositStatus::Claimed => panic!("Deposit is under claim"),
+        _ => panic!("Deposit already settled"),
+    }
+}
+
+/// Lock a held deposit while a damage claim against it is open
+pub fn lock_deposit(env: &Env, rental_id: u64) -> Deposit {
+    let mut deposit = get_held_deposit(env, rental_id);
+    deposit.status = DepositStatus::Claimed;
+    env.storage().persistent().set(&(DEPOSIT_KEY, rental_id), &deposit);
+    deposit
+}
+
+/// Settle a claimed deposit: `owner_amount` goes to the owner, the rest back to the payer
+pub fn split_deposit(env: &Env, rental_id: u64, owner: &Address, owner_amount: i128) {
+    let mut deposit = get_deposit(env, rental_id).expect("Deposit not found");
+    if deposit.status != DepositStatus::Claimed {
+        panic!("Deposit is not under claim");
+    }
+    if owner_amount < 0 || owner_amount > deposit.amount {
+        panic!("Invalid deposit split");
+    }
+
+    deposit.status = DepositStatus::Settled;
+    env.storage().persistent().set(&(DEPOSIT_KEY, rental_id), &deposit);
+
+    let token = env.storage().persistent().get(&TOKEN).expect("TOKEN NOT YET INITIALIZED");
+    let token_client = token::Client::new(env, &token);
+    let payer_amount = deposit.amount - owner_amount;
+    if owner_amount > 0 {
+        token_client.transfer(&env.current_contract_address(), owner, &owner_amount);
+    }
+    if payer_amount > 0 {
+        token_client.transfer(&env.current_contract_address(), &deposit.payer, &payer_amount);
+    }
+    env.events().publish((DEPOSIT_SPLIT, rental_id), (owner_amount, payer_amount));
+}
+
+/// Send an escrowed security deposit back to its payer
+pub fn return_deposit(env: &Env, rental_id: u64) {
+    let mut deposit = get_held_deposit(env, rental_id);
+
+    deposit.status = DepositStatus::Returned;
+    env.storage().persistent().set(&(DEPOSIT_KEY, rental_id), &deposit);
+
+    if deposit.amount > 0 {
+        let token = env.storage().persistent().get(&TOKEN).expect("TOKEN NOT YET INITIALIZED");
+        let token_client = token::Client::new(env, &token);
+        token_client.transfer(&env.current_contract_address(), &deposit.payer, &deposit.amount);
+    }
+    env.events().publish((DEPOSIT_RETURNED, rental_id), (deposit.payer, deposit.amount));
+}
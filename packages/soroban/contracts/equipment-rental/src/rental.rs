@@ -1,21 +1,28 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
-use crate::utils::{validate_duration, generate_rental_id};
-use crate::payment::{get_payment_by_rental_id, get_equipment_price, refund_payment};
+use crate::utils::{validate_duration, generate_rental_id, billable_hours, require_admin};
+use crate::payment::{get_payment_by_rental_id, get_rent_amount, get_held_deposit, send_refund, pay_owner, return_deposit};
+use crate::equipment::{get_equipment, get_equipment_price, require_equipment_owner};
 
 pub const RENTAL_KEY: Symbol = symbol_short!("rentals");
+pub const EQUIPMENT_RENTALS: Symbol = symbol_short!("eq_rents");
 pub const RENTAL_KEY_CANCEL: Symbol = symbol_short!("rentalcan");
 pub const MAX_DURATION: Symbol = symbol_short!("max_dur");
 pub const RENTAL_CREATED: Symbol = symbol_short!("r_created");
 pub const RENTAL_FAILED: Symbol = symbol_short!("r_failed");
 pub const RENTAL_CANCEL: Symbol = symbol_short!("r_cancel");
+pub const RENTAL_COMPLETED: Symbol = symbol_short!("r_done");
+pub const CLAIM_WINDOW: Symbol = symbol_short!("claim_win");
+pub const DEFAULT_CLAIM_WINDOW: u64 = 3 * 24 * 3600; // 3 days
+pub const PENDING_RESERVATION_TTL: u64 = 24 * 3600; // Unpaid reservations expire after 1 day
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub enum RentalStatus {
     Pending,    // Rental created, awaiting payment
     Active,     // Payment processed, rental active
-    Completed,  // Rental duration ended
+    Completed,  // Equipment returned and rent paid out
     Cancelled,  // Rental cancelled before start
+    Expired,    // Not paid within the pending reservation TTL
 }
 
 #[contracttype]
@@ -24,8 +31,9 @@ pub struct Rental {
     pub rental_id: u64,
     pub equipment_id: u64,
     pub renter: Address,
-    pub start_time: u64,
-    pub duration: u64,
+    pub start_time: u64,       // Reservation start (timestamp)
+    pub end_time: u64,         // Reservation end (timestamp, exclusive)
+    pub created_at: u64,       // Reservation time, starts the pending reservation TTL
     pub status: RentalStatus,
 }
 
@@ -38,65 +46,143 @@ pub struct Cancellation {
     pub timestamp: u64,        // Cancellation timestamp
 }
 
-pub fn create_rental(env: &Env, renter: Address, equipment_id: u64, duration: u64) -> u64 {
+pub fn create_rental(env: &Env, renter: Address, equipment_id: u64, start_time: u64, end_time: u64) -> u64 {
     renter.require_auth();
 
-    if duration == 0 {
-        panic!("Duration Can't be negative");
+    if end_time <= start_time {
+        panic!("End time must be after start time");
+    }
+
+    if start_time < env.ledger().timestamp() {
+        panic!("Start time is in the past");
     }
 
-    validate_duration(&env, duration);
+    validate_duration(&env, end_time - start_time);
+
+    if get_equipment(env, equipment_id).is_none() {
+        panic!("Equipment not registered");
+    }
 
-    if check_availability(&env, equipment_id) == false {
+    if !check_availability(&env, equipment_id, start_time, end_time) {
         env.events().publish((RENTAL_FAILED, equipment_id), "equipment_unavailable");
         panic!("Equipment not available");
     }
 
+    prune_expired_reservations(env, equipment_id);
     let rental_id = generate_rental_id(&env);
-    
+
     let rental = Rental {
         rental_id,
         equipment_id,
         renter: renter.clone(),
-        start_time: env.ledger().timestamp(),
-        duration,
+        start_time,
+        end_time,
+        created_at: env.ledger().timestamp(),
         status: RentalStatus::Pending,
     };
 
     save_rental(&env, &rental);
-    env.events().publish((RENTAL_CREATED, rental_id), (equipment_id, renter, duration));
+    env.events().publish((RENTAL_CREATED, rental_id), (equipment_id, renter, start_time, end_time));
     rental_id
 }
 
 pub fn cancel_rental(env: &Env, renter: Address, rental_id: u64) {
     let current_time = env.ledger().timestamp();
-    let mut rental = validate_cancellation(&env, rental_id, renter).unwrap();
+    let rental = validate_cancellation(&env, rental_id, renter).unwrap();
 
     let payment = get_payment_by_rental_id(&env, rental_id).unwrap();
-    let payment_timestamp = payment.timestamp;
-    let payment_amount = payment.amount;
-    let duration = rental.duration;
-    let rent_per_hour = get_equipment_price(&env, rental.equipment_id);
+    let rent = get_rent_amount(env, rental_id);
+    let rent_per_hour = rent / billable_hours(rental.start_time, rental.end_time) as i128;
 
-    // Amount of refund =  (duration - (current_time - payment_timestamp))
+    // Hours are billed the same way as the rent: a started hour counts as used.
+    // Refund the hours that have not been started yet, the owner is paid for the rest
     // Maybe remove a cancellation fee
-    let amount_to_refund = (duration - (current_time - payment_timestamp)) * rent_per_hour as u64;
+    let used_until = current_time.clamp(rental.start_time, rental.end_time);
+    let unused_hours = billable_hours(rental.start_time, rental.end_time) - billable_hours(rental.start_time, used_until);
+    let amount_to_refund = unused_hours as i128 * rent_per_hour;
 
     update_rental_status(&env, rental_id, RentalStatus::Cancelled);
 
     let cancellation = Cancellation {
         rental_id,
         canceller: rental.renter.clone(),
-        refund_amount: amount_to_refund as i128,
+        refund_amount: amount_to_refund,
         timestamp: current_time
     };
 
-    env.storage().persistent().set(&RENTAL_KEY_CANCEL, &cancellation);
-    refund_payment(env.clone(), rental_id, amount_to_refund.into());
+    env.storage().persistent().set(&(RENTAL_KEY_CANCEL, rental_id), &cancellation);
+    send_refund(env, &payment, amount_to_refund);
+    pay_owner(env, &rental, rent - amount_to_refund);
+
+    // The equipment was never handed over, so there is nothing to inspect
+    if current_time <= rental.start_time {
+        return_deposit(env, rental_id);
+    }
 
     env.events().publish((RENTAL_CANCEL, rental_id), (rental.equipment_id, rental.renter, amount_to_refund));
 }
 
+/// The owner confirms the equipment came back in order: the rent is paid out
+/// and the security deposit returned to the payer.
+pub fn confirm_return(env: &Env, owner: Address, rental_id: u64) {
+    let rental = get_rental_by_rental_id(env, rental_id).expect("Rental not found");
+    require_equipment_owner(env, &owner, rental.equipment_id);
+
+    settle_return(env, &rental);
+}
+
+/// Return the security deposit once the claim window after the rental has lapsed
+/// without the owner confirming the return or filing a claim. Anyone can trigger it.
+pub fn release_deposit(env: &Env, rental_id: u64) {
+    let rental = get_rental_by_rental_id(env, rental_id).expect("Rental not found");
+
+    if env.ledger().timestamp() < claim_deadline(env, &rental) {
+        panic!("Claim window still open");
+    }
+
+    settle_return(env, &rental);
+}
+
+fn settle_return(env: &Env, rental: &Rental) {
+    get_held_deposit(env, rental.rental_id);
+    complete_rental(env, rental);
+    return_deposit(env, rental.rental_id);
+}
+
+/// Mark a rental whose equipment is back as completed and pay the owner the rent
+pub fn complete_rental(env: &Env, rental: &Rental) {
+    match rental.status {
+        RentalStatus::Active => {
+            update_rental_status(env, rental.rental_id, RentalStatus::Completed);
+            pay_owner(env, rental, get_rent_amount(env, rental.rental_id));
+            env.events().publish((RENTAL_COMPLETED, rental.rental_id), (rental.equipment_id, rental.renter.clone()));
+        }
+        // Cancelled rentals were already paid out when they were cancelled
+        RentalStatus::Cancelled => {}
+        _ => panic!("Rental has no equipment to return"),
+    }
+}
+
+/// Last moment the owner can file a damage claim: the claim window after the
+/// end of the reservation, or after the cancellation time
+pub fn claim_deadline(env: &Env, rental: &Rental) -> u64 {
+    let cancellation: Option<Cancellation> = env.storage().persistent().get(&(RENTAL_KEY_CANCEL, rental.rental_id));
+    let return_due = match cancellation {
+        Some(cancellation) if rental.status == RentalStatus::Cancelled => cancellation.timestamp,
+        _ => rental.end_time,
+    };
+    return_due + get_claim_window(env)
+}
+
+pub fn set_claim_window(env: &Env, admin: Address, claim_window: u64) {
+    require_admin(env, &admin);
+    env.storage().persistent().set(&CLAIM_WINDOW, &claim_window);
+}
+
+pub fn get_claim_window(env: &Env) -> u64 {
+    env.storage().persistent().get(&CLAIM_WINDOW).unwrap_or(DEFAULT_CLAIM_WINDOW)
+}
+
 pub fn validate_cancellation(env: &Env, rental_id: u64, caller: Address) -> Option<Rental> {
     caller.require_auth();
 
@@ -113,18 +199,24 @@ pub fn validate_cancellation(env: &Env, rental_id: u64, caller: Address) -> Opti
     rental
 }
 
-
 pub fn save_rental(env: &Env, rental: &Rental) {
-    let mut rentals: Vec<Rental> = env.storage().persistent().get(&RENTAL_KEY).unwrap_or(Vec::new(&env));
-    rentals.push_back(rental.clone());
-    env.storage().persistent().set(&RENTAL_KEY, &rentals);
+    env.storage().persistent().set(&(RENTAL_KEY, rental.rental_id), rental);
+
+    let key = (EQUIPMENT_RENTALS, rental.equipment_id);
+    let mut rental_ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    if !rental_ids.contains(rental.rental_id) {
+        rental_ids.push_back(rental.rental_id);
+        env.storage().persistent().set(&key, &rental_ids);
+    }
 }
 
 pub fn get_rentals_by_equipment_id(env: &Env, equipment_id: u64) -> Vec<Rental> {
-    let rentals: Vec<Rental> = env.storage().persistent().get(&RENTAL_KEY).unwrap_or(Vec::new(&env));
-    let mut result = Vec::new(&env);
-    for rental in rentals.iter() {
-        if rental.equipment_id == equipment_id {
+    let rental_ids: Vec<u64> = env.storage().persistent()
+        .get(&(EQUIPMENT_RENTALS, equipment_id))
+        .unwrap_or(Vec::new(env));
+    let mut result = Vec::new(env);
+    for rental_id in rental_ids.iter() {
+        if let Some(rental) = get_rental_by_rental_id(env, rental_id) {
             result.push_back(rental);
         }
     }
@@ -132,54 +224,111 @@ pub fn get_rentals_by_equipment_id(env: &Env, equipment_id: u64) -> Vec<Rental>
 }
 
 pub fn get_rental_by_rental_id(env: &Env, rental_id: u64) -> Option<Rental> {
-    let rentals: Option<Vec<Rental>> = env.storage().persistent().get(&RENTAL_KEY).unwrap();
-    let rental = rentals
-        .and_then(|ps| ps.iter().find(|p| p.rental_id == rental_id))
-        .unwrap_or_else(|| panic!("Payment not found"));
-    Some(rental)
+    env.storage().persistent().get(&(RENTAL_KEY, rental_id))
 }
 
 pub fn update_rental_status(env: &Env, rental_id: u64, rental_status: RentalStatus) -> bool {
-    let mut rentals: Vec<Rental> = env.storage().persistent().get(&RENTAL_KEY).unwrap_or(Vec::new(&env));
-    if let Some(index) = rentals.iter().position(|r| r.rental_id == rental_id) {
-        let mut rental = rentals.get_unchecked((index as usize).try_into().unwrap()).clone();
+    if let Some(mut rental) = get_rental_by_rental_id(env, rental_id) {
         rental.status = rental_status;
-        rentals.set(index as u32, rental);
-        env.storage().persistent().set(&RENTAL_KEY, &rentals);
+        env.storage().persistent().set(&(RENTAL_KEY, rental_id), &rental);
         true
     } else {
         false
     }
 }
 
-pub fn check_availability(env: &Env, equipment_id: u64) -> bool {
-    let rentals = get_rentals_by_equipment_id(env, equipment_id);
-    let timestamp = env.ledger().timestamp();
+// Paid reservations hold their time slot; pending ones do not until payment
+fn is_blocking(rental: &Rental) -> bool {
+    rental.status == RentalStatus::Active
+}
 
-    for rental in rentals {
-        let payment_by_rental = get_payment_by_rental_id(&env, rental.rental_id);
+/// Whether a reservation can no longer be paid for: it expired, or it is still
+/// pending after the pending reservation TTL
+pub fn is_expired(env: &Env, rental: &Rental) -> bool {
+    match rental.status {
+        RentalStatus::Expired => true,
+        RentalStatus::Pending => env.ledger().timestamp() >= rental.created_at.saturating_add(PENDING_RESERVATION_TTL),
+        _ => false,
+    }
+}
 
-        match rental.status {
-            RentalStatus::Pending => {
-                if payment_by_rental.is_some() {
-                    return false; // Pending with payment, not available
-                }
-                continue; // Pending without payment, considered available
-            }
-            RentalStatus::Active => {
-                if let Some(payment) = payment_by_rental {
-                    let rental_end_time = payment.timestamp + rental.duration;
-                    if rental_end_time < timestamp {
-                        update_rental_status(&env, rental.rental_id, RentalStatus::Completed);
-                        continue; // Expired, considered available
-                    }
-                    return false; // Active, not expired, not available
-                }
-            }
-            RentalStatus::Completed | RentalStatus::Cancelled => {
-                continue; // Completed or cancelled, considered available
+/// Mark the unpaid reservations of the equipment that outlived the TTL as expired
+/// and drop them from its rental list, so that the list does not keep growing
+fn prune_expired_reservations(env: &Env, equipment_id: u64) {
+    let key = (EQUIPMENT_RENTALS, equipment_id);
+    let rental_ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+
+    let mut kept = Vec::new(env);
+    for rental_id in rental_ids.iter() {
+        match get_rental_by_rental_id(env, rental_id) {
+            Some(rental) if is_expired(env, &rental) => {
+                update_rental_status(env, rental_id, RentalStatus::Expired);
             }
+            _ => kept.push_back(rental_id),
+        }
+    }
+
+    if kept.len() != rental_ids.len() {
+        env.storage().persistent().set(&key, &kept);
+    }
+}
+
+fn overlaps(rental: &Rental, start_time: u64, end_time: u64) -> bool {
+    rental.start_time < end_time && start_time < rental.end_time
+}
+
+/// Check that no paid reservation of the equipment overlaps `[start_time, end_time)`
+pub fn check_availability(env: &Env, equipment_id: u64, start_time: u64, end_time: u64) -> bool {
+    for rental in get_rentals_by_equipment_id(env, equipment_id).iter() {
+        if is_blocking(&rental) && overlaps(&rental, start_time, end_time) {
+            return false;
         }
     }
-    true // No blocking rentals, equipment available
+    true
+}
+
+/// Reservations of the equipment overlapping `[from, to)`, ordered by start time.
+/// Cancelled and expired reservations are left out.
+pub fn get_equipment_calendar(env: &Env, equipment_id: u64, from: u64, to: u64) -> Vec<Rental> {
+    let mut calendar: Vec<Rental> = Vec::new(env);
+    for rental in get_rentals_by_equipment_id(env, equipment_id).iter() {
+        if rental.status == RentalStatus::Cancelled || is_expired(env, &rental) || !overlaps(&rental, from, to) {
+            continue;
+        }
+
+        let position = calendar.iter()
+            .position(|booked| booked.start_time > rental.start_time)
+            .unwrap_or(calendar.len() as usize);
+        calendar.insert(position as u32, rental);
+    }
+    calendar
+}
+
+/// Earliest start time at or after `from` when the equipment is free for `duration` seconds
+pub fn get_next_available_slot(env: &Env, equipment_id: u64, from: u64, duration: u64) -> u64 {
+    if duration == 0 {
+        panic!("Invalid rental duration");
+    }
+
+    let mut candidate = from;
+    for rental in get_equipment_calendar(env, equipment_id, from, u64::MAX).iter() {
+        if !is_blocking(&rental) || rental.end_time <= candidate {
+            continue;
+        }
+        if rental.start_time >= slot_end(candidate, duration) {
+            break;
+        }
+        candidate = rental.end_time;
+    }
+    slot_end(candidate, duration);
+    candidate
+}
+
+fn slot_end(start_time: u64, duration: u64) -> u64 {
+    start_time.checked_add(duration).unwrap_or_else(|| panic!("No available slot"))
+}
+
+/// Total rent owed for a reservation, charged per started hour
+pub fn rental_cost(env: &Env, rental: &Rental) -> i128 {
+    billable_hours(rental.start_time, rental.end_time) as i128 * get_equipment_price(env, rental.equipment_id)
 }
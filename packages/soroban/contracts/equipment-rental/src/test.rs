@@ -2,9 +2,11 @@
 extern crate std;
 
 use super::*;
-use crate::rental::RentalStatus;
+use crate::rental::{RentalStatus, PENDING_RESERVATION_TTL};
+use crate::payment::DepositStatus;
+use crate::claim::ClaimStatus;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{token, Address, Env, log};
+use soroban_sdk::{token, Address, BytesN, Env, String, log};
 use token::Client as TokenClient;
 use token::StellarAssetClient as TokenAdminClient;
 use soroban_sdk::testutils::{LedgerInfo};
@@ -17,6 +19,22 @@ fn create_token_contract<'a>(e: &Env, admin: &Address) -> (TokenClient<'a>, Toke
     )
 }
 
+// Reserve equipment from now for `hours` hours
+fn reserve(client: &EquipmentRentalContractClient, renter: &Address, equipment_id: &u64, hours: u64) -> u64 {
+    let start_time = client.env.ledger().timestamp();
+    client.create_rental(renter, equipment_id, &start_time, &(start_time + hours * 3600))
+}
+
+fn register_equipment(client: &EquipmentRentalContractClient, owner: &Address, price_per_hour: i128, security_deposit: i128) -> u64 {
+    client.register_equipment(
+        owner,
+        &String::from_str(&client.env, "Microscope"),
+        &price_per_hour,
+        &security_deposit,
+        &EquipmentCondition::Good,
+    )
+}
+
 fn create_claimable_balance_contract<'a>(e: &Env) -> EquipmentRentalContractClient<'a> {
     EquipmentRentalContractClient::new(e, &e.register(EquipmentRentalContract, ()))
 }
@@ -24,6 +42,8 @@ fn create_claimable_balance_contract<'a>(e: &Env) -> EquipmentRentalContractClie
 struct EquipmentRentalTest<'a> {
     env: Env,
     deposit_address: Address,
+    admin: Address,
+    owner: Address,
     payer: Address,
     payer2: Address,
     renter: Address,
@@ -50,9 +70,18 @@ impl<'a> EquipmentRentalTest<'a> {
         token_admin_client.mint(&payer2, &300_000_000); // 300m stroops == 300 xlm
 
         let contract = create_claimable_balance_contract(&env);
+
+        // Equipment 1 and 2, 1 xlm per hour without a security deposit
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        register_equipment(&contract, &owner, 10_000_000, 0);
+        register_equipment(&contract, &owner, 10_000_000, 0);
+
         EquipmentRentalTest {
             env,
             deposit_address,
+            admin,
+            owner,
             payer,
             payer2,
             renter,
@@ -69,7 +98,11 @@ fn test_create_rental_success_rental_id() {
     let client = EquipmentRentalContractClient::new(&env, &contract_id);
 
     let max_duration = 30 * 24; // 30 days
-    client.initialize(&max_duration);
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env), &max_duration);
+    let owner = Address::generate(&env);
+    register_equipment(&client, &owner, 10_000_000, 0);
+    register_equipment(&client, &owner, 10_000_000, 0);
 
     let equipment_id = 1;
     let duration = 1 * 24; // 1 day
@@ -77,7 +110,7 @@ fn test_create_rental_success_rental_id() {
 
     env.mock_all_auths();
 
-    client.create_rental(&renter, &equipment_id, &duration);
+    reserve(&client, &renter, &equipment_id, duration);
 
     // Verify rental storage
     let rental = client.get_rental_by_rental_id(&1).unwrap();
@@ -85,7 +118,7 @@ fn test_create_rental_success_rental_id() {
     assert_eq!(rental.rental_id, 1);
     assert_eq!(rental.equipment_id, equipment_id);
     assert_eq!(rental.renter, renter);
-    assert_eq!(rental.duration, duration);
+    assert_eq!(rental.end_time - rental.start_time, duration * 3600);
 }
 
 #[test]
@@ -95,20 +128,24 @@ fn test_create_multiple_rental_success() {
     let client = EquipmentRentalContractClient::new(&env, &contract_id);
 
     let max_duration = 30 * 24; // 30 days
-    client.initialize(&max_duration);
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env), &max_duration);
+    let owner = Address::generate(&env);
+    register_equipment(&client, &owner, 10_000_000, 0);
+    register_equipment(&client, &owner, 10_000_000, 0);
 
     let equipment_id = 1;
     let duration = 1 * 24; // 1 day
     let renter = Address::generate(&env);
 
     env.mock_all_auths();
-    client.create_rental(&renter, &equipment_id, &duration);
+    reserve(&client, &renter, &equipment_id, duration);
 
     let equipment_id_2 = 2;
     let duration_2 = 3 * 24; // 3 day
     env.mock_all_auths();
 
-    client.create_rental(&renter, &equipment_id_2, &duration_2);
+    reserve(&client, &renter, &equipment_id_2, duration_2);
 }
 
 #[test]
@@ -118,7 +155,11 @@ fn test_create_rental_equipment_time_ended() {
     let client = EquipmentRentalContractClient::new(&env, &contract_id);
 
     let max_duration = 30 * 24;
-    client.initialize(&max_duration);
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env), &max_duration);
+    let owner = Address::generate(&env);
+    register_equipment(&client, &owner, 10_000_000, 0);
+    register_equipment(&client, &owner, 10_000_000, 0);
 
     let equipment_id = 1;
     let duration = 1 * 24;  //1day
@@ -127,9 +168,9 @@ fn test_create_rental_equipment_time_ended() {
 
     // Create first rental
     env.mock_all_auths();
-    client.create_rental(&renter, &equipment_id, &duration);
+    reserve(&client, &renter, &equipment_id, duration);
 
-    client.create_rental(&renter2, &equipment_id, &duration);
+    reserve(&client, &renter2, &equipment_id, duration);
 }
 
 
@@ -140,7 +181,11 @@ fn test_create_rental_multiple_pending() {
     let client = EquipmentRentalContractClient::new(&env, &contract_id);
 
     let max_duration = 30 * 24;
-    client.initialize(&max_duration);
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env), &max_duration);
+    let owner = Address::generate(&env);
+    register_equipment(&client, &owner, 10_000_000, 0);
+    register_equipment(&client, &owner, 10_000_000, 0);
 
     let equipment_id = 1;
     let duration = 1 * 24;  //1day
@@ -149,8 +194,8 @@ fn test_create_rental_multiple_pending() {
 
     // Create first rental
     env.mock_all_auths();
-    client.create_rental(&renter, &equipment_id, &duration);
-    client.create_rental(&renter2, &equipment_id, &duration);
+    reserve(&client, &renter, &equipment_id, duration);
+    reserve(&client, &renter2, &equipment_id, duration);
 }
 
 #[test]
@@ -161,14 +206,18 @@ fn test_create_rental_invalid_duration() {
     let client = EquipmentRentalContractClient::new(&env, &contract_id);
 
     let max_duration = 30 * 24 * 3600 + 1;
-    client.initialize(&max_duration);
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env), &max_duration);
+    let owner = Address::generate(&env);
+    register_equipment(&client, &owner, 10_000_000, 0);
+    register_equipment(&client, &owner, 10_000_000, 0);
 
     let equipment_id = 1;
     let duration = max_duration + 1; // Exceeds max duration
     let renter = Address::generate(&env);
 
     env.mock_all_auths();
-    client.create_rental(&renter, &equipment_id, &duration);
+    reserve(&client, &renter, &equipment_id, duration);
 }
 
 #[test]
@@ -182,20 +231,18 @@ fn test_payment() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
     let rental = test.contract.get_rental_by_rental_id(&1).unwrap();
 
     assert_eq!(rental.rental_id, 1);
     assert_eq!(rental.equipment_id, equipment_id);
     assert_eq!(rental.renter, renter);
-    assert_eq!(rental.duration, duration);
+    assert_eq!(rental.end_time - rental.start_time, duration * 3600);
 
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _stored_equip_price = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = 240_000_000; // 240 xlm
@@ -210,10 +257,10 @@ fn test_payment() {
 }
 
 #[test]
-#[should_panic(expected = "NO PRICE FOR EQUIPMENT")]
-fn test_payment_equipment_price_unset() {
+#[should_panic(expected = "Equipment not registered")]
+fn test_rental_unregistered_equipment() {
     let max_duration = 30 * 24; // 30 days
-    let equipment_id = 1;
+    let equipment_id = 3;
     let duration = 1 * 24; // 1 day
 
     let test = EquipmentRentalTest::setup();
@@ -221,10 +268,10 @@ fn test_payment_equipment_price_unset() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
-    test.contract.set_token_address(&token);
+    test.contract.set_token_address(&test.admin, &token);
     let amount_to_pay = 240_000_000; // 240 xlm
     test.contract.process_payment(
         &1,
@@ -245,14 +292,11 @@ fn test_payment_multiple() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
-    test.contract.create_rental(&renter, &equipment_id_2, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
+    reserve(&test.contract, &renter, &equipment_id_2, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
-    test.contract.set_equipment_price(&equipment_id_2, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
 
     let amount_to_pay = 240_000_000; // 240 xlm
     test.contract.process_payment(
@@ -289,13 +333,11 @@ fn test_payment_same_equipmnent_unavailable() {
     let payer2 = test.payer2;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&payer1, &equipment_id, &duration);
-    test.contract.create_rental(&payer2, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &payer1, &equipment_id, duration);
+    reserve(&test.contract, &payer2, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
 
     let amount_to_pay = 240_000_000; // 240 xlm
     test.contract.process_payment(
@@ -304,9 +346,10 @@ fn test_payment_same_equipmnent_unavailable() {
         &amount_to_pay,
     );
 
+    // The second reservation of the same slot cannot be paid
     let amount_to_pay_2 = 280_000_000; // 280 xlm
     test.contract.process_payment(
-        &1,
+        &2,
         &payer2,
         &amount_to_pay_2,
     );
@@ -325,14 +368,11 @@ fn test_payment_multiple_equipmnents() {
     let payer2 = test.payer2;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&payer1, &equipment_id, &duration);
-    test.contract.create_rental(&payer2, &equipment_id_2, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &payer1, &equipment_id, duration);
+    reserve(&test.contract, &payer2, &equipment_id_2, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
-    test.contract.set_equipment_price(&equipment_id_2, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
 
     let amount_to_pay = 240_000_000; // 240 xlm
     test.contract.process_payment(
@@ -372,12 +412,10 @@ fn test_payment_same_equipmnent_available_after_completed() {
     let payer2 = test.payer2;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&payer1, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &payer1, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
 
     let _ = test.contract.get_rental_by_rental_id(&(1 as u64));
 
@@ -391,7 +429,7 @@ fn test_payment_same_equipmnent_available_after_completed() {
     let _ = test.contract.get_rental_by_rental_id(&(1 as u64));
     let _ = test.contract.get_rentals_by_equipment_id(&(1 as u64));
 
-    let next_timestamp = test.env.ledger().timestamp() + duration + 10;
+    let next_timestamp = test.env.ledger().timestamp() + duration * 3600 + 10;
 
     test.env.ledger().set(LedgerInfo {
         timestamp: next_timestamp,
@@ -404,11 +442,11 @@ fn test_payment_same_equipmnent_available_after_completed() {
         max_entry_ttl: 6312000,
     });
 
-    test.contract.create_rental(&payer2, &equipment_id, &duration);
+    let rental_id_2 = reserve(&test.contract, &payer2, &equipment_id, duration);
 
     let amount_to_pay_2 = 280_000_000; // 280 xlm
     test.contract.process_payment(
-        &1,
+        &rental_id_2,
         &payer2,
         &amount_to_pay_2,
     );
@@ -425,12 +463,10 @@ fn test_payment_and_refund() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _ = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = 200_000_000; // 200 xlm
@@ -448,6 +484,24 @@ fn test_payment_and_refund() {
     assert_eq!(test.token.balance(&test.payer), 100_000_000);
 }
 
+#[test]
+#[should_panic(expected = "Max Refundable Amount: 0")]
+fn test_payment_refunded_only_once() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    // 2 xlm of rent paid with 5 xlm
+    let rental_id = test.contract.create_rental(&test.renter, &1, &now, &(now + 2 * 3600));
+    test.contract.process_payment(&rental_id, &test.payer, &5_000_000);
+
+    test.contract.refund_payment(&rental_id, &3_000_000);
+    assert_eq!(test.token.balance(&test.payer), 248_000_000);
+    assert_eq!(test.contract.get_payment_by_rental_id(&rental_id).unwrap().refunded, 3_000_000);
+
+    // The contract still holds the rent, but the overpayment is gone
+    test.contract.refund_payment(&rental_id, &1_000_000);
+}
+
 #[test]
 #[should_panic(expected = "Max Refundable Amount:")]
 fn test_payment_and_refund_failed() {
@@ -460,12 +514,10 @@ fn test_payment_and_refund_failed() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
 
     let amount_to_pay = 200_000_000; // 200 xlm
     test.contract.process_payment(
@@ -489,12 +541,10 @@ fn test_payment_and_refund_neg_amount_failed() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _ = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = -200_000_000; // 200 xlm
@@ -517,12 +567,10 @@ fn test_payment_and_refund_insufficient_amount_failed() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _ = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = 100_000_000; // 100 xlm
@@ -546,12 +594,10 @@ fn test_payment_and_refund_insufficient_contract_balance_failed() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    reserve(&test.contract, &renter, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _ = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = 200_000_000; // 200 xlm
@@ -575,12 +621,10 @@ fn test_payment_and_cancel() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    let rental_id = test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    let rental_id = reserve(&test.contract, &renter, &equipment_id, duration);
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _ = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = 200_000_000; // 200 xlm
@@ -596,15 +640,17 @@ fn test_payment_and_cancel() {
     let rent = test.contract.get_rental_by_rental_id(&rental_id);
     
     test.env.ledger().with_mut(|li| {
-        li.timestamp = 1 * 13; // 24 hours + 1 second
+        li.timestamp = 1 + 12 * 3600; // 12 of 15 hours used
     });
 
     test.contract.cancel_rental(&renter, &rental_id);
 
     let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
 
-    assert_eq!(test.token.balance(&test.contract.address), 170000000);
+    // 3 unused hours are refunded, the owner is paid for the 12 used ones
+    assert_eq!(test.token.balance(&test.contract.address), 50_000_000);
     assert_eq!(test.token.balance(&test.payer), 80000000);
+    assert_eq!(test.token.balance(&test.owner), 120_000_000);
 
     assert_eq!(rental.rental_id, 1);
     assert_eq!(rental.status, RentalStatus::Cancelled);
@@ -622,13 +668,11 @@ fn test_payment_and_cancel_create() {
     let renter = test.renter;
     let token = &test.token.address;
 
-    test.contract.initialize(&max_duration);
-    let rental_id = test.contract.create_rental(&renter, &equipment_id, &duration);
+    test.contract.initialize(&test.admin, &max_duration);
+    let rental_id = reserve(&test.contract, &renter, &equipment_id, duration);
     let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
 
-    let price_per_hour = 10_000_000; // 1 xlm
-    test.contract.set_token_address(&token);
-    test.contract.set_equipment_price(&equipment_id, &(price_per_hour as i128));
+    test.contract.set_token_address(&test.admin, &token);
     let _ = test.contract.get_equipment_price(&equipment_id);
 
     let amount_to_pay = 200_000_000; // 200 xlm
@@ -640,7 +684,7 @@ fn test_payment_and_cancel_create() {
 
     
     test.env.ledger().with_mut(|li| {
-        li.timestamp = 1 * 13; // 24 hours + 1 second
+        li.timestamp = 1 + 12 * 3600; // 12 of 15 hours used
     });
 
     test.contract.cancel_rental(&renter, &rental_id);
@@ -649,11 +693,489 @@ fn test_payment_and_cancel_create() {
     let duration = 25; // 25hrs
     let equipment_id_2 = 1;
 
-    let new_rental_id = test.contract.create_rental(&new_renter, &equipment_id_2, &duration);
+    let new_rental_id = reserve(&test.contract, &new_renter, &equipment_id_2, duration);
     let rental = test.contract.get_rental_by_rental_id(&new_rental_id).unwrap();
 
     assert_eq!(rental.rental_id, 2);
     assert_eq!(rental.equipment_id, equipment_id);
     assert_eq!(rental.renter, new_renter);
-    assert_eq!(rental.duration, duration);
-}
\ No newline at end of file
+    assert_eq!(rental.end_time - rental.start_time, duration * 3600);
+}
+// Reserve `[start_hour, end_hour)` (in hours from now) and pay for it
+fn book(test: &EquipmentRentalTest, equipment_id: u64, start_hour: u64, end_hour: u64) -> u64 {
+    let now = test.env.ledger().timestamp();
+    let rental_id = test.contract.create_rental(
+        &test.renter,
+        &equipment_id,
+        &(now + start_hour * 3600),
+        &(now + end_hour * 3600),
+    );
+    test.contract.process_payment(&rental_id, &test.payer, &(((end_hour - start_hour) * 1_000_000) as i128));
+    rental_id
+}
+
+fn setup_booking_test<'a>() -> EquipmentRentalTest<'a> {
+    let test = EquipmentRentalTest::setup();
+    test.contract.initialize(&test.admin, &(30 * 24 * 3600));
+    test.contract.set_token_address(&test.admin, &test.token.address);
+    test.contract.set_equipment_price(&test.owner, &1, &1_000_000);
+    test
+}
+
+#[test]
+fn test_future_reservation() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    let rental_id = book(&test, 1, 48, 50);
+    let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
+
+    assert_eq!(rental.start_time, now + 48 * 3600);
+    assert_eq!(rental.end_time, now + 50 * 3600);
+    assert_eq!(rental.status, RentalStatus::Active);
+    assert_eq!(test.token.balance(&test.contract.address), 2_000_000);
+
+    // The slot is taken, the time before and after it is free
+    assert!(!test.contract.check_availability(&1, &(now + 49 * 3600), &(now + 51 * 3600)));
+    assert!(test.contract.check_availability(&1, &now, &(now + 48 * 3600)));
+    assert!(test.contract.check_availability(&1, &(now + 50 * 3600), &(now + 52 * 3600)));
+    assert!(test.contract.check_availability(&2, &(now + 49 * 3600), &(now + 51 * 3600)));
+}
+
+#[test]
+#[should_panic(expected = "Equipment not available")]
+fn test_overlapping_reservation_rejected() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    book(&test, 1, 10, 20);
+    test.contract.create_rental(&test.renter, &1, &(now + 19 * 3600), &(now + 21 * 3600));
+}
+
+#[test]
+#[should_panic(expected = "Start time is in the past")]
+fn test_reservation_in_the_past_rejected() {
+    let test = setup_booking_test();
+    test.env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    test.contract.create_rental(&test.renter, &1, &9_000, &20_000);
+}
+
+#[test]
+fn test_check_availability_has_no_side_effects() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    let rental_id = book(&test, 1, 0, 2);
+    test.env.ledger().with_mut(|li| li.timestamp = now + 3 * 3600);
+
+    assert!(test.contract.check_availability(&1, &(now + 3 * 3600), &(now + 4 * 3600)));
+    let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
+    assert_eq!(rental.status, RentalStatus::Active);
+}
+
+#[test]
+fn test_equipment_calendar_and_next_slot() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    let late = book(&test, 1, 10, 12);
+    let early = book(&test, 1, 2, 4);
+    let middle = book(&test, 1, 4, 7);
+    let pending = test.contract.create_rental(&test.renter, &1, &(now + 20 * 3600), &(now + 22 * 3600));
+
+    // Ordered by start time within the requested range
+    let calendar = test.contract.get_equipment_calendar(&1, &now, &(now + 11 * 3600));
+    assert_eq!(calendar.len(), 3);
+    assert_eq!(calendar.get(0).unwrap().rental_id, early);
+    assert_eq!(calendar.get(1).unwrap().rental_id, middle);
+    assert_eq!(calendar.get(2).unwrap().rental_id, late);
+
+    let calendar = test.contract.get_equipment_calendar(&1, &(now + 12 * 3600), &(now + 24 * 3600));
+    assert_eq!(calendar.len(), 1);
+    assert_eq!(calendar.get(0).unwrap().rental_id, pending);
+
+    // Two free hours fit before the first booking
+    assert_eq!(test.contract.get_next_available_slot(&1, &now, &(2 * 3600)), now);
+    // Four hours do not fit in the 7h-10h gap, so the slot starts when the last paid booking ends
+    assert_eq!(test.contract.get_next_available_slot(&1, &(now + 3 * 3600), &(4 * 3600)), now + 12 * 3600);
+    assert_eq!(test.contract.get_next_available_slot(&1, &(now + 3 * 3600), &(3 * 3600)), now + 7 * 3600);
+}
+
+#[test]
+fn test_cancel_refund_rounds_like_billing() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    // 90 minutes are billed as 2 hours
+    let rental_id = test.contract.create_rental(&test.renter, &1, &(now + 3600), &(now + 3600 + 90 * 60));
+    test.contract.process_payment(&rental_id, &test.payer, &2_000_000);
+
+    // 30 minutes in, the first hour is started and the second one is refunded
+    test.env.ledger().with_mut(|li| li.timestamp = now + 3600 + 30 * 60);
+    test.contract.cancel_rental(&test.renter, &rental_id);
+
+    assert_eq!(test.token.balance(&test.payer), 249_000_000);
+    assert_eq!(test.token.balance(&test.owner), 1_000_000);
+
+    // Cancelled before the start, every billed hour is refunded
+    let now = test.env.ledger().timestamp();
+    let rental_id = test.contract.create_rental(&test.renter, &1, &(now + 3600), &(now + 3600 + 90 * 60));
+    test.contract.process_payment(&rental_id, &test.payer, &2_000_000);
+    test.contract.cancel_rental(&test.renter, &rental_id);
+
+    assert_eq!(test.token.balance(&test.payer), 249_000_000);
+    assert_eq!(test.token.balance(&test.owner), 1_000_000);
+}
+
+#[test]
+fn test_unpaid_reservation_expires_and_is_pruned() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    let pending = test.contract.create_rental(&test.renter, &1, &(now + 48 * 3600), &(now + 50 * 3600));
+    test.env.ledger().with_mut(|li| li.timestamp = now + PENDING_RESERVATION_TTL);
+    assert_eq!(test.contract.get_equipment_calendar(&1, &now, &u64::MAX).len(), 0);
+
+    let rental_id = test.contract.create_rental(&test.renter, &1, &(now + 48 * 3600), &(now + 50 * 3600));
+
+    let rentals = test.contract.get_rentals_by_equipment_id(&1);
+    assert_eq!(rentals.len(), 1);
+    assert_eq!(rentals.get(0).unwrap().rental_id, rental_id);
+    assert_eq!(test.contract.get_rental_by_rental_id(&pending).unwrap().status, RentalStatus::Expired);
+}
+
+#[test]
+#[should_panic(expected = "Reservation expired")]
+fn test_expired_reservation_cannot_be_paid() {
+    let test = setup_booking_test();
+    let now = test.env.ledger().timestamp();
+
+    let rental_id = test.contract.create_rental(&test.renter, &1, &(now + 48 * 3600), &(now + 50 * 3600));
+    test.env.ledger().with_mut(|li| li.timestamp = now + PENDING_RESERVATION_TTL);
+    test.contract.process_payment(&rental_id, &test.payer, &2_000_000);
+}
+
+#[test]
+#[should_panic(expected = "No available slot")]
+fn test_next_available_slot_overflow() {
+    let test = setup_booking_test();
+    test.contract.get_next_available_slot(&1, &(u64::MAX - 10), &3600);
+}
+
+// Equipment 3 at 1 xlm per hour with a 5 xlm deposit, 10% platform fee
+fn setup_deposit_test<'a>() -> (EquipmentRentalTest<'a>, u64) {
+    let test = EquipmentRentalTest::setup();
+    test.contract.initialize(&test.admin, &(30 * 24 * 3600));
+    test.contract.set_token_address(&test.admin, &test.token.address);
+    test.contract.set_platform_fee(&test.admin, &1_000);
+
+    let equipment_id = register_equipment(&test.contract, &test.owner, 10_000_000, 50_000_000);
+    (test, equipment_id)
+}
+
+// Reserve and pay 10 hours of rent plus the deposit, starting `start_hour` hours from now
+fn book_with_deposit(test: &EquipmentRentalTest, equipment_id: u64, start_hour: u64) -> u64 {
+    let now = test.env.ledger().timestamp();
+    let rental_id = test.contract.create_rental(
+        &test.renter,
+        &equipment_id,
+        &(now + start_hour * 3600),
+        &(now + (start_hour + 10) * 3600),
+    );
+    test.contract.process_payment(&rental_id, &test.payer, &150_000_000);
+    rental_id
+}
+
+#[test]
+fn test_register_equipment_and_owner_pricing() {
+    let (test, equipment_id) = setup_deposit_test();
+
+    let equipment = test.contract.get_equipment(&equipment_id).unwrap();
+    assert_eq!(equipment.equipment_id, 3);
+    assert_eq!(equipment.owner, test.owner);
+    assert_eq!(equipment.price_per_hour, 10_000_000);
+    assert_eq!(equipment.security_deposit, 50_000_000);
+    assert_eq!(equipment.condition, EquipmentCondition::Good);
+
+    test.contract.set_equipment_price(&test.owner, &equipment_id, &20_000_000);
+    test.contract.set_security_deposit(&test.owner, &equipment_id, &0);
+    test.contract.set_equipment_condition(&test.owner, &equipment_id, &EquipmentCondition::Worn);
+
+    let equipment = test.contract.get_equipment(&equipment_id).unwrap();
+    assert_eq!(test.contract.get_equipment_price(&equipment_id), 20_000_000);
+    assert_eq!(equipment.security_deposit, 0);
+    assert_eq!(equipment.condition, EquipmentCondition::Worn);
+}
+
+#[test]
+#[should_panic(expected = "Only the equipment owner can do this")]
+fn test_set_equipment_price_not_owner() {
+    let (test, equipment_id) = setup_deposit_test();
+    test.contract.set_equipment_price(&test.renter, &equipment_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Only the admin can do this")]
+fn test_set_token_address_not_admin() {
+    let (test, _) = setup_deposit_test();
+    test.contract.set_token_address(&test.owner, &test.token.address);
+}
+
+#[test]
+fn test_confirm_return_pays_owner_and_returns_deposit() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+
+    // 10 xlm rent + 5 xlm deposit escrowed
+    let deposit = test.contract.get_deposit(&rental_id).unwrap();
+    assert_eq!(deposit.amount, 50_000_000);
+    assert_eq!(deposit.status, DepositStatus::Held);
+    assert_eq!(test.token.balance(&test.contract.address), 150_000_000);
+
+    test.env.ledger().with_mut(|li| li.timestamp += 10 * 3600);
+    test.contract.confirm_return(&test.owner, &rental_id);
+
+    assert_eq!(test.token.balance(&test.owner), 90_000_000);
+    assert_eq!(test.token.balance(&test.admin), 10_000_000);
+    assert_eq!(test.token.balance(&test.payer), 150_000_000);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+
+    let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
+    assert_eq!(rental.status, RentalStatus::Completed);
+    assert_eq!(test.contract.get_deposit(&rental_id).unwrap().status, DepositStatus::Returned);
+}
+
+#[test]
+#[should_panic(expected = "Only the equipment owner can do this")]
+fn test_confirm_return_not_owner() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+
+    test.contract.confirm_return(&test.renter, &rental_id);
+}
+
+#[test]
+fn test_release_deposit_after_claim_window() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+    let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
+
+    test.env.ledger().with_mut(|li| li.timestamp = rental.end_time + test.contract.get_claim_window());
+    test.contract.release_deposit(&rental_id);
+
+    assert_eq!(test.token.balance(&test.owner), 90_000_000);
+    assert_eq!(test.token.balance(&test.payer), 150_000_000);
+    assert_eq!(test.contract.get_rental_by_rental_id(&rental_id).unwrap().status, RentalStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Claim window still open")]
+fn test_release_deposit_claim_window_open() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+    let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
+
+    test.env.ledger().with_mut(|li| li.timestamp = rental.end_time + test.contract.get_claim_window() - 1);
+    test.contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Deposit already settled")]
+fn test_deposit_cannot_be_released_twice() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+
+    test.contract.confirm_return(&test.owner, &rental_id);
+    test.env.ledger().with_mut(|li| li.timestamp += 30 * 24 * 3600);
+    test.contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Rental is not awaiting payment")]
+fn test_completed_rental_cannot_be_paid_again() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+
+    test.contract.confirm_return(&test.owner, &rental_id);
+    test.contract.process_payment(&rental_id, &test.payer, &150_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Rental is not awaiting payment")]
+fn test_cancelled_rental_cannot_be_paid_again() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 24);
+
+    test.contract.cancel_rental(&test.renter, &rental_id);
+    test.contract.process_payment(&rental_id, &test.payer, &150_000_000);
+}
+
+#[test]
+fn test_cancel_before_start_returns_deposit() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 24);
+
+    test.contract.cancel_rental(&test.renter, &rental_id);
+
+    assert_eq!(test.token.balance(&test.payer), 250_000_000);
+    assert_eq!(test.token.balance(&test.owner), 0);
+    assert_eq!(test.contract.get_deposit(&rental_id).unwrap().status, DepositStatus::Returned);
+}
+
+// Finished 10 hour rental with a 5 xlm deposit, and the owner claims 2 xlm of it
+fn setup_claim_test<'a>() -> (EquipmentRentalTest<'a>, u64) {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+
+    test.env.ledger().with_mut(|li| li.timestamp += 10 * 3600);
+    test.contract.file_claim(&test.owner, &rental_id, &20_000_000, &BytesN::from_array(&test.env, &[1; 32]));
+    (test, rental_id)
+}
+
+#[test]
+fn test_claim_accepted_by_renter() {
+    let (test, rental_id) = setup_claim_test();
+
+    // Filing the claim pays out the rent and locks the deposit
+    assert_eq!(test.token.balance(&test.owner), 90_000_000);
+    assert_eq!(test.contract.get_rental_by_rental_id(&rental_id).unwrap().status, RentalStatus::Completed);
+    assert_eq!(test.contract.get_deposit(&rental_id).unwrap().status, DepositStatus::Claimed);
+
+    let claim = test.contract.get_claim(&rental_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Open);
+    assert_eq!(claim.amount, 20_000_000);
+    assert_eq!(claim.renter, test.renter);
+    assert_eq!(claim.response_deadline, claim.filed_at + test.contract.get_response_window());
+
+    test.contract.accept_claim(&test.renter, &rental_id);
+
+    assert_eq!(test.token.balance(&test.owner), 110_000_000);
+    assert_eq!(test.token.balance(&test.payer), 130_000_000);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+
+    let claim = test.contract.get_claim(&rental_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Accepted);
+    assert_eq!(claim.owner_award, 20_000_000);
+    assert_eq!(test.contract.get_deposit(&rental_id).unwrap().status, DepositStatus::Settled);
+}
+
+#[test]
+fn test_disputed_claim_resolved_by_arbiter() {
+    let (test, rental_id) = setup_claim_test();
+    let arbiter = Address::generate(&test.env);
+    test.contract.add_arbiter(&test.admin, &arbiter);
+    assert!(test.contract.is_arbiter(&arbiter));
+
+    let response = BytesN::from_array(&test.env, &[2; 32]);
+    test.contract.dispute_claim(&test.renter, &rental_id, &response);
+
+    let claim = test.contract.get_claim(&rental_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Disputed);
+    assert_eq!(claim.response_hash, Some(response));
+
+    test.contract.resolve_claim(&arbiter, &rental_id, &5_000_000);
+
+    assert_eq!(test.token.balance(&test.owner), 95_000_000);
+    assert_eq!(test.token.balance(&test.payer), 145_000_000);
+
+    let claim = test.contract.get_claim(&rental_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Resolved);
+    assert_eq!(claim.arbiter, Some(arbiter));
+    assert_eq!(claim.owner_award, 5_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Not an arbiter")]
+fn test_resolve_claim_not_arbiter() {
+    let (test, rental_id) = setup_claim_test();
+    let arbiter = Address::generate(&test.env);
+    test.contract.add_arbiter(&test.admin, &arbiter);
+    test.contract.remove_arbiter(&test.admin, &arbiter);
+
+    test.contract.dispute_claim(&test.renter, &rental_id, &BytesN::from_array(&test.env, &[2; 32]));
+    test.contract.resolve_claim(&arbiter, &rental_id, &0);
+}
+
+#[test]
+#[should_panic(expected = "Claim is not disputed")]
+fn test_resolve_claim_before_dispute() {
+    let (test, rental_id) = setup_claim_test();
+    let arbiter = Address::generate(&test.env);
+    test.contract.add_arbiter(&test.admin, &arbiter);
+
+    test.contract.resolve_claim(&arbiter, &rental_id, &0);
+}
+
+#[test]
+fn test_unanswered_claim_goes_to_owner() {
+    let (test, rental_id) = setup_claim_test();
+    let claim = test.contract.get_claim(&rental_id).unwrap();
+
+    test.env.ledger().with_mut(|li| li.timestamp = claim.response_deadline);
+    test.contract.finalize_unanswered_claim(&rental_id);
+
+    assert_eq!(test.token.balance(&test.owner), 110_000_000);
+    assert_eq!(test.token.balance(&test.payer), 130_000_000);
+    assert_eq!(test.contract.get_claim(&rental_id).unwrap().status, ClaimStatus::Unanswered);
+}
+
+#[test]
+#[should_panic(expected = "Response window closed")]
+fn test_dispute_after_response_window() {
+    let (test, rental_id) = setup_claim_test();
+    let claim = test.contract.get_claim(&rental_id).unwrap();
+
+    test.env.ledger().with_mut(|li| li.timestamp = claim.response_deadline);
+    test.contract.dispute_claim(&test.renter, &rental_id, &BytesN::from_array(&test.env, &[2; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Deposit is under claim")]
+fn test_claimed_deposit_not_released() {
+    let (test, rental_id) = setup_claim_test();
+
+    test.env.ledger().with_mut(|li| li.timestamp += 30 * 24 * 3600);
+    test.contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Claim window closed")]
+fn test_claim_after_claim_window() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+    let rental = test.contract.get_rental_by_rental_id(&rental_id).unwrap();
+
+    test.env.ledger().with_mut(|li| li.timestamp = rental.end_time + test.contract.get_claim_window());
+    test.contract.file_claim(&test.owner, &rental_id, &1, &BytesN::from_array(&test.env, &[1; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Invalid claim amount")]
+fn test_claim_exceeds_deposit() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 0);
+
+    test.contract.file_claim(&test.owner, &rental_id, &50_000_001, &BytesN::from_array(&test.env, &[1; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Rental has not started")]
+fn test_claim_before_rental_start() {
+    let (test, equipment_id) = setup_deposit_test();
+    let rental_id = book_with_deposit(&test, equipment_id, 24);
+
+    test.contract.file_claim(&test.owner, &rental_id, &1, &BytesN::from_array(&test.env, &[1; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Invalid owner award")]
+fn test_resolve_claim_award_above_claim() {
+    let (test, rental_id) = setup_claim_test();
+    let arbiter = Address::generate(&test.env);
+    test.contract.add_arbiter(&test.admin, &arbiter);
+
+    // The deposit is 5 xlm but only 2 xlm were claimed
+    test.contract.dispute_claim(&test.renter, &rental_id, &BytesN::from_array(&test.env, &[2; 32]));
+    test.contract.resolve_claim(&arbiter, &rental_id, &20_000_001);
+}
@@ -1,10 +1,13 @@
 // equipment-rental/src/utils.rs
-use soroban_sdk::{Env, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, Symbol, symbol_short};
 use crate::rental::{MAX_DURATION};
 const LAST_ID: Symbol = symbol_short!("last_id");
+pub const ADMIN: Symbol = symbol_short!("admin");
+
+pub const HOUR: u64 = 3600;
 
 pub fn validate_duration(env: &Env, duration: u64) {
-    let max_duration: u64 = env.storage().instance().get(&MAX_DURATION).unwrap_or(30 * 24 * 3600); // Default: 30 days in hours
+    let max_duration: u64 = env.storage().instance().get(&MAX_DURATION).unwrap_or(30 * 24 * 3600); // Default: 30 days in seconds
     if duration == 0 || duration > max_duration {
         panic!("Invalid rental duration");
     }
@@ -17,3 +20,19 @@ pub fn generate_rental_id(env: &Env) -> u64 {
     id
 }
 
+/// Number of hours charged for `[start_time, end_time)`; a started hour counts as a full hour
+pub fn billable_hours(start_time: u64, end_time: u64) -> u64 {
+    (end_time - start_time).div_ceil(HOUR)
+}
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage().persistent().get(&ADMIN).expect("Contract not initialized")
+}
+
+/// Check that `caller` is the platform admin set at initialization
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+    if *caller != get_admin(env) {
+        panic!("Only the admin can do this");
+    }
+}
@@ -16,8 +16,9 @@ pub enum TierLevel {
 pub struct PremiumTier {
     pub user: Address,             // Stellar address of the user
     pub tier: TierLevel,           // Tier level
-    pub contribution: i128,        // Contribution amount in Stroops (1 XLM = 10,000,000 Stroops)
+    pub contribution: i128,        // Total amount paid in Stroops (1 XLM = 10,000,000 Stroops)
     pub assigned_at: u64,          // Tier assignment timestamp
+    pub expires_at: u64,           // End of the paid membership
     pub features: PremiumFeatures, // Available features for this tier
 }
 
@@ -64,6 +65,18 @@ impl TierLevel {
         }
     }
 
+    /// Price of one membership period (in Stroops), the tier's contribution threshold
+    pub fn price(&self) -> i128 {
+        const ONE_XLM: i128 = 10_000_000; // 1 XLM in Stroops
+
+        match self {
+            TierLevel::None => 0,
+            TierLevel::Basic => 100 * ONE_XLM,
+            TierLevel::Pro => 500 * ONE_XLM,
+            TierLevel::Elite => 2000 * ONE_XLM,
+        }
+    }
+
     /// Determine tier level based on contribution amount (in Stroops)
     /// 1 XLM = 10,000,000 Stroops
     pub fn from_contribution(contribution: i128) -> Self {
@@ -134,3 +147,23 @@ pub struct UserProfile {
     pub preferences: String,
     pub registered_at: u64,
 }
+
+/// Messages every user can send; custom text requires `custom_greeting_messages`
+pub const STANDARD_GREETINGS: [&str; 5] = [
+    "Hello!",
+    "Good morning!",
+    "Good evening!",
+    "Welcome!",
+    "Thank you!",
+];
+
+/// Greeting data structure
+#[contracttype]
+#[derive(Debug, Clone)]
+pub struct Greeting {
+    pub id: u64,                // Auto-incrementing unique ID
+    pub creator: Address,       // Stellar address of the creator
+    pub message: String,        // Greeting message (1-1000 chars)
+    pub timestamp: u64,         // Creation timestamp
+    pub edited_at: Option<u64>, // Last edit timestamp
+}
@@ -51,4 +51,37 @@ pub enum Error {
 
     /// Cross-contract call failed
     ExternalCallFailed = 16,
+
+    /// Greeting message is empty or too long
+    InvalidGreetingMessage = 17,
+
+    /// Daily greeting limit of the user's tier reached
+    DailyGreetingLimitReached = 18,
+
+    /// The user's tier only allows standard greeting messages
+    CustomMessageNotAllowed = 19,
+
+    /// Only the creator can change the greeting
+    NotGreetingCreator = 20,
+
+    /// User has already liked the greeting
+    AlreadyLiked = 21,
+
+    /// User has not liked the greeting
+    NotLiked = 22,
+
+    /// Comment is empty or too long
+    InvalidComment = 23,
+
+    /// Greeting reached the maximum number of comments
+    CommentLimitReached = 24,
+
+    /// Contract has not been initialized
+    NotInitialized = 25,
+
+    /// Contract is already initialized
+    AlreadyInitialized = 26,
+
+    /// User has no active (paid, unexpired) tier
+    TierNotActive = 27,
 }
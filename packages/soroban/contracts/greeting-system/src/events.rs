@@ -1,6 +1,9 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
-use crate::{Error, GreetingReward, TierAssignmentEvent, TierLevel, TierUpgradeEvent, UserProfile};
+use crate::{
+    Error, Greeting, GreetingReward, PremiumTier, TierAssignmentEvent, TierLevel, TierUpgradeEvent,
+    UserProfile,
+};
 
 /// Event symbol for tier assignment
 pub const TIER_ASSIGNED: Symbol = symbol_short!("TIER_ASGN");
@@ -8,14 +11,23 @@ pub const TIER_ASSIGNED: Symbol = symbol_short!("TIER_ASGN");
 /// Event symbol for tier upgrade
 pub const TIER_UPGRADED: Symbol = symbol_short!("TIER_UPG");
 
-/// Event symbol for tier downgrade (if allowed in future)
+/// Event symbol for tier downgrade (expiry or revocation)
 pub const TIER_DOWNGRADED: Symbol = symbol_short!("TIER_DWN");
 
+/// Event symbol for tier renewal
+pub const TIER_RENEWED: Symbol = symbol_short!("TIER_RNW");
+
+/// Event symbol for tier revocation by the admin
+pub const TIER_REVOKED: Symbol = symbol_short!("TIER_REV");
+
 /// Event symbol for greeting reward issuance
 pub const GREETING_REWARD: Symbol = symbol_short!("GRT_RWD");
 /// Event symbol for user registration
 pub const USER_REGISTERED: Symbol = symbol_short!("USR_REG");
 
+/// Event symbol for greeting lifecycle changes
+pub const GREETING_EVENT: Symbol = symbol_short!("GRT");
+
 /// Emit a tier assignment event
 pub fn emit_tier_assigned(env: &Env, event: &TierAssignmentEvent) -> Result<(), Error> {
     let tier_str = event.tier.to_str();
@@ -90,6 +102,42 @@ pub fn emit_tier_downgraded(
     Ok(())
 }
 
+/// Emit a tier renewal event
+pub fn emit_tier_renewed(
+    env: &Env,
+    tier: &PremiumTier,
+    contribution: i128,
+    timestamp: u64,
+) -> Result<(), Error> {
+    env.events().publish(
+        (TIER_RENEWED, symbol_short!("renewed")),
+        (
+            tier.user.clone(),
+            String::from_str(env, tier.tier.to_str()),
+            contribution,
+            tier.expires_at,
+            timestamp,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Emit a tier revocation event
+pub fn emit_tier_revoked(
+    env: &Env,
+    user: &Address,
+    refund: i128,
+    timestamp: u64,
+) -> Result<(), Error> {
+    env.events().publish(
+        (TIER_REVOKED, symbol_short!("revoked")),
+        (user.clone(), refund, timestamp),
+    );
+
+    Ok(())
+}
+
 /// Emit a user registration event
 pub fn emit_user_registered(env: &Env, profile: &UserProfile) -> Result<(), Error> {
     env.events().publish(
@@ -103,3 +151,45 @@ pub fn emit_user_registered(env: &Env, profile: &UserProfile) -> Result<(), Erro
     );
     Ok(())
 }
+
+/// Emit a greeting creation event
+pub fn emit_greeting_created(env: &Env, greeting: &Greeting) -> Result<(), Error> {
+    env.events().publish(
+        (GREETING_EVENT, symbol_short!("created")),
+        (
+            greeting.id,
+            greeting.creator.clone(),
+            greeting.message.clone(),
+            greeting.timestamp,
+        ),
+    );
+    Ok(())
+}
+
+/// Emit a greeting edit event
+pub fn emit_greeting_edited(env: &Env, greeting: &Greeting) -> Result<(), Error> {
+    env.events().publish(
+        (GREETING_EVENT, symbol_short!("edited")),
+        (
+            greeting.id,
+            greeting.creator.clone(),
+            greeting.message.clone(),
+            greeting.edited_at,
+        ),
+    );
+    Ok(())
+}
+
+/// Emit a greeting deletion event
+pub fn emit_greeting_deleted(
+    env: &Env,
+    greeting_id: u64,
+    creator: &Address,
+    timestamp: u64,
+) -> Result<(), Error> {
+    env.events().publish(
+        (GREETING_EVENT, symbol_short!("deleted")),
+        (greeting_id, creator.clone(), timestamp),
+    );
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{symbol_short, Address, Env, String, Vec};
+
+use crate::{
+    emit_greeting_created, emit_greeting_deleted, emit_greeting_edited, get_current_day,
+    get_current_timestamp, get_daily_greetings, get_greeting_count, is_standard_greeting,
+    load_greeting, next_greeting_id, premium, remove_greeting, save_greeting,
+    set_daily_greetings, validate_greeting_message, verify_user_authorization, Error, Greeting,
+    PremiumFeatures, TierLevel, MAX_LIST_SCAN, MAX_PAGE_SIZE,
+};
+
+pub fn create(env: &Env, creator: &Address, message: &String) -> Result<u64, Error> {
+    verify_user_authorization(env, creator)?;
+    validate_greeting_message(message)?;
+
+    let features = get_features(env, creator)?;
+    check_custom_message(env, &features, message)?;
+
+    let day = get_current_day(env);
+    let sent_today = get_daily_greetings(env, creator, day);
+    if sent_today >= features.max_greetings_per_day {
+        return Err(Error::DailyGreetingLimitReached);
+    }
+    set_daily_greetings(env, creator, day, sent_today + 1);
+
+    let greeting = Greeting {
+        id: next_greeting_id(env),
+        creator: creator.clone(),
+        message: message.clone(),
+        timestamp: get_current_timestamp(env),
+        edited_at: None,
+    };
+    save_greeting(env, &greeting)?;
+
+    emit_greeting_created(env, &greeting)?;
+    Ok(greeting.id)
+}
+
+pub fn edit(env: &Env, creator: &Address, greeting_id: u64, message: &String) -> Result<(), Error> {
+    verify_user_authorization(env, creator)?;
+    validate_greeting_message(message)?;
+
+    let mut greeting = load_owned_greeting(env, creator, greeting_id)?;
+    check_custom_message(env, &get_features(env, creator)?, message)?;
+
+    greeting.message = message.clone();
+    greeting.edited_at = Some(get_current_timestamp(env));
+    save_greeting(env, &greeting)?;
+
+    emit_greeting_edited(env, &greeting)?;
+    Ok(())
+}
+
+/// Delete a greeting together with its likes count and comments
+pub fn delete(env: &Env, creator: &Address, greeting_id: u64) -> Result<(), Error> {
+    verify_user_authorization(env, creator)?;
+    load_owned_greeting(env, creator, greeting_id)?;
+
+    remove_greeting(env, greeting_id);
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("LIKE_CNT"), greeting_id));
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("COMMENTS"), greeting_id));
+
+    emit_greeting_deleted(env, greeting_id, creator, get_current_timestamp(env))?;
+    Ok(())
+}
+
+/// List up to `limit` greetings with an id of at least `start_id`, skipping deleted ones.
+/// At most `MAX_LIST_SCAN` ids are scanned, so a short page does not mean the end.
+pub fn list(env: &Env, start_id: u64, limit: u32) -> Vec<Greeting> {
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let mut greetings = Vec::new(env);
+    let mut greeting_id = start_id.max(1);
+    let last_id = get_greeting_count(env).min(greeting_id.saturating_add(MAX_LIST_SCAN - 1));
+
+    while greeting_id <= last_id && greetings.len() < limit {
+        if let Ok(greeting) = load_greeting(env, greeting_id) {
+            greetings.push_back(greeting);
+        }
+        greeting_id += 1;
+    }
+    greetings
+}
+
+/// Features of the user's tier, or the free tier ones without an active premium tier
+fn get_features(env: &Env, user: &Address) -> Result<PremiumFeatures, Error> {
+    let features = premium::refresh_tier(env, user)?
+        .map(|tier| tier.features)
+        .unwrap_or(TierLevel::None.get_features());
+    Ok(features)
+}
+
+fn check_custom_message(env: &Env, features: &PremiumFeatures, message: &String) -> Result<(), Error> {
+    if !features.custom_greeting_messages && !is_standard_greeting(env, message) {
+        return Err(Error::CustomMessageNotAllowed);
+    }
+    Ok(())
+}
+
+fn load_owned_greeting(env: &Env, creator: &Address, greeting_id: u64) -> Result<Greeting, Error> {
+    let greeting = load_greeting(env, greeting_id)?;
+    if greeting.creator != *creator {
+        return Err(Error::NotGreetingCreator);
+    }
+    Ok(greeting)
+}
@@ -1,10 +1,15 @@
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::{Error, GreetingReward, PremiumFeatures, PremiumTier, TierLevel, UserProfile};
+use crate::{
+    Error, Greeting, GreetingReward, PremiumFeatures, PremiumTier, SocialInteraction, TierLevel,
+    UserProfile,
+};
 
 /// Interface for premium tier management
 pub trait PremiumTierTrait {
-    /// Assign a premium tier to a user based on their contribution
+    /// Buy a premium tier; the contribution is transferred to the treasury.
+    /// The tier follows the contribution thresholds and lasts `MEMBERSHIP_PERIOD`
+    /// for every tier price paid.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -15,7 +20,8 @@ pub trait PremiumTierTrait {
     /// * `Result<(), Error>` - Ok(()) if successful, Error if failed
     fn assign_premium_tier(env: Env, user: Address, contribution: i128) -> Result<(), Error>;
 
-    /// Upgrade a user's premium tier with additional contribution
+    /// Upgrade a user's active tier with additional contribution.
+    /// The unused time of the current membership is credited at its tier price.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -30,6 +36,24 @@ pub trait PremiumTierTrait {
         additional_contribution: i128,
     ) -> Result<(), Error>;
 
+    /// Extend a user's active tier by the time the contribution buys at its price
+    ///
+    /// # Returns
+    /// * `Result<u64, Error>` - The new expiry timestamp
+    fn renew_premium_tier(env: Env, user: Address, contribution: i128) -> Result<u64, Error>;
+
+    /// Revoke a user's active tier, refunding the unused time from the treasury (admin only)
+    ///
+    /// # Returns
+    /// * `Result<i128, Error>` - The refunded amount in Stroops
+    fn revoke_premium_tier(env: Env, admin: Address, user: Address) -> Result<i128, Error>;
+
+    /// Downgrade the user's tier if the membership expired
+    ///
+    /// # Returns
+    /// * `Result<bool, Error>` - Whether the tier is still active
+    fn check_tier_expiry(env: Env, user: Address) -> Result<bool, Error>;
+
     /// Get the premium status of a user
     ///
     /// # Arguments
@@ -91,6 +115,57 @@ pub trait GreetingRewardTrait {
     fn get_greeting_reward(env: Env, greeting_id: u64) -> Option<GreetingReward>;
 }
 
+/// Interface for the greeting lifecycle
+pub trait GreetingTrait {
+    /// Create a greeting, counted against the creator's daily tier limit.
+    /// Custom messages need a tier with `custom_greeting_messages`,
+    /// other users can only send one of the standard greetings.
+    /// Returns the new greeting id.
+    fn create_greeting(env: Env, creator: Address, message: String) -> Result<u64, Error>;
+
+    /// Replace the message of a greeting (creator only)
+    fn edit_greeting(
+        env: Env,
+        creator: Address,
+        greeting_id: u64,
+        message: String,
+    ) -> Result<(), Error>;
+
+    /// Delete a greeting with its likes and comments (creator only)
+    fn delete_greeting(env: Env, creator: Address, greeting_id: u64) -> Result<(), Error>;
+
+    /// Get a greeting by id
+    fn get_greeting(env: Env, greeting_id: u64) -> Result<Greeting, Error>;
+
+    /// List up to `limit` greetings starting at id `start_id`, deleted ones are skipped.
+    /// Only ids below `start_id + MAX_LIST_SCAN` are scanned.
+    fn list_greetings(env: Env, start_id: u64, limit: u32) -> Vec<Greeting>;
+
+    /// Number of greeting ids assigned so far, deleted greetings included
+    fn get_greeting_count(env: Env) -> u64;
+}
+
+/// Interface for likes and comments on greetings
+pub trait SocialTrait {
+    /// Like a greeting, returns the new like count
+    fn like(env: Env, user: Address, greeting_id: u64) -> Result<u64, Error>;
+
+    /// Remove a like, returns the new like count
+    fn unlike(env: Env, user: Address, greeting_id: u64) -> Result<u64, Error>;
+
+    /// Comment on a greeting, returns the new comment count
+    fn comment(env: Env, user: Address, greeting_id: u64, text: String) -> Result<u64, Error>;
+
+    /// Get up to `limit` comments of a greeting starting at `offset`, oldest first
+    fn get_comments(env: Env, greeting_id: u64, offset: u32, limit: u32) -> Vec<SocialInteraction>;
+
+    /// Get the like count of a greeting
+    fn get_like_count(env: Env, greeting_id: u64) -> u64;
+
+    /// Check if a user has liked a greeting
+    fn has_user_liked(env: Env, greeting_id: u64, user: Address) -> bool;
+}
+
 /// Interface for user registration and profiles
 pub trait UserRegistryTrait {
     /// Register a specific user address with name and preferences
@@ -107,6 +182,10 @@ pub trait UserRegistryTrait {
 
 /// Interface for admin/config actions
 pub trait ConfigTrait {
+    /// Set the admin, the SEP-41 token tiers are paid with and the treasury receiving payments.
+    /// Only the deployer recorded at deployment can initialize the contract.
+    fn initialize(env: Env, admin: Address, token: Address, treasury: Address) -> Result<(), Error>;
+
     /// Set the reputation contract address for integration
     fn set_reputation_contract(env: Env, contract: Address) -> Result<(), Error>;
 
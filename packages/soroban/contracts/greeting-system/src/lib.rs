@@ -1,11 +1,14 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
 mod datatype;
 mod error;
 mod events;
+mod greeting;
 mod interface;
+mod premium;
 mod rewards;
+mod social;
 mod storage;
 mod user;
 mod utils;
@@ -14,7 +17,9 @@ pub use datatype::*;
 pub use error::*;
 pub use events::*;
 pub use interface::*;
+pub use premium::MEMBERSHIP_PERIOD;
 pub use rewards::*;
+pub use social::SocialInteraction;
 pub use storage::*;
 pub use utils::*;
 
@@ -23,91 +28,55 @@ pub struct GreetingSystem;
 
 #[contractimpl]
 impl GreetingSystem {
-    /// Assign a premium tier to a user based on their contribution
-    pub fn assign_premium_tier(env: Env, user: Address, contribution: i128) -> Result<(), Error> {
-        verify_user_authorization(&env, &user)?;
-        validate_contribution(contribution)?;
-
-        if has_premium_tier(&env, &user) {
-            return Err(Error::TierAlreadyExists);
-        }
-
-        let tier_level = TierLevel::from_contribution(contribution);
-        let features = tier_level.get_features();
-        let timestamp = get_current_timestamp(&env);
-
-        let premium_tier = PremiumTier {
-            user: user.clone(),
-            tier: tier_level.clone(),
-            contribution,
-            assigned_at: timestamp,
-            features,
-        };
-
-        save_premium_tier(&env, &premium_tier)?;
-
-        let event = TierAssignmentEvent {
-            user: user.clone(),
-            tier: tier_level,
-            contribution,
-            timestamp,
-        };
-        emit_tier_assigned(&env, &event)?;
+    /// Record the deployer, the only account allowed to call `initialize`
+    pub fn __constructor(env: Env, deployer: Address) {
+        save_deployer(&env, &deployer);
+    }
 
-        Ok(())
+    /// Buy a premium tier with a contribution transferred to the treasury
+    pub fn assign_premium_tier(env: Env, user: Address, contribution: i128) -> Result<(), Error> {
+        premium::assign(&env, &user, contribution)
     }
 
-    /// Upgrade a user's premium tier with additional contribution
+    /// Upgrade a user's premium tier with additional contribution, crediting unused time
     pub fn upgrade_premium_tier(
         env: Env,
         user: Address,
         additional_contribution: i128,
     ) -> Result<(), Error> {
-        verify_user_authorization(&env, &user)?;
-        validate_contribution(additional_contribution)?;
-
-        let mut existing_tier = load_premium_tier(&env, &user)?;
-        let new_total_contribution = existing_tier.contribution + additional_contribution;
-        let new_tier_level = TierLevel::from_contribution(new_total_contribution);
-
-        if new_tier_level < existing_tier.tier {
-            return Err(Error::DowngradeNotAllowed);
-        }
-
-        let old_tier = existing_tier.tier.clone();
-        existing_tier.tier = new_tier_level.clone();
-        existing_tier.contribution = new_total_contribution;
-        existing_tier.features = new_tier_level.get_features();
+        premium::upgrade(&env, &user, additional_contribution)
+    }
 
-        save_premium_tier(&env, &existing_tier)?;
+    /// Extend a user's active tier, returns the new expiry
+    pub fn renew_premium_tier(env: Env, user: Address, contribution: i128) -> Result<u64, Error> {
+        premium::renew(&env, &user, contribution)
+    }
 
-        let timestamp = get_current_timestamp(&env);
-        let event = TierUpgradeEvent {
-            user: user.clone(),
-            old_tier,
-            new_tier: new_tier_level,
-            contribution: new_total_contribution,
-            timestamp,
-        };
-        emit_tier_upgraded(&env, &event)?;
+    /// Revoke a user's tier (admin only), refunding unused time. Returns the refund.
+    pub fn revoke_premium_tier(env: Env, admin: Address, user: Address) -> Result<i128, Error> {
+        premium::revoke(&env, &admin, &user)
+    }
 
-        Ok(())
+    /// Downgrade the user's tier if it expired, returns whether it is still active
+    pub fn check_tier_expiry(env: Env, user: Address) -> Result<bool, Error> {
+        let tier = premium::refresh_tier(&env, &user)?.ok_or(Error::TierNotFound)?;
+        Ok(tier.tier != TierLevel::None)
     }
 
     /// Get the premium status of a user
     pub fn get_premium_status(env: Env, user: Address) -> Result<PremiumTier, Error> {
-        load_premium_tier(&env, &user)
+        premium::current_tier(&env, &user)
     }
 
     /// Get features available to a user
     pub fn get_user_features(env: Env, user: Address) -> Result<PremiumFeatures, Error> {
-        let tier = load_premium_tier(&env, &user)?;
+        let tier = premium::current_tier(&env, &user)?;
         Ok(tier.features)
     }
 
     /// Get the tier level of a user
     pub fn get_tier_level(env: Env, user: Address) -> Result<TierLevel, Error> {
-        let tier = load_premium_tier(&env, &user)?;
+        let tier = premium::current_tier(&env, &user)?;
         Ok(tier.tier)
     }
 
@@ -147,6 +116,65 @@ impl GreetingSystem {
     }
 }
 
+#[contractimpl]
+impl crate::GreetingTrait for GreetingSystem {
+    fn create_greeting(env: Env, creator: Address, message: String) -> Result<u64, Error> {
+        greeting::create(&env, &creator, &message)
+    }
+
+    fn edit_greeting(
+        env: Env,
+        creator: Address,
+        greeting_id: u64,
+        message: String,
+    ) -> Result<(), Error> {
+        greeting::edit(&env, &creator, greeting_id, &message)
+    }
+
+    fn delete_greeting(env: Env, creator: Address, greeting_id: u64) -> Result<(), Error> {
+        greeting::delete(&env, &creator, greeting_id)
+    }
+
+    fn get_greeting(env: Env, greeting_id: u64) -> Result<Greeting, Error> {
+        load_greeting(&env, greeting_id)
+    }
+
+    fn list_greetings(env: Env, start_id: u64, limit: u32) -> Vec<Greeting> {
+        greeting::list(&env, start_id, limit)
+    }
+
+    fn get_greeting_count(env: Env) -> u64 {
+        get_greeting_count(&env)
+    }
+}
+
+#[contractimpl]
+impl crate::SocialTrait for GreetingSystem {
+    fn like(env: Env, user: Address, greeting_id: u64) -> Result<u64, Error> {
+        social::like_greeting(env, greeting_id, user)
+    }
+
+    fn unlike(env: Env, user: Address, greeting_id: u64) -> Result<u64, Error> {
+        social::unlike_greeting(env, greeting_id, user)
+    }
+
+    fn comment(env: Env, user: Address, greeting_id: u64, text: String) -> Result<u64, Error> {
+        social::comment_on_greeting(env, greeting_id, user, text)
+    }
+
+    fn get_comments(env: Env, greeting_id: u64, offset: u32, limit: u32) -> Vec<SocialInteraction> {
+        social::get_comments(env, greeting_id, offset, limit)
+    }
+
+    fn get_like_count(env: Env, greeting_id: u64) -> u64 {
+        social::get_like_count(env, greeting_id)
+    }
+
+    fn has_user_liked(env: Env, greeting_id: u64, user: Address) -> bool {
+        social::has_user_liked(env, greeting_id, user)
+    }
+}
+
 #[contractimpl]
 impl crate::UserRegistryTrait for GreetingSystem {
     /// Registers a user with profile details
@@ -167,6 +195,16 @@ impl crate::UserRegistryTrait for GreetingSystem {
 
 #[contractimpl]
 impl crate::ConfigTrait for GreetingSystem {
+    fn initialize(env: Env, admin: Address, token: Address, treasury: Address) -> Result<(), Error> {
+        if is_initialized(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        get_deployer(&env)?.require_auth();
+        admin.require_auth();
+        save_config(&env, &admin, &token, &treasury);
+        Ok(())
+    }
+
     fn set_reputation_contract(env: Env, contract: Address) -> Result<(), Error> {
         // Require admin auth: here we keep it simple and require the contract address itself to auth.
         contract.require_auth();
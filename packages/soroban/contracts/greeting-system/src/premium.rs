@@ -0,0 +1,220 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::{
+    emit_tier_assigned, emit_tier_downgraded, emit_tier_renewed, emit_tier_revoked,
+    emit_tier_upgraded, get_admin, get_current_timestamp, get_payment_token, get_treasury,
+    load_premium_tier, save_premium_tier, validate_contribution, verify_user_authorization, Error,
+    PremiumTier, TierAssignmentEvent, TierLevel, TierUpgradeEvent,
+};
+
+/// Length of the membership bought with one tier price (30 days)
+pub const MEMBERSHIP_PERIOD: u64 = 30 * 24 * 3600;
+
+/// Buy a tier with `contribution` Stroops transferred to the treasury.
+/// The tier follows the contribution thresholds and lasts one period per tier price paid.
+pub fn assign(env: &Env, user: &Address, contribution: i128) -> Result<(), Error> {
+    verify_user_authorization(env, user)?;
+    validate_contribution(contribution)?;
+
+    let previous = refresh_tier(env, user)?;
+    if previous.as_ref().is_some_and(|tier| tier.tier != TierLevel::None) {
+        return Err(Error::TierAlreadyExists);
+    }
+
+    let tier_level = TierLevel::from_contribution(contribution);
+    if tier_level == TierLevel::None {
+        return Err(Error::InvalidTierLevel);
+    }
+
+    let timestamp = get_current_timestamp(env);
+    let expires_at = extend(timestamp, membership_duration(contribution, &tier_level)?)?;
+    let total_contribution = add_contribution(previous.map_or(0, |tier| tier.contribution), contribution)?;
+
+    collect_payment(env, user, contribution)?;
+
+    let premium_tier = PremiumTier {
+        user: user.clone(),
+        tier: tier_level.clone(),
+        contribution: total_contribution,
+        assigned_at: timestamp,
+        expires_at,
+        features: tier_level.get_features(),
+    };
+    save_premium_tier(env, &premium_tier)?;
+
+    let event = TierAssignmentEvent {
+        user: user.clone(),
+        tier: tier_level,
+        contribution,
+        timestamp,
+    };
+    emit_tier_assigned(env, &event)?;
+
+    Ok(())
+}
+
+/// Upgrade an active tier mid-period. The unused time of the current membership is
+/// credited at its tier price and added to `additional_contribution` to pick the new
+/// tier and its expiry.
+pub fn upgrade(env: &Env, user: &Address, additional_contribution: i128) -> Result<(), Error> {
+    verify_user_authorization(env, user)?;
+    validate_contribution(additional_contribution)?;
+
+    let mut existing_tier = load_active_tier(env, user)?;
+    let value = add_contribution(unused_value(env, &existing_tier), additional_contribution)?;
+    let new_tier_level = TierLevel::from_contribution(value);
+
+    if new_tier_level < existing_tier.tier {
+        return Err(Error::DowngradeNotAllowed);
+    }
+
+    let timestamp = get_current_timestamp(env);
+    let expires_at = extend(timestamp, membership_duration(value, &new_tier_level)?)?;
+    let total_contribution = add_contribution(existing_tier.contribution, additional_contribution)?;
+
+    collect_payment(env, user, additional_contribution)?;
+
+    let old_tier = existing_tier.tier.clone();
+    existing_tier.tier = new_tier_level.clone();
+    existing_tier.contribution = total_contribution;
+    existing_tier.expires_at = expires_at;
+    existing_tier.features = new_tier_level.get_features();
+    save_premium_tier(env, &existing_tier)?;
+
+    let event = TierUpgradeEvent {
+        user: user.clone(),
+        old_tier,
+        new_tier: new_tier_level,
+        contribution: existing_tier.contribution,
+        timestamp,
+    };
+    emit_tier_upgraded(env, &event)?;
+
+    Ok(())
+}
+
+/// Extend an active tier by the time `contribution` buys at its price
+pub fn renew(env: &Env, user: &Address, contribution: i128) -> Result<u64, Error> {
+    verify_user_authorization(env, user)?;
+    validate_contribution(contribution)?;
+
+    let mut existing_tier = load_active_tier(env, user)?;
+    let expires_at = extend(existing_tier.expires_at, membership_duration(contribution, &existing_tier.tier)?)?;
+    let total_contribution = add_contribution(existing_tier.contribution, contribution)?;
+
+    collect_payment(env, user, contribution)?;
+
+    existing_tier.contribution = total_contribution;
+    existing_tier.expires_at = expires_at;
+    save_premium_tier(env, &existing_tier)?;
+
+    emit_tier_renewed(env, &existing_tier, contribution, get_current_timestamp(env))?;
+    Ok(existing_tier.expires_at)
+}
+
+/// Revoke an active tier, refunding the unused time from the treasury.
+/// The treasury pays the refund, so it has to authorize the revocation too.
+/// Returns the refunded amount.
+pub fn revoke(env: &Env, admin: &Address, user: &Address) -> Result<i128, Error> {
+    admin.require_auth();
+    if *admin != get_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+    let treasury = get_treasury(env)?;
+    if treasury != *admin {
+        treasury.require_auth();
+    }
+
+    let tier = load_active_tier(env, user)?;
+    let refund = unused_value(env, &tier);
+    if refund > 0 {
+        let token_client = token::Client::new(env, &get_payment_token(env)?);
+        token_client.transfer(&treasury, user, &refund);
+    }
+
+    let timestamp = get_current_timestamp(env);
+    downgrade(env, tier, timestamp)?;
+    emit_tier_revoked(env, user, refund, timestamp)?;
+
+    Ok(refund)
+}
+
+/// Downgrade the user's tier if the membership expired.
+/// Returns the stored tier record, up to date.
+pub fn refresh_tier(env: &Env, user: &Address) -> Result<Option<PremiumTier>, Error> {
+    let tier = match load_premium_tier(env, user) {
+        Ok(tier) => tier,
+        Err(Error::TierNotFound) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    if !is_expired(env, &tier) {
+        return Ok(Some(tier));
+    }
+    let expires_at = tier.expires_at;
+    downgrade(env, tier, expires_at).map(Some)
+}
+
+/// Tier record as it currently applies: an expired membership shows as the free tier
+pub fn current_tier(env: &Env, user: &Address) -> Result<PremiumTier, Error> {
+    let mut tier = load_premium_tier(env, user)?;
+    if is_expired(env, &tier) {
+        tier.tier = TierLevel::None;
+        tier.features = TierLevel::None.get_features();
+    }
+    Ok(tier)
+}
+
+fn load_active_tier(env: &Env, user: &Address) -> Result<PremiumTier, Error> {
+    let tier = current_tier(env, user)?;
+    if tier.tier == TierLevel::None {
+        return Err(Error::TierNotActive);
+    }
+    Ok(tier)
+}
+
+fn is_expired(env: &Env, tier: &PremiumTier) -> bool {
+    tier.tier != TierLevel::None && get_current_timestamp(env) >= tier.expires_at
+}
+
+fn downgrade(env: &Env, mut tier: PremiumTier, timestamp: u64) -> Result<PremiumTier, Error> {
+    let old_tier = tier.tier.clone();
+    tier.tier = TierLevel::None;
+    tier.features = TierLevel::None.get_features();
+    tier.expires_at = timestamp.min(tier.expires_at);
+    save_premium_tier(env, &tier)?;
+
+    emit_tier_downgraded(env, &tier.user, &old_tier, &tier.tier, timestamp)?;
+    Ok(tier)
+}
+
+/// Transfer a payment from the user to the treasury
+fn collect_payment(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+    let token_client = token::Client::new(env, &get_payment_token(env)?);
+    token_client.transfer(user, &get_treasury(env)?, &amount);
+    Ok(())
+}
+
+/// Time bought by `value` Stroops at the tier's price per period
+fn membership_duration(value: i128, tier: &TierLevel) -> Result<u64, Error> {
+    value
+        .checked_mul(MEMBERSHIP_PERIOD as i128)
+        .and_then(|scaled| scaled.checked_div(tier.price()))
+        .and_then(|duration| u64::try_from(duration).ok())
+        .ok_or(Error::InvalidContribution)
+}
+
+/// Move an expiry `duration` seconds later
+fn extend(expires_at: u64, duration: u64) -> Result<u64, Error> {
+    expires_at.checked_add(duration).ok_or(Error::InvalidContribution)
+}
+
+fn add_contribution(total: i128, contribution: i128) -> Result<i128, Error> {
+    total.checked_add(contribution).ok_or(Error::InvalidContribution)
+}
+
+/// Value of the time left on a membership at its tier's price per period
+fn unused_value(env: &Env, tier: &PremiumTier) -> i128 {
+    let remaining = tier.expires_at.saturating_sub(get_current_timestamp(env));
+    tier.tier.price() * remaining as i128 / MEMBERSHIP_PERIOD as i128
+}
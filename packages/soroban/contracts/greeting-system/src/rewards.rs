@@ -1,7 +1,7 @@
 use soroban_sdk::{symbol_short, Address, Env, IntoVal};
 
 use crate::{
-    get_current_timestamp, is_greeting_eligible, is_reward_claimed, load_greeting_reward,
+    get_current_timestamp, has_greeting, is_greeting_eligible, is_reward_claimed, load_greeting_reward,
     mark_reward_claimed, save_greeting_reward, validate_token_amount, Error, GreetingReward,
 };
 
@@ -26,13 +26,6 @@ fn distribute_tokens_via_tipping(
     Ok(())
 }
 
-/// Check if a greeting exists by probing storage key used by social module
-fn greeting_exists(env: &Env, greeting_id: u64) -> bool {
-    env.storage()
-        .persistent()
-        .has(&(symbol_short!("GRT"), greeting_id))
-}
-
 /// Issues tokens for a popular greeting, persists a `GreetingReward`, and emits event.
 pub fn issue_greeting_reward(
     env: Env,
@@ -46,7 +39,7 @@ pub fn issue_greeting_reward(
     creator.require_auth();
 
     // Validate greeting
-    if !greeting_exists(&env, greeting_id) {
+    if !has_greeting(&env, greeting_id) {
         return Err(Error::GreetingNotFound);
     }
     if is_reward_claimed(&env, &greeting_id) {
@@ -91,7 +84,7 @@ pub fn issue_greeting_reward(
 
 /// Verifies if a greeting meets reward criteria based on engagement metrics.
 pub fn check_reward_eligibility(env: Env, greeting_id: u64) -> Result<bool, Error> {
-    if !greeting_exists(&env, greeting_id) {
+    if !has_greeting(&env, greeting_id) {
         return Err(Error::GreetingNotFound);
     }
     Ok(is_greeting_eligible(&env, greeting_id) && !is_reward_claimed(&env, &greeting_id))
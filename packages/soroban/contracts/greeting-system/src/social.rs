@@ -1,6 +1,9 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Vec, vec};
 
-use crate::utils;
+use crate::{has_greeting, utils, Error, MAX_PAGE_SIZE};
+
+/// Maximum number of comments per greeting
+const MAX_COMMENTS: u32 = 100;
 
 /// Represents a social interaction (like or comment) on a greeting
 #[derive(Clone)]
@@ -16,18 +19,18 @@ pub struct SocialInteraction {
 // Like a greeting
 // Prevents duplicate likes from the same user
 // Returns the updated like count
-pub fn like_greeting(env: Env, greeting_id: u64, user: Address) -> u64 {
+pub fn like_greeting(env: Env, greeting_id: u64, user: Address) -> Result<u64, Error> {
     // Require authentication
     user.require_auth();
 
     // Check if greeting exists
-    if !greeting_exists(&env, greeting_id) {
-        panic!("Greeting not found");
+    if !has_greeting(&env, greeting_id) {
+        return Err(Error::GreetingNotFound);
     }
 
     // Check if user has already liked this greeting
     if has_user_liked(env.clone(), greeting_id, user.clone()) {
-        panic!("User has already liked this greeting");
+        return Err(Error::AlreadyLiked);
     }
 
     // Mark user as having liked this greeting
@@ -36,39 +39,67 @@ pub fn like_greeting(env: Env, greeting_id: u64, user: Address) -> u64 {
 
     // Increment like count
     let like_count_key = (symbol_short!("LIKE_CNT"), greeting_id);
-    let mut like_count: u64 = env.storage().persistent().get(&like_count_key).unwrap_or(0);
+    let like_count: u64 = env.storage().persistent().get(&like_count_key).unwrap_or(0);
+    let like_count = like_count.saturating_add(1);
+    env.storage().persistent().set(&like_count_key, &like_count);
+
+    // Emit LikeEvent
+    env.events().publish(
+        (symbol_short!("like"),),
+        (greeting_id, user.clone(), env.ledger().timestamp()),
+    );
+
+    Ok(like_count)
+}
+
+// Remove a like from a greeting
+// Returns the updated like count
+pub fn unlike_greeting(env: Env, greeting_id: u64, user: Address) -> Result<u64, Error> {
+    // Require authentication
+    user.require_auth();
 
-    // Check for integer overflow before incrementing
-    if like_count == u64::MAX {
-        panic!("Maximum number of likes reached");
+    // Check if greeting exists
+    if !has_greeting(&env, greeting_id) {
+        return Err(Error::GreetingNotFound);
+    }
+
+    // Only a previous like can be removed
+    if !has_user_liked(env.clone(), greeting_id, user.clone()) {
+        return Err(Error::NotLiked);
     }
 
-    like_count += 1;
+    let like_tracker_key = (symbol_short!("LIKE_TRK"), greeting_id, user.clone());
+    env.storage().persistent().remove(&like_tracker_key);
+
+    // Decrement like count
+    let like_count_key = (symbol_short!("LIKE_CNT"), greeting_id);
+    let like_count: u64 = env.storage().persistent().get(&like_count_key).unwrap_or(0);
+    let like_count = like_count.saturating_sub(1);
     env.storage().persistent().set(&like_count_key, &like_count);
 
-    // Emit LikeEvent
+    // Emit UnlikeEvent
     env.events().publish(
-        (symbol_short!("like"),),
+        (symbol_short!("unlike"),),
         (greeting_id, user.clone(), env.ledger().timestamp()),
     );
 
-    like_count
+    Ok(like_count)
 }
 
 /// Comment on a greeting
 /// Validates comment text and stores it
 /// Returns the new comment count
-pub fn comment_on_greeting(env: Env, greeting_id: u64, user: Address, text: String) -> u64 {
+pub fn comment_on_greeting(env: Env, greeting_id: u64, user: Address, text: String) -> Result<u64, Error> {
     // Require authentication
     user.require_auth();
 
     // Check if greeting exists
-    if !greeting_exists(&env, greeting_id) {
-        panic!("Greeting not found");
+    if !has_greeting(&env, greeting_id) {
+        return Err(Error::GreetingNotFound);
     }
 
     // Validate comment using utils module
-    utils::validate_comment(&text);
+    utils::validate_comment(&text)?;
 
     // Create the social interaction
     let interaction = SocialInteraction {
@@ -88,8 +119,8 @@ pub fn comment_on_greeting(env: Env, greeting_id: u64, user: Address, text: Stri
         .unwrap_or(vec![&env]);
 
     // Check comment limit (max 100 comments per greeting)
-    if comments.len() >= 100 {
-        panic!("Maximum comment limit reached (100 comments)");
+    if comments.len() >= MAX_COMMENTS {
+        return Err(Error::CommentLimitReached);
     }
 
     // Add the new comment
@@ -104,7 +135,7 @@ pub fn comment_on_greeting(env: Env, greeting_id: u64, user: Address, text: Stri
         (greeting_id, user.clone(), text, env.ledger().timestamp()),
     );
 
-    comments.len() as u64
+    Ok(comments.len() as u64)
 }
 
 /// Get the like count for a greeting
@@ -113,13 +144,18 @@ pub fn get_like_count(env: Env, greeting_id: u64) -> u64 {
     env.storage().persistent().get(&like_count_key).unwrap_or(0)
 }
 
-/// Get all comments for a greeting
-pub fn get_comments(env: Env, greeting_id: u64) -> Vec<SocialInteraction> {
+/// Get a page of comments for a greeting, oldest first
+pub fn get_comments(env: Env, greeting_id: u64, offset: u32, limit: u32) -> Vec<SocialInteraction> {
     let comments_key = (symbol_short!("COMMENTS"), greeting_id);
-    env.storage()
+    let comments: Vec<SocialInteraction> = env
+        .storage()
         .persistent()
         .get(&comments_key)
-        .unwrap_or(vec![&env])
+        .unwrap_or(vec![&env]);
+
+    let start = offset.min(comments.len());
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(comments.len());
+    comments.slice(start..end)
 }
 
 /// Check if a user has liked a greeting
@@ -127,9 +163,3 @@ pub fn has_user_liked(env: Env, greeting_id: u64, user: Address) -> bool {
     let like_tracker_key = (symbol_short!("LIKE_TRK"), greeting_id, user);
     env.storage().persistent().get(&like_tracker_key).unwrap_or(false)
 }
-
-/// Helper function to check if a greeting exists
-fn greeting_exists(env: &Env, greeting_id: u64) -> bool {
-    env.storage().persistent().has(&(symbol_short!("GRT"), greeting_id))
-}
-
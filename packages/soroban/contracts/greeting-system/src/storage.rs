@@ -1,6 +1,12 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
-use crate::{Error, GreetingReward, PremiumTier, UserProfile};
+use crate::{Error, Greeting, GreetingReward, PremiumTier, UserProfile};
+
+/// Greetings are stored under `(GRT, id)`, the key the social and reward modules look up
+pub const GREETING_KEY: Symbol = symbol_short!("GRT");
+
+/// Greeting counter, the last assigned greeting id
+pub const GREETING_COUNT_KEY: Symbol = symbol_short!("GRT_CNT");
 
 /// Storage keys for the premium tier system
 #[contracttype]
@@ -11,6 +17,11 @@ pub enum StorageKey {
     RewardClaimed(u64),
     UserProfile(Address),
     ReputationContract,
+    DailyGreetings(Address, u64), // Greetings created by a user on a given day
+    Admin,
+    PaymentToken,                 // SEP-41 token tiers are paid with
+    Treasury,                     // Receives tier payments
+    Deployer,                     // Set at deployment, initializes the contract
 }
 
 /// Save a premium tier to storage
@@ -100,3 +111,100 @@ pub fn get_reputation_contract(env: &Env) -> Option<Address> {
     let key = StorageKey::ReputationContract;
     env.storage().persistent().get(&key)
 }
+
+/// Save a greeting
+pub fn save_greeting(env: &Env, greeting: &Greeting) -> Result<(), Error> {
+    env.storage().persistent().set(&(GREETING_KEY, greeting.id), greeting);
+    Ok(())
+}
+
+/// Load a greeting by id
+pub fn load_greeting(env: &Env, greeting_id: u64) -> Result<Greeting, Error> {
+    env.storage()
+        .persistent()
+        .get(&(GREETING_KEY, greeting_id))
+        .ok_or(Error::GreetingNotFound)
+}
+
+/// Check if a greeting exists
+pub fn has_greeting(env: &Env, greeting_id: u64) -> bool {
+    env.storage().persistent().has(&(GREETING_KEY, greeting_id))
+}
+
+/// Remove a greeting
+pub fn remove_greeting(env: &Env, greeting_id: u64) {
+    env.storage().persistent().remove(&(GREETING_KEY, greeting_id));
+}
+
+/// Get the last assigned greeting id
+pub fn get_greeting_count(env: &Env) -> u64 {
+    env.storage().persistent().get(&GREETING_COUNT_KEY).unwrap_or(0)
+}
+
+/// Assign the next greeting id
+pub fn next_greeting_id(env: &Env) -> u64 {
+    let id = get_greeting_count(env) + 1;
+    env.storage().persistent().set(&GREETING_COUNT_KEY, &id);
+    id
+}
+
+/// Get the number of greetings a user created on `day`
+pub fn get_daily_greetings(env: &Env, user: &Address, day: u64) -> u32 {
+    let key = StorageKey::DailyGreetings(user.clone(), day);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the number of greetings a user created on `day`
+pub fn set_daily_greetings(env: &Env, user: &Address, day: u64, count: u32) {
+    let key = StorageKey::DailyGreetings(user.clone(), day);
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Check if the contract is initialized
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().persistent().has(&StorageKey::Admin)
+}
+
+/// Save the account that deployed the contract
+pub fn save_deployer(env: &Env, deployer: &Address) {
+    env.storage().instance().set(&StorageKey::Deployer, deployer);
+}
+
+/// Get the account allowed to initialize the contract
+pub fn get_deployer(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::Deployer)
+        .ok_or(Error::Unauthorized)
+}
+
+/// Save the admin, payment token and treasury set at initialization
+pub fn save_config(env: &Env, admin: &Address, token: &Address, treasury: &Address) {
+    env.storage().persistent().set(&StorageKey::Admin, admin);
+    env.storage().persistent().set(&StorageKey::PaymentToken, token);
+    env.storage().persistent().set(&StorageKey::Treasury, treasury);
+}
+
+/// Get the contract admin
+pub fn get_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Get the token premium tiers are paid with
+pub fn get_payment_token(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::PaymentToken)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Get the treasury receiving tier payments
+pub fn get_treasury(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Treasury)
+        .ok_or(Error::NotInitialized)
+}
@@ -1,16 +1,55 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
-
-use crate::{xlm_to_stroops, GreetingSystem, GreetingSystemClient, TierLevel};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{
+    xlm_to_stroops, Error, GreetingSystem, GreetingSystemClient, TierLevel, DAY_IN_SECONDS,
+    MAX_LIST_SCAN, MEMBERSHIP_PERIOD,
+};
+
+struct PremiumTest<'a> {
+    env: Env,
+    client: GreetingSystemClient<'a>,
+    user: Address,
+    admin: Address,
+    treasury: Address,
+    token: token::Client<'a>,
+}
 
-fn create_test_env<'a>() -> (Env, GreetingSystemClient<'a>, Address) {
+/// Initialized contract paid with a test token, the user holds 10,000 XLM
+fn setup_premium<'a>() -> PremiumTest<'a> {
     let env = Env::default();
-    let contract_id = env.register(GreetingSystem, ());
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let contract_id = env.register(GreetingSystem, (deployer,));
     let client = GreetingSystemClient::new(&env, &contract_id);
     let user = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&user, &xlm_to_stroops(10_000));
+    let token = token::Client::new(&env, &sac.address());
+
+    client.initialize(&admin, &sac.address(), &treasury);
+
+    PremiumTest {
+        env,
+        client,
+        user,
+        admin,
+        treasury,
+        token,
+    }
+}
 
-    (env, client, user)
+fn create_test_env<'a>() -> (Env, GreetingSystemClient<'a>, Address) {
+    let test = setup_premium();
+    (test.env, test.client, test.user)
 }
 
 #[test]
@@ -319,3 +358,338 @@ fn test_stress_register_many_users() {
         }
     }
 }
+
+#[test]
+fn test_create_and_get_greeting() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let message = String::from_str(&env, "Hello!");
+    let greeting_id = client.create_greeting(&user, &message);
+    assert_eq!(greeting_id, 1);
+
+    let greeting = client.get_greeting(&greeting_id);
+    assert_eq!(greeting.creator, user);
+    assert_eq!(greeting.message, message);
+    assert_eq!(greeting.edited_at, None);
+}
+
+#[test]
+fn test_custom_message_requires_tier() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let message = String::from_str(&env, "Happy birthday, Ada!");
+    let result = client.try_create_greeting(&user, &message);
+    assert_eq!(result, Err(Ok(Error::CustomMessageNotAllowed)));
+
+    client.assign_premium_tier(&user, &xlm_to_stroops(100));
+    let greeting_id = client.create_greeting(&user, &message);
+    assert_eq!(client.get_greeting(&greeting_id).message, message);
+}
+
+#[test]
+fn test_create_greeting_invalid_message() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let result = client.try_create_greeting(&user, &String::from_str(&env, ""));
+    assert_eq!(result, Err(Ok(Error::InvalidGreetingMessage)));
+}
+
+#[test]
+fn test_daily_greeting_limit() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    // Users without a premium tier can send 10 greetings a day
+    let message = String::from_str(&env, "Welcome!");
+    for _ in 0..10 {
+        client.create_greeting(&user, &message);
+    }
+    let result = client.try_create_greeting(&user, &message);
+    assert_eq!(result, Err(Ok(Error::DailyGreetingLimitReached)));
+
+    // The limit resets the next day
+    env.ledger().with_mut(|li| li.timestamp += DAY_IN_SECONDS);
+    assert_eq!(client.create_greeting(&user, &message), 11);
+}
+
+#[test]
+fn test_edit_greeting() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let greeting_id = client.create_greeting(&user, &String::from_str(&env, "Hello!"));
+    let message = String::from_str(&env, "Good morning!");
+    client.edit_greeting(&user, &greeting_id, &message);
+
+    let greeting = client.get_greeting(&greeting_id);
+    assert_eq!(greeting.message, message);
+    assert_eq!(greeting.edited_at, Some(1_000));
+
+    // Editing to a custom message needs a premium tier too
+    let result = client.try_edit_greeting(&user, &greeting_id, &String::from_str(&env, "Hey you"));
+    assert_eq!(result, Err(Ok(Error::CustomMessageNotAllowed)));
+}
+
+#[test]
+fn test_edit_greeting_not_creator() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let greeting_id = client.create_greeting(&user, &String::from_str(&env, "Hello!"));
+    let other = Address::generate(&env);
+
+    let message = String::from_str(&env, "Welcome!");
+    let result = client.try_edit_greeting(&other, &greeting_id, &message);
+    assert_eq!(result, Err(Ok(Error::NotGreetingCreator)));
+    let result = client.try_delete_greeting(&other, &greeting_id);
+    assert_eq!(result, Err(Ok(Error::NotGreetingCreator)));
+}
+
+#[test]
+fn test_delete_greeting() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let greeting_id = client.create_greeting(&user, &String::from_str(&env, "Hello!"));
+    client.like(&user, &greeting_id);
+    client.delete_greeting(&user, &greeting_id);
+
+    assert_eq!(client.try_get_greeting(&greeting_id).err(), Some(Ok(Error::GreetingNotFound)));
+    assert_eq!(client.get_like_count(&greeting_id), 0);
+    assert_eq!(client.try_like(&user, &greeting_id), Err(Ok(Error::GreetingNotFound)));
+}
+
+#[test]
+fn test_list_greetings_paginated() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let message = String::from_str(&env, "Hello!");
+    for _ in 0..5 {
+        client.create_greeting(&user, &message);
+    }
+    client.delete_greeting(&user, &2);
+
+    let page = client.list_greetings(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(1).unwrap().id, 3);
+
+    let page = client.list_greetings(&4, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().id, 5);
+
+    assert_eq!(client.list_greetings(&6, &10).len(), 0);
+}
+
+#[test]
+fn test_list_greetings_bounds_scanned_ids() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let message = String::from_str(&env, "Hello!");
+    let total = MAX_LIST_SCAN + 5;
+    for sent in 0..total {
+        // Stay within the free tier's daily limit
+        if sent % 10 == 0 {
+            env.ledger().with_mut(|li| li.timestamp += DAY_IN_SECONDS);
+        }
+        client.create_greeting(&user, &message);
+    }
+    for greeting_id in 1..=MAX_LIST_SCAN {
+        client.delete_greeting(&user, &greeting_id);
+    }
+    assert_eq!(client.get_greeting_count(), total);
+
+    // The first window only holds deleted greetings
+    assert_eq!(client.list_greetings(&1, &10).len(), 0);
+
+    let page = client.list_greetings(&(1 + MAX_LIST_SCAN), &10);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().id, MAX_LIST_SCAN + 1);
+}
+
+#[test]
+fn test_like_and_unlike() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let greeting_id = client.create_greeting(&user, &String::from_str(&env, "Hello!"));
+    let fan = Address::generate(&env);
+
+    assert_eq!(client.like(&user, &greeting_id), 1);
+    assert_eq!(client.like(&fan, &greeting_id), 2);
+    assert!(client.has_user_liked(&greeting_id, &fan));
+    assert_eq!(client.try_like(&fan, &greeting_id), Err(Ok(Error::AlreadyLiked)));
+
+    assert_eq!(client.unlike(&fan, &greeting_id), 1);
+    assert!(!client.has_user_liked(&greeting_id, &fan));
+    assert_eq!(client.try_unlike(&fan, &greeting_id), Err(Ok(Error::NotLiked)));
+    assert_eq!(client.get_like_count(&greeting_id), 1);
+}
+
+#[test]
+fn test_comments_paginated() {
+    let (env, client, user) = create_test_env();
+    env.mock_all_auths();
+
+    let greeting_id = client.create_greeting(&user, &String::from_str(&env, "Hello!"));
+    for _ in 0..3 {
+        client.comment(&user, &greeting_id, &String::from_str(&env, "Nice one"));
+    }
+    let last = String::from_str(&env, "Thanks!");
+    assert_eq!(client.comment(&user, &greeting_id, &last), 4);
+
+    let page = client.get_comments(&greeting_id, &2, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().comment_text, last);
+    assert_eq!(client.get_comments(&greeting_id, &10, &10).len(), 0);
+
+    let result = client.try_comment(&user, &greeting_id, &String::from_str(&env, ""));
+    assert_eq!(result, Err(Ok(Error::InvalidComment)));
+}
+
+#[test]
+fn test_initialize_requires_deployer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let client = GreetingSystemClient::new(&env, &env.register(GreetingSystem, (deployer.clone(),)));
+
+    client.initialize(&admin, &Address::generate(&env), &Address::generate(&env));
+    assert_eq!(env.auths()[0].0, deployer);
+    assert_eq!(env.auths()[1].0, admin);
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let test = setup_premium();
+
+    let result = test.client.try_initialize(&test.admin, &test.token.address, &test.treasury);
+    assert_eq!(result, Err(Ok(Error::AlreadyInitialized)));
+}
+
+#[test]
+fn test_assign_tier_pays_treasury() {
+    let test = setup_premium();
+    test.env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(200));
+
+    assert_eq!(test.token.balance(&test.treasury), xlm_to_stroops(200));
+    assert_eq!(test.token.balance(&test.user), xlm_to_stroops(9_800));
+
+    // Twice the Basic price buys two periods
+    let tier = test.client.get_premium_status(&test.user);
+    assert_eq!(tier.tier, TierLevel::Basic);
+    assert_eq!(tier.expires_at, 1_000 + 2 * MEMBERSHIP_PERIOD);
+}
+
+#[test]
+fn test_assign_tier_below_basic_price() {
+    let test = setup_premium();
+
+    let result = test.client.try_assign_premium_tier(&test.user, &xlm_to_stroops(50));
+    assert_eq!(result, Err(Ok(Error::InvalidTierLevel)));
+    assert_eq!(test.token.balance(&test.treasury), 0);
+}
+
+#[test]
+fn test_tier_expires_and_downgrades() {
+    let test = setup_premium();
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(500));
+
+    test.env.ledger().with_mut(|li| li.timestamp += MEMBERSHIP_PERIOD);
+
+    // Expired tiers read as the free tier before anyone downgrades them
+    assert_eq!(test.client.get_tier_level(&test.user), TierLevel::None);
+    assert_eq!(test.client.get_user_features(&test.user).max_greetings_per_day, 10);
+    assert!(!test.client.check_tier_expiry(&test.user));
+
+    let result = test.client.try_create_greeting(&test.user, &String::from_str(&test.env, "Hey you"));
+    assert_eq!(result, Err(Ok(Error::CustomMessageNotAllowed)));
+    let result = test.client.try_renew_premium_tier(&test.user, &xlm_to_stroops(500));
+    assert_eq!(result, Err(Ok(Error::TierNotActive)));
+
+    // The tier can be bought again
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(100));
+    assert_eq!(test.client.get_tier_level(&test.user), TierLevel::Basic);
+    assert_eq!(test.client.get_total_contribution(&test.user), xlm_to_stroops(600));
+}
+
+#[test]
+fn test_oversized_contribution_rejected() {
+    let test = setup_premium();
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(100));
+
+    // The membership time bought overflows
+    let result = test.client.try_renew_premium_tier(&test.user, &i128::MAX);
+    assert_eq!(result, Err(Ok(Error::InvalidContribution)));
+
+    // The time bought fits in u64 but not after the current expiry
+    let result = test.client.try_renew_premium_tier(&test.user, &(xlm_to_stroops(100) * (u64::MAX / MEMBERSHIP_PERIOD) as i128));
+    assert_eq!(result, Err(Ok(Error::InvalidContribution)));
+
+    let result = test.client.try_upgrade_premium_tier(&test.user, &i128::MAX);
+    assert_eq!(result, Err(Ok(Error::InvalidContribution)));
+}
+
+#[test]
+fn test_renew_tier() {
+    let test = setup_premium();
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(100));
+    let expires_at = test.client.get_premium_status(&test.user).expires_at;
+
+    test.env.ledger().with_mut(|li| li.timestamp += MEMBERSHIP_PERIOD / 2);
+    let renewed_until = test.client.renew_premium_tier(&test.user, &xlm_to_stroops(50));
+
+    assert_eq!(renewed_until, expires_at + MEMBERSHIP_PERIOD / 2);
+    assert_eq!(test.client.get_premium_status(&test.user).expires_at, renewed_until);
+    assert_eq!(test.token.balance(&test.treasury), xlm_to_stroops(150));
+}
+
+#[test]
+fn test_upgrade_tier_prorated() {
+    let test = setup_premium();
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(100));
+
+    // Half of the Basic period is left, worth 50 XLM
+    test.env.ledger().with_mut(|li| li.timestamp += MEMBERSHIP_PERIOD / 2);
+    let now = test.env.ledger().timestamp();
+    test.client.upgrade_premium_tier(&test.user, &xlm_to_stroops(450));
+
+    let tier = test.client.get_premium_status(&test.user);
+    assert_eq!(tier.tier, TierLevel::Pro);
+    assert_eq!(tier.expires_at, now + MEMBERSHIP_PERIOD);
+    assert_eq!(tier.contribution, xlm_to_stroops(550));
+    assert_eq!(test.token.balance(&test.treasury), xlm_to_stroops(550));
+}
+
+#[test]
+fn test_revoke_tier_refunds_unused_time() {
+    let test = setup_premium();
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(500));
+
+    test.env.ledger().with_mut(|li| li.timestamp += MEMBERSHIP_PERIOD / 5);
+    let refund = test.client.revoke_premium_tier(&test.admin, &test.user);
+
+    assert_eq!(refund, xlm_to_stroops(400));
+    assert_eq!(test.token.balance(&test.user), xlm_to_stroops(9_900));
+    assert_eq!(test.token.balance(&test.treasury), xlm_to_stroops(100));
+    assert_eq!(test.client.get_tier_level(&test.user), TierLevel::None);
+}
+
+#[test]
+fn test_revoke_tier_not_admin() {
+    let test = setup_premium();
+    test.client.assign_premium_tier(&test.user, &xlm_to_stroops(500));
+
+    let result = test.client.try_revoke_premium_tier(&test.user, &test.user);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
@@ -1,6 +1,15 @@
 use soroban_sdk::{Address, Env, String};
 
-use crate::Error;
+use crate::{Error, STANDARD_GREETINGS};
+
+/// Seconds in a day, the period of the daily greeting limit
+pub const DAY_IN_SECONDS: u64 = 86_400;
+
+/// Maximum number of items returned by a paginated query
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of greeting ids, deleted ones included, scanned by `list_greetings`
+pub const MAX_LIST_SCAN: u64 = 2 * MAX_PAGE_SIZE as u64;
 
 /// Validate contribution amount
 /// Ensures the contribution is positive and non-zero
@@ -78,6 +87,36 @@ pub fn validate_preferences(preferences: &String) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validate greeting message length (1-1000 characters)
+pub fn validate_greeting_message(message: &String) -> Result<(), Error> {
+    let len = message.len();
+    if len == 0 || len > 1000 {
+        return Err(Error::InvalidGreetingMessage);
+    }
+    Ok(())
+}
+
+/// Validate comment length (1-500 characters)
+pub fn validate_comment(text: &String) -> Result<(), Error> {
+    let len = text.len();
+    if len == 0 || len > 500 {
+        return Err(Error::InvalidComment);
+    }
+    Ok(())
+}
+
+/// Check whether a message is one of the standard greetings
+pub fn is_standard_greeting(env: &Env, message: &String) -> bool {
+    STANDARD_GREETINGS
+        .iter()
+        .any(|standard| *message == String::from_str(env, standard))
+}
+
+/// Get the current day number, used to bucket the daily greeting limit
+pub fn get_current_day(env: &Env) -> u64 {
+    env.ledger().timestamp() / DAY_IN_SECONDS
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
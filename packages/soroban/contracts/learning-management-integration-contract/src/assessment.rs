@@ -0,0 +1,232 @@
+use crate::course;
+use crate::error::ContractError;
+use crate::events::*;
+use crate::lms;
+use crate::storage::{
+    self, Assessment, AssessmentAttempt, CourseModule, LearningProgress, ModuleCompletion,
+};
+use soroban_sdk::{Address, Env, Vec};
+
+// Validate module and assessment definitions
+fn validate_modules(env: &Env, modules: &Vec<CourseModule>) -> Result<(), ContractError> {
+    if modules.is_empty() {
+        return Err(ContractError::InvalidInput);
+    }
+
+    let mut module_ids: Vec<u32> = Vec::new(env);
+    let mut assessment_ids: Vec<u32> = Vec::new(env);
+    for module in modules.iter() {
+        if module_ids.contains(module.module_id) {
+            return Err(ContractError::InvalidInput);
+        }
+        module_ids.push_back(module.module_id);
+
+        for assessment in module.assessments.iter() {
+            if assessment_ids.contains(assessment.assessment_id)
+                || assessment.weight == 0
+                || assessment.pass_threshold > 100
+                || assessment.max_attempts == 0
+            {
+                return Err(ContractError::InvalidInput);
+            }
+            assessment_ids.push_back(assessment.assessment_id);
+        }
+    }
+
+    // The final grade needs at least one graded assessment
+    if assessment_ids.is_empty() {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+// Find an assessment in a course's modules
+fn find_assessment(
+    modules: &Vec<CourseModule>,
+    assessment_id: u32,
+) -> Result<Assessment, ContractError> {
+    for module in modules.iter() {
+        for assessment in module.assessments.iter() {
+            if assessment.assessment_id == assessment_id {
+                return Ok(assessment);
+            }
+        }
+    }
+    Err(ContractError::AssessmentNotFound)
+}
+
+// Load a progress entry of a course with modules, checking that the caller owns the course
+fn load_progress(
+    env: &Env,
+    platform: &Address,
+    token_id: u64,
+) -> Result<(LearningProgress, Vec<CourseModule>), ContractError> {
+    lms::require_platform(env, platform)?;
+
+    let progress = storage::get_progress(env, token_id).ok_or(ContractError::ProgressNotFound)?;
+    course::require_course_owner(env, progress.course_id, platform)?;
+
+    let modules = storage::get_course_modules(env, progress.course_id);
+    if modules.is_empty() {
+        return Err(ContractError::ModuleNotFound);
+    }
+    if progress.nft_issued {
+        return Err(ContractError::NFTAlreadyIssued);
+    }
+    Ok((progress, modules))
+}
+
+/// Check whether a course's completion and grade are derived from modules and assessments
+pub fn has_modules(env: &Env, course_id: u64) -> bool {
+    !storage::get_course_modules(env, course_id).is_empty()
+}
+
+/// Define a registered course's modules and assessments (owner platform only).
+/// The structure is fixed once the first user enrolls.
+pub fn set_course_modules(
+    env: &Env,
+    platform: &Address,
+    course_id: u64,
+    modules: Vec<CourseModule>,
+) -> Result<(), ContractError> {
+    lms::require_platform(env, platform)?;
+    course::require_course_owner(env, course_id, platform)?;
+
+    // Modules are fixed once anyone has progress in the course, including progress
+    // created before the course was registered
+    if storage::get_course_progress_count(env, course_id) > 0 {
+        return Err(ContractError::OperationNotAllowed);
+    }
+    validate_modules(env, &modules)?;
+
+    storage::set_course_modules(env, course_id, &modules);
+    Ok(())
+}
+
+/// Get a course's modules and assessments
+pub fn get_course_modules(env: &Env, course_id: u64) -> Vec<CourseModule> {
+    storage::get_course_modules(env, course_id)
+}
+
+/// Record a user's score on an assessment and return whether it passed
+pub fn record_assessment_attempt(
+    env: &Env,
+    platform: &Address,
+    token_id: u64,
+    assessment_id: u32,
+    score: u32,
+) -> Result<bool, ContractError> {
+    if score > 100 {
+        return Err(ContractError::InvalidInput);
+    }
+    let (progress, modules) = load_progress(env, platform, token_id)?;
+    let assessment = find_assessment(&modules, assessment_id)?;
+
+    let mut attempts = storage::get_assessment_attempts(env, token_id, assessment_id);
+    if attempts.len() >= assessment.max_attempts {
+        return Err(ContractError::AttemptLimitReached);
+    }
+
+    let passed = score >= assessment.pass_threshold;
+    attempts.push_back(AssessmentAttempt {
+        score,
+        passed,
+        submitted_at: env.ledger().timestamp(),
+    });
+    storage::set_assessment_attempts(env, token_id, assessment_id, &attempts);
+
+    emit_assessment_attempted(
+        env,
+        token_id,
+        progress.user,
+        assessment_id,
+        score,
+        attempts.len(),
+        passed,
+    );
+
+    Ok(passed)
+}
+
+/// Get every attempt a user made on an assessment
+pub fn get_assessment_attempts(env: &Env, token_id: u64, assessment_id: u32) -> Vec<AssessmentAttempt> {
+    storage::get_assessment_attempts(env, token_id, assessment_id)
+}
+
+/// Mark a module as completed once all of its assessments are passed, and
+/// recompute the overall completion percentage
+pub fn complete_module(
+    env: &Env,
+    platform: &Address,
+    token_id: u64,
+    module_id: u32,
+) -> Result<(), ContractError> {
+    let (mut progress, modules) = load_progress(env, platform, token_id)?;
+
+    let module = modules
+        .iter()
+        .find(|module| module.module_id == module_id)
+        .ok_or(ContractError::ModuleNotFound)?;
+
+    let mut completions = storage::get_module_completions(env, token_id);
+    if completions.iter().any(|completion| completion.module_id == module_id) {
+        return Err(ContractError::ModuleAlreadyCompleted);
+    }
+
+    for assessment in module.assessments.iter() {
+        let attempts = storage::get_assessment_attempts(env, token_id, assessment.assessment_id);
+        if !attempts.iter().any(|attempt| attempt.passed) {
+            return Err(ContractError::AssessmentsNotPassed);
+        }
+    }
+
+    let timestamp = env.ledger().timestamp();
+    completions.push_back(ModuleCompletion {
+        module_id,
+        completed_at: timestamp,
+    });
+    storage::set_module_completions(env, token_id, &completions);
+
+    progress.completion_status = completions.len() * 100 / modules.len();
+    progress.updated_at = timestamp;
+    storage::set_progress(env, &progress);
+
+    emit_module_completed(env, token_id, progress.user.clone(), module_id);
+    emit_progress_updated(
+        env,
+        token_id,
+        progress.user.clone(),
+        progress.course_id,
+        progress.completion_status,
+    );
+
+    Ok(())
+}
+
+/// Get the modules a user has completed
+pub fn get_module_completions(env: &Env, token_id: u64) -> Vec<ModuleCompletion> {
+    storage::get_module_completions(env, token_id)
+}
+
+/// Weighted average of the best score on each assessment; unattempted assessments count as 0
+pub fn derive_grade(env: &Env, progress: &LearningProgress) -> u32 {
+    let mut weighted_total: u64 = 0;
+    let mut total_weight: u64 = 0;
+
+    for module in storage::get_course_modules(env, progress.course_id).iter() {
+        for assessment in module.assessments.iter() {
+            let best_score = storage::get_assessment_attempts(env, progress.token_id, assessment.assessment_id)
+                .iter()
+                .map(|attempt| attempt.score)
+                .max()
+                .unwrap_or(0);
+            weighted_total += best_score as u64 * assessment.weight as u64;
+            total_weight += assessment.weight as u64;
+        }
+    }
+
+    if total_weight == 0 {
+        return 0;
+    }
+    (weighted_total / total_weight) as u32
+}
@@ -0,0 +1,80 @@
+use crate::error::ContractError;
+use crate::storage::{self, LearningProgress};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// Metadata carried by a course-completion NFT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseCertificate {
+    pub course_id: u64,
+    pub learner: Address,       // Learner who completed the course, kept across transfers
+    pub progress_token_id: u64, // Learning progress entry in the issuing LMS contract
+    pub platform: Address,      // Platform that issued the certificate
+    pub grade: u32,             // Final grade (0-100)
+    pub completed_at: u64,
+}
+
+/// Most certificates of one course checked for a user when verifying a prerequisite
+pub const MAX_CERTIFICATE_SCAN: u32 = 20;
+
+/// Course certificate interface of the `educational-nft-contract` NFT contract
+#[contractclient(name = "CourseNftClient")]
+pub trait CourseNftInterface {
+    /// Mint a certificate to `to`; `minter` is the calling LMS contract
+    fn mint_course_certificate(minter: Address, to: Address, metadata: CourseCertificate) -> u64;
+    fn course_tokens_of(owner: Address, course_id: u64) -> Vec<u64>;
+    fn get_certificate(token_id: u64) -> CourseCertificate;
+    fn minter_of(token_id: u64) -> Address;
+}
+
+/// Mint the completion NFT for a finished course and return its token ID
+pub fn mint_certificate(
+    env: &Env,
+    progress: &LearningProgress,
+    platform: &Address,
+    grade: u32,
+) -> Result<u64, ContractError> {
+    let nft_contract = storage::get_nft_contract(env).ok_or(ContractError::NFTContractNotSet)?;
+
+    let metadata = CourseCertificate {
+        course_id: progress.course_id,
+        learner: progress.user.clone(),
+        progress_token_id: progress.token_id,
+        platform: platform.clone(),
+        grade,
+        completed_at: env.ledger().timestamp(),
+    };
+
+    let client = CourseNftClient::new(env, &nft_contract);
+    Ok(client.mint_course_certificate(&env.current_contract_address(), &progress.user, &metadata))
+}
+
+/// Check whether the user holds a completion NFT for the course, with at least `min_grade`,
+/// issued by any registered platform. Only certificates minted by this contract or by a
+/// trusted LMS contract count, since the minter supplies the certificate metadata, and
+/// only for their learner: a certificate transferred to someone else does not count.
+pub fn holds_certificate(env: &Env, user: &Address, course_id: u64, min_grade: u32) -> bool {
+    let nft_contract = match storage::get_nft_contract(env) {
+        Some(address) => address,
+        None => return false,
+    };
+
+    let client = CourseNftClient::new(env, &nft_contract);
+    let token_ids = client.course_tokens_of(user, &course_id);
+    for token_id in token_ids.iter().take(MAX_CERTIFICATE_SCAN as usize) {
+        let minter = client.minter_of(&token_id);
+        if minter != env.current_contract_address() && !storage::is_trusted_issuer(env, &minter) {
+            continue;
+        }
+
+        let certificate = client.get_certificate(&token_id);
+        if certificate.course_id == course_id
+            && certificate.learner == *user
+            && certificate.grade >= min_grade
+            && storage::is_platform(env, &certificate.platform)
+        {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,132 @@
+use crate::error::ContractError;
+use crate::events::*;
+use crate::lms;
+use crate::storage::{self, Course, CourseDetails};
+use soroban_sdk::{Address, Env, Vec};
+
+// Validate course settings
+fn validate_details(details: &CourseDetails) -> Result<(), ContractError> {
+    if details.title.is_empty() || details.capacity == 0 {
+        return Err(ContractError::InvalidInput);
+    }
+    if details.enrollment_opens >= details.enrollment_closes {
+        return Err(ContractError::InvalidInput);
+    }
+    if details.required_completion == 0 || details.required_completion > 100 {
+        return Err(ContractError::InvalidCompletionStatus);
+    }
+    Ok(())
+}
+
+/// Load a course and check that the caller is its owner platform
+pub(crate) fn require_course_owner(
+    env: &Env,
+    course_id: u64,
+    platform: &Address,
+) -> Result<Course, ContractError> {
+    let course = storage::get_course(env, course_id).ok_or(ContractError::CourseNotFound)?;
+    if course.platform != *platform {
+        return Err(ContractError::NotCourseOwner);
+    }
+    Ok(course)
+}
+
+/// Register a course owned by the calling platform
+pub fn register_course(
+    env: &Env,
+    platform: &Address,
+    course_id: u64,
+    details: CourseDetails,
+) -> Result<(), ContractError> {
+    if course_id == 0 {
+        return Err(ContractError::InvalidCourseId);
+    }
+    lms::require_platform(env, platform)?;
+    validate_details(&details)?;
+
+    if storage::get_course(env, course_id).is_some() {
+        return Err(ContractError::CourseAlreadyExists);
+    }
+
+    let course = Course {
+        course_id,
+        platform: platform.clone(),
+        details,
+        enrolled: 0,
+        created_at: env.ledger().timestamp(),
+    };
+    storage::set_course(env, &course);
+
+    emit_course_registered(env, course_id, platform.clone(), course.details.capacity);
+
+    Ok(())
+}
+
+/// Update a course's settings (owner platform only)
+pub fn update_course(
+    env: &Env,
+    platform: &Address,
+    course_id: u64,
+    details: CourseDetails,
+) -> Result<(), ContractError> {
+    lms::require_platform(env, platform)?;
+    validate_details(&details)?;
+
+    let mut course = require_course_owner(env, course_id, platform)?;
+
+    // Existing enrollments must still fit
+    if details.capacity < course.enrolled {
+        return Err(ContractError::InvalidInput);
+    }
+
+    course.details = details;
+    storage::set_course(env, &course);
+
+    Ok(())
+}
+
+/// Get a course from the catalog
+pub fn get_course(env: &Env, course_id: u64) -> Result<Course, ContractError> {
+    storage::get_course(env, course_id).ok_or(ContractError::CourseNotFound)
+}
+
+/// Enroll a user in a course after checking the enrollment window, capacity and prerequisites
+pub fn enroll(
+    env: &Env,
+    platform: &Address,
+    user: &Address,
+    course_id: u64,
+) -> Result<u64, ContractError> {
+    lms::require_platform(env, platform)?;
+    let mut course = require_course_owner(env, course_id, platform)?;
+
+    let now = env.ledger().timestamp();
+    if now < course.details.enrollment_opens || now > course.details.enrollment_closes {
+        return Err(ContractError::EnrollmentClosed);
+    }
+    if course.enrolled >= course.details.capacity {
+        return Err(ContractError::CourseFull);
+    }
+    if !lms::verify_prerequisites(env, user, course_id)? {
+        return Err(ContractError::PrerequisiteNotMet);
+    }
+
+    // Record every course named in the prerequisite expression on the progress entry
+    let mut prerequisites = Vec::new(env);
+    for group in storage::get_course_prerequisites(env, course_id).iter() {
+        for condition in group.any_of.iter() {
+            if !prerequisites.contains(condition.course_id) {
+                prerequisites.push_back(condition.course_id);
+            }
+        }
+    }
+
+    let token_id = lms::create_progress(env, user, course_id, prerequisites)?;
+
+    course.enrolled += 1;
+    storage::set_course(env, &course);
+
+    emit_user_enrolled(env, token_id, user.clone(), course_id);
+
+    Ok(token_id)
+}
@@ -39,4 +39,28 @@ pub enum ContractError {
     // General errors
     OperationNotAllowed = 21,
     StorageError = 22,
+
+    // Signed receipt errors
+    PlatformKeyNotSet = 23,
+    ReceiptExpired = 24,
+    ReceiptAlreadyUsed = 25,
+
+    // Certificate errors
+    NFTContractNotSet = 26,
+    InvalidGrade = 27,
+
+    // Course catalog errors
+    CourseNotFound = 28,
+    CourseAlreadyExists = 29,
+    NotCourseOwner = 30,
+    EnrollmentClosed = 31,
+    CourseFull = 32,
+    PrerequisiteCycle = 33,
+
+    // Module and assessment errors
+    ModuleNotFound = 34,
+    AssessmentNotFound = 35,
+    AttemptLimitReached = 36,
+    AssessmentsNotPassed = 37,
+    ModuleAlreadyCompleted = 38,
 }
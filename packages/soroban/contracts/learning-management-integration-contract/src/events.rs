@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,6 +7,8 @@ pub struct CourseNFTIssuedEvent {
     pub user: Address,
     pub course_id: u64,
     pub platform: Address,
+    pub certificate_id: u64,
+    pub grade: u32,
     pub issued_at: u64,
 }
 
@@ -46,6 +48,53 @@ pub struct PlatformRemovedEvent {
     pub removed_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformKeySetEvent {
+    pub platform: Address,
+    pub public_key: BytesN<32>,
+    pub set_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseRegisteredEvent {
+    pub course_id: u64,
+    pub platform: Address,
+    pub capacity: u32,
+    pub registered_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserEnrolledEvent {
+    pub token_id: u64,
+    pub user: Address,
+    pub course_id: u64,
+    pub enrolled_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssessmentAttemptedEvent {
+    pub token_id: u64,
+    pub user: Address,
+    pub assessment_id: u32,
+    pub score: u32,
+    pub attempt: u32,
+    pub passed: bool,
+    pub submitted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleCompletedEvent {
+    pub token_id: u64,
+    pub user: Address,
+    pub module_id: u32,
+    pub completed_at: u64,
+}
+
 // Event emission functions
 pub fn emit_course_nft_issued(
     env: &Env,
@@ -53,12 +102,16 @@ pub fn emit_course_nft_issued(
     user: Address,
     course_id: u64,
     platform: Address,
+    certificate_id: u64,
+    grade: u32,
 ) {
     let event = CourseNFTIssuedEvent {
         token_id,
         user,
         course_id,
         platform,
+        certificate_id,
+        grade,
         issued_at: env.ledger().timestamp(),
     };
     env.events().publish(("course_nft_issued",), event);
@@ -115,3 +168,63 @@ pub fn emit_platform_removed(env: &Env, platform: Address, admin: Address) {
     };
     env.events().publish(("platform_removed",), event);
 }
+
+pub fn emit_platform_key_set(env: &Env, platform: Address, public_key: BytesN<32>) {
+    let event = PlatformKeySetEvent {
+        platform,
+        public_key,
+        set_at: env.ledger().timestamp(),
+    };
+    env.events().publish(("platform_key_set",), event);
+}
+
+pub fn emit_course_registered(env: &Env, course_id: u64, platform: Address, capacity: u32) {
+    let event = CourseRegisteredEvent {
+        course_id,
+        platform,
+        capacity,
+        registered_at: env.ledger().timestamp(),
+    };
+    env.events().publish(("course_registered",), event);
+}
+
+pub fn emit_user_enrolled(env: &Env, token_id: u64, user: Address, course_id: u64) {
+    let event = UserEnrolledEvent {
+        token_id,
+        user,
+        course_id,
+        enrolled_at: env.ledger().timestamp(),
+    };
+    env.events().publish(("user_enrolled",), event);
+}
+
+pub fn emit_assessment_attempted(
+    env: &Env,
+    token_id: u64,
+    user: Address,
+    assessment_id: u32,
+    score: u32,
+    attempt: u32,
+    passed: bool,
+) {
+    let event = AssessmentAttemptedEvent {
+        token_id,
+        user,
+        assessment_id,
+        score,
+        attempt,
+        passed,
+        submitted_at: env.ledger().timestamp(),
+    };
+    env.events().publish(("assessment_attempted",), event);
+}
+
+pub fn emit_module_completed(env: &Env, token_id: u64, user: Address, module_id: u32) {
+    let event = ModuleCompletedEvent {
+        token_id,
+        user,
+        module_id,
+        completed_at: env.ledger().timestamp(),
+    };
+    env.events().publish(("module_completed",), event);
+}
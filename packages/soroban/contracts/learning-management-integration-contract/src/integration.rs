@@ -1,4 +1,5 @@
 use crate::error::ContractError;
+use crate::lms;
 use crate::storage;
 use soroban_sdk::{contracttype, Env};
 
@@ -26,9 +27,7 @@ pub fn link_progress_with_milestone(
     platform: &soroban_sdk::Address,
 ) -> Result<(), ContractError> {
     // Verify platform authorization
-    if !storage::is_platform(env, platform) {
-        return Err(ContractError::NotAuthorizedPlatform);
-    }
+    lms::require_platform(env, platform)?;
 
     // Verify progress exists
     let _progress = storage::get_progress(env, token_id)
@@ -57,9 +56,7 @@ pub fn notify_milestone_completion(
     platform: &soroban_sdk::Address,
 ) -> Result<(), ContractError> {
     // Verify platform authorization
-    if !storage::is_platform(env, platform) {
-        return Err(ContractError::NotAuthorizedPlatform);
-    }
+    lms::require_platform(env, platform)?;
 
     // Get existing milestone info
     let key = IntegrationKey::MilestoneLink(token_id);
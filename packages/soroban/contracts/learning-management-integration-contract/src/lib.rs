@@ -5,15 +5,22 @@ mod events;
 mod storage;
 mod lms;
 mod integration;
+mod certificate;
+mod course;
+mod assessment;
 
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 
 pub use error::*;
 pub use events::*;
-pub use storage::LearningProgress;
+pub use storage::{
+    Assessment, AssessmentAttempt, Course, CourseDetails, CourseModule, LearningProgress,
+    ModuleCompletion, PrerequisiteCondition, PrerequisiteGroup, ProgressReceipt,
+};
+pub use certificate::{CourseCertificate, CourseNftClient, CourseNftInterface};
 
 #[contract]
 pub struct LearningManagementContract;
@@ -38,10 +45,100 @@ impl LearningManagementContract {
         course_id: u64,
         prerequisites: Vec<u64>,
     ) -> Result<u64, ContractError> {
-        platform.require_auth();
         lms::initialize_progress(&env, &user, course_id, prerequisites, &platform)
     }
 
+    /// Register a course owned by the calling platform
+    pub fn register_course(
+        env: Env,
+        platform: Address,
+        course_id: u64,
+        details: CourseDetails,
+    ) -> Result<(), ContractError> {
+        course::register_course(&env, &platform, course_id, details)
+    }
+
+    /// Update a course's settings (owner platform only)
+    pub fn update_course(
+        env: Env,
+        platform: Address,
+        course_id: u64,
+        details: CourseDetails,
+    ) -> Result<(), ContractError> {
+        course::update_course(&env, &platform, course_id, details)
+    }
+
+    /// Get a course from the catalog
+    pub fn get_course(env: Env, course_id: u64) -> Result<Course, ContractError> {
+        course::get_course(&env, course_id)
+    }
+
+    /// Enroll a user in a registered course and create their learning progress
+    pub fn enroll(
+        env: Env,
+        platform: Address,
+        user: Address,
+        course_id: u64,
+    ) -> Result<u64, ContractError> {
+        course::enroll(&env, &platform, &user, course_id)
+    }
+
+    /// Define a course's modules and assessments (owner platform, before enrollment)
+    pub fn set_course_modules(
+        env: Env,
+        platform: Address,
+        course_id: u64,
+        modules: Vec<CourseModule>,
+    ) -> Result<(), ContractError> {
+        assessment::set_course_modules(&env, &platform, course_id, modules)
+    }
+
+    /// Get a course's modules and assessments
+    pub fn get_course_modules(env: Env, course_id: u64) -> Vec<CourseModule> {
+        assessment::get_course_modules(&env, course_id)
+    }
+
+    /// Record an assessment score and return whether it passed
+    pub fn record_assessment_attempt(
+        env: Env,
+        platform: Address,
+        token_id: u64,
+        assessment_id: u32,
+        score: u32,
+    ) -> Result<bool, ContractError> {
+        assessment::record_assessment_attempt(&env, &platform, token_id, assessment_id, score)
+    }
+
+    /// Get a user's attempts on an assessment
+    pub fn get_assessment_attempts(
+        env: Env,
+        token_id: u64,
+        assessment_id: u32,
+    ) -> Vec<AssessmentAttempt> {
+        assessment::get_assessment_attempts(&env, token_id, assessment_id)
+    }
+
+    /// Complete a module whose assessments are all passed
+    pub fn complete_module(
+        env: Env,
+        platform: Address,
+        token_id: u64,
+        module_id: u32,
+    ) -> Result<(), ContractError> {
+        assessment::complete_module(&env, &platform, token_id, module_id)
+    }
+
+    /// Get the modules a user has completed
+    pub fn get_module_completions(env: Env, token_id: u64) -> Vec<ModuleCompletion> {
+        assessment::get_module_completions(&env, token_id)
+    }
+
+    /// Get the grade derived so far from a user's assessment scores
+    pub fn get_current_grade(env: Env, token_id: u64) -> Result<u32, ContractError> {
+        let progress = lms::get_progress(&env, token_id)?;
+        Ok(assessment::derive_grade(&env, &progress))
+    }
+
     /// Update learning progress for a user
     pub fn update_progress(
         env: Env,
@@ -49,10 +146,19 @@ impl LearningManagementContract {
         token_id: u64,
         completion_status: u32,
     ) -> Result<(), ContractError> {
-        platform.require_auth();
         lms::update_progress(&env, token_id, completion_status, &platform)
     }
 
+    /// Submit a completion receipt signed by the platform's registered key.
+    /// Can be called by any relayer; the signature authorizes the update.
+    pub fn submit_signed_progress(
+        env: Env,
+        receipt: ProgressReceipt,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        lms::submit_signed_progress(&env, receipt, signature)
+    }
+
     /// Verify if user meets all prerequisites for a course
     pub fn verify_prerequisites(
         env: Env,
@@ -62,14 +168,15 @@ impl LearningManagementContract {
         lms::verify_prerequisites(&env, &user, course_id)
     }
 
-    /// Issue NFT upon course completion
+    /// Mint the course-completion NFT and return its token ID. The grade is derived
+    /// on-chain for courses with modules and must be given for other courses.
     pub fn issue_course_nft(
         env: Env,
         platform: Address,
         token_id: u64,
-    ) -> Result<(), ContractError> {
-        platform.require_auth();
-        lms::issue_course_nft(&env, token_id, &platform)
+        grade: Option<u32>,
+    ) -> Result<u64, ContractError> {
+        lms::issue_course_nft(&env, token_id, grade, &platform)
     }
 
     /// Get learning progress by token ID
@@ -103,10 +210,25 @@ impl LearningManagementContract {
         course_id: u64,
         prerequisites: Vec<u64>,
     ) -> Result<(), ContractError> {
-        platform.require_auth();
         lms::set_course_prerequisites(&env, course_id, prerequisites, &platform)
     }
 
+    /// Set a course's prerequisite expression: every group must be satisfied,
+    /// and a group is satisfied by any one of its conditions
+    pub fn set_prerequisite_groups(
+        env: Env,
+        platform: Address,
+        course_id: u64,
+        groups: Vec<PrerequisiteGroup>,
+    ) -> Result<(), ContractError> {
+        lms::set_prerequisite_groups(&env, course_id, groups, &platform)
+    }
+
+    /// Get a course's prerequisite expression
+    pub fn get_prerequisite_groups(env: Env, course_id: u64) -> Vec<PrerequisiteGroup> {
+        lms::get_prerequisite_groups(&env, course_id)
+    }
+
     /// Add a learning platform
     pub fn add_platform(
         env: Env,
@@ -127,6 +249,60 @@ impl LearningManagementContract {
         lms::remove_platform(&env, &admin, &platform)
     }
 
+    /// Register the ed25519 key a platform uses to sign completion receipts
+    pub fn set_platform_key(
+        env: Env,
+        platform: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        lms::set_platform_key(&env, &platform, public_key)
+    }
+
+    /// Get a platform's receipt signing key
+    pub fn get_platform_key(env: Env, platform: Address) -> Option<BytesN<32>> {
+        lms::get_platform_key(&env, &platform)
+    }
+
+    /// Configure the NFT contract that mints course-completion certificates
+    pub fn set_nft_contract(
+        env: Env,
+        admin: Address,
+        nft_contract: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        lms::set_nft_contract(&env, &admin, &nft_contract)
+    }
+
+    /// Get the configured course-completion NFT contract
+    pub fn get_nft_contract(env: Env) -> Option<Address> {
+        storage::get_nft_contract(&env)
+    }
+
+    /// Trust course certificates minted by another LMS contract for prerequisites
+    pub fn add_trusted_issuer(
+        env: Env,
+        admin: Address,
+        issuer: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        lms::add_trusted_issuer(&env, &admin, &issuer)
+    }
+
+    /// Stop trusting course certificates minted by another LMS contract
+    pub fn remove_trusted_issuer(
+        env: Env,
+        admin: Address,
+        issuer: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        lms::remove_trusted_issuer(&env, &admin, &issuer)
+    }
+
+    /// Get the LMS contracts whose certificates are trusted
+    pub fn get_trusted_issuers(env: Env) -> Vec<Address> {
+        storage::get_trusted_issuers(&env)
+    }
+
     /// Check if address is authorized platform
     pub fn is_platform(env: Env, platform: Address) -> bool {
         lms::is_platform(&env, &platform)
@@ -139,7 +315,6 @@ impl LearningManagementContract {
         token_id: u64,
         milestone_id: u64,
     ) -> Result<(), ContractError> {
-        platform.require_auth();
         integration::notify_milestone_completion(&env, token_id, milestone_id, &platform)
     }
 
@@ -151,7 +326,6 @@ impl LearningManagementContract {
         project_id: u64,
         milestone_id: u64,
     ) -> Result<(), ContractError> {
-        platform.require_auth();
         integration::link_progress_with_milestone(&env, token_id, project_id, milestone_id, &platform)
     }
 
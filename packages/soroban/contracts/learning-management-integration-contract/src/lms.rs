@@ -1,7 +1,33 @@
+use crate::assessment;
+use crate::certificate;
+use crate::course;
 use crate::error::ContractError;
 use crate::events::*;
-use crate::storage::{self, LearningProgress};
-use soroban_sdk::{Address, Env, Vec};
+use crate::storage::{
+    self, LearningProgress, PrerequisiteCondition, PrerequisiteGroup, ProgressReceipt,
+};
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, Vec};
+
+/// Require the platform's authorization and check that it is registered
+pub(crate) fn require_platform(env: &Env, platform: &Address) -> Result<(), ContractError> {
+    platform.require_auth();
+    if !storage::is_platform(env, platform) {
+        return Err(ContractError::NotAuthorizedPlatform);
+    }
+    Ok(())
+}
+
+// Only the owner platform can act on a registered course
+fn require_registered_course_owner(
+    env: &Env,
+    course_id: u64,
+    platform: &Address,
+) -> Result<(), ContractError> {
+    if storage::get_course(env, course_id).is_some() {
+        course::require_course_owner(env, course_id, platform)?;
+    }
+    Ok(())
+}
 
 // Validate completion status (0-100)
 fn validate_completion_status(completion_status: u32) -> Result<(), ContractError> {
@@ -11,6 +37,14 @@ fn validate_completion_status(completion_status: u32) -> Result<(), ContractErro
     Ok(())
 }
 
+// Validate grade (0-100)
+fn validate_grade(grade: u32) -> Result<(), ContractError> {
+    if grade > 100 {
+        return Err(ContractError::InvalidGrade);
+    }
+    Ok(())
+}
+
 // Validate token ID
 fn validate_token_id(token_id: u64) -> Result<(), ContractError> {
     if token_id == 0 {
@@ -27,7 +61,9 @@ fn validate_course_id(course_id: u64) -> Result<(), ContractError> {
     Ok(())
 }
 
-/// Initialize learning progress for a user in a course
+/// Initialize learning progress for a user in a course. Registered courses go through
+/// `course::enroll`, which checks ownership, the enrollment window, capacity and the
+/// catalog prerequisites; `prerequisites` only applies to courses outside the catalog.
 pub fn initialize_progress(
     env: &Env,
     user: &Address,
@@ -37,11 +73,23 @@ pub fn initialize_progress(
 ) -> Result<u64, ContractError> {
     validate_course_id(course_id)?;
 
-    // Check if platform is authorized
-    if !storage::is_platform(env, platform) {
-        return Err(ContractError::NotAuthorizedPlatform);
+    if storage::get_course(env, course_id).is_some() {
+        return course::enroll(env, platform, user, course_id);
     }
 
+    // Check if platform is authorized
+    require_platform(env, platform)?;
+
+    create_progress(env, user, course_id, prerequisites)
+}
+
+// Create the progress entry for an already authorized platform
+pub(crate) fn create_progress(
+    env: &Env,
+    user: &Address,
+    course_id: u64,
+    prerequisites: Vec<u64>,
+) -> Result<u64, ContractError> {
     // Check if user already has progress for this course
     if let Some(_) = storage::get_user_progress_token_id(env, user, course_id) {
         return Err(ContractError::ProgressAlreadyExists);
@@ -61,11 +109,14 @@ pub fn initialize_progress(
         created_at: timestamp,
         updated_at: timestamp,
         nft_issued: false,
+        certificate_id: None,
+        grade: None,
     };
 
     // Store progress
     storage::set_progress(env, &progress);
     storage::set_user_progress_token_id(env, user, course_id, token_id);
+    storage::increment_course_progress_count(env, course_id);
 
     Ok(token_id)
 }
@@ -81,13 +132,58 @@ pub fn update_progress(
     validate_completion_status(completion_status)?;
 
     // Check if platform is authorized
-    if !storage::is_platform(env, platform) {
+    require_platform(env, platform)?;
+
+    apply_progress(env, token_id, completion_status, platform)
+}
+
+/// Update learning progress from a completion receipt signed by the platform's
+/// registered ed25519 key, so that any relayer can submit it
+pub fn submit_signed_progress(
+    env: &Env,
+    receipt: ProgressReceipt,
+    signature: BytesN<64>,
+) -> Result<(), ContractError> {
+    validate_token_id(receipt.token_id)?;
+    validate_completion_status(receipt.completion_status)?;
+
+    if !storage::is_platform(env, &receipt.platform) {
         return Err(ContractError::NotAuthorizedPlatform);
     }
+    let public_key = storage::get_platform_key(env, &receipt.platform)
+        .ok_or(ContractError::PlatformKeyNotSet)?;
 
+    if env.ledger().timestamp() > receipt.expires_at {
+        return Err(ContractError::ReceiptExpired);
+    }
+    if storage::is_receipt_used(env, &receipt.platform, receipt.nonce) {
+        return Err(ContractError::ReceiptAlreadyUsed);
+    }
+
+    // The signed message binds the receipt to this contract instance
+    let message = (env.current_contract_address(), receipt.clone()).to_xdr(env);
+    env.crypto().ed25519_verify(&public_key, &message, &signature);
+
+    storage::mark_receipt_used(env, &receipt.platform, receipt.nonce);
+    apply_progress(env, receipt.token_id, receipt.completion_status, &receipt.platform)
+}
+
+// Store a new completion status reported by `platform` and emit the update event
+fn apply_progress(
+    env: &Env,
+    token_id: u64,
+    completion_status: u32,
+    platform: &Address,
+) -> Result<(), ContractError> {
     // Get existing progress
     let mut progress = storage::get_progress(env, token_id)
         .ok_or(ContractError::ProgressNotFound)?;
+    require_registered_course_owner(env, progress.course_id, platform)?;
+
+    // Completion of courses with modules is derived from module completions
+    if assessment::has_modules(env, progress.course_id) {
+        return Err(ContractError::OperationNotAllowed);
+    }
 
     // Update progress
     progress.completion_status = completion_status;
@@ -116,60 +212,72 @@ pub fn verify_prerequisites(
 ) -> Result<bool, ContractError> {
     validate_course_id(course_id)?;
 
-    // Get course prerequisites
-    let prerequisites = storage::get_course_prerequisites(env, course_id);
+    // Every group must hold, and a group holds when any of its conditions is met
+    for group in storage::get_course_prerequisites(env, course_id).iter() {
+        let mut group_met = false;
+        for condition in group.any_of.iter() {
+            // Completion recorded here, or a completion NFT from any registered platform
+            let met = completed_locally(env, user, &condition)?
+                || certificate::holds_certificate(env, user, condition.course_id, condition.min_grade);
+
+            emit_prerequisite_verified(env, user.clone(), course_id, condition.course_id, met);
+            if met {
+                group_met = true;
+                break;
+            }
+        }
 
-    // If no prerequisites, return true
-    if prerequisites.is_empty() {
-        return Ok(true);
+        if !group_met {
+            return Ok(false);
+        }
     }
 
-    // Check each prerequisite
-    for i in 0..prerequisites.len() {
-        let prereq_id = prerequisites.get(i).unwrap();
+    Ok(true)
+}
 
-        // Get user's progress for this prerequisite course
-        let prereq_token_id = storage::get_user_progress_token_id(env, user, prereq_id);
+// Completion percentage a course requires before its NFT can be issued
+fn required_completion(env: &Env, course_id: u64) -> u32 {
+    storage::get_course(env, course_id)
+        .map(|course| course.details.required_completion)
+        .unwrap_or(100)
+}
 
-        if let Some(token_id) = prereq_token_id {
-            let prereq_progress = storage::get_progress(env, token_id)
+// Check if the user completed the course in this contract with the minimum grade and received its NFT
+fn completed_locally(
+    env: &Env,
+    user: &Address,
+    condition: &PrerequisiteCondition,
+) -> Result<bool, ContractError> {
+    match storage::get_user_progress_token_id(env, user, condition.course_id) {
+        Some(token_id) => {
+            let progress = storage::get_progress(env, token_id)
                 .ok_or(ContractError::PrerequisiteNotFound)?;
-
-            // Check if prerequisite course is completed (100%) and NFT issued
-            if prereq_progress.completion_status < 100 || !prereq_progress.nft_issued {
-                // Emit verification failed event
-                emit_prerequisite_verified(env, user.clone(), course_id, prereq_id, false);
-                return Ok(false);
-            }
-
-            // Emit verification success event for this prerequisite
-            emit_prerequisite_verified(env, user.clone(), course_id, prereq_id, true);
-        } else {
-            // User hasn't started the prerequisite course
-            emit_prerequisite_verified(env, user.clone(), course_id, prereq_id, false);
-            return Ok(false);
+            Ok(progress.nft_issued
+                && progress.completion_status >= required_completion(env, condition.course_id)
+                && progress.grade.unwrap_or(0) >= condition.min_grade)
         }
+        None => Ok(false),
     }
-
-    Ok(true)
 }
 
-/// Issue NFT upon course completion
+/// Issue NFT upon course completion by minting it on the configured NFT contract.
+/// Courses with modules use the grade derived from assessments, so `grade` must be
+/// `None` for them and is required otherwise.
 pub fn issue_course_nft(
     env: &Env,
     token_id: u64,
+    grade: Option<u32>,
     platform: &Address,
-) -> Result<(), ContractError> {
+) -> Result<u64, ContractError> {
     validate_token_id(token_id)?;
 
     // Check if platform is authorized
-    if !storage::is_platform(env, platform) {
-        return Err(ContractError::NotAuthorizedPlatform);
-    }
+    require_platform(env, platform)?;
 
     // Get progress
     let mut progress = storage::get_progress(env, token_id)
         .ok_or(ContractError::ProgressNotFound)?;
+    require_registered_course_owner(env, progress.course_id, platform)?;
 
     // Check if NFT already issued
     if progress.nft_issued {
@@ -177,18 +285,31 @@ pub fn issue_course_nft(
     }
 
     // Verify course completion
-    if progress.completion_status < 100 {
+    if progress.completion_status < required_completion(env, progress.course_id) {
         return Err(ContractError::CourseNotCompleted);
     }
 
+    let grade = match (assessment::has_modules(env, progress.course_id), grade) {
+        (true, None) => assessment::derive_grade(env, &progress),
+        (false, Some(grade)) => {
+            validate_grade(grade)?;
+            grade
+        }
+        _ => return Err(ContractError::InvalidGrade),
+    };
+
     // Verify prerequisites
     let prerequisites_met = verify_prerequisites(env, &progress.user, progress.course_id)?;
     if !prerequisites_met {
         return Err(ContractError::PrerequisiteNotMet);
     }
 
+    let certificate_id = certificate::mint_certificate(env, &progress, platform, grade)?;
+
     // Mark NFT as issued
     progress.nft_issued = true;
+    progress.certificate_id = Some(certificate_id);
+    progress.grade = Some(grade);
     progress.updated_at = env.ledger().timestamp();
 
     // Update storage
@@ -203,9 +324,11 @@ pub fn issue_course_nft(
         progress.user.clone(),
         progress.course_id,
         platform.clone(),
+        certificate_id,
+        grade,
     );
 
-    Ok(())
+    Ok(certificate_id)
 }
 
 /// Get learning progress by token ID
@@ -240,36 +363,95 @@ pub fn get_course_nfts(env: &Env, course_id: u64) -> Result<Vec<u64>, ContractEr
     Ok(storage::get_course_nfts(env, course_id))
 }
 
-/// Set prerequisites for a course (admin/platform only)
+/// Set prerequisites for a course as a plain list of courses that must all be completed
 pub fn set_course_prerequisites(
     env: &Env,
     course_id: u64,
     prerequisites: Vec<u64>,
     platform: &Address,
+) -> Result<(), ContractError> {
+    let mut groups = Vec::new(env);
+    for prereq_id in prerequisites.iter() {
+        let mut any_of = Vec::new(env);
+        any_of.push_back(PrerequisiteCondition {
+            course_id: prereq_id,
+            min_grade: 0,
+        });
+        groups.push_back(PrerequisiteGroup { any_of });
+    }
+
+    set_prerequisite_groups(env, course_id, groups, platform)
+}
+
+/// Set a course's prerequisite expression (AND of OR-groups). Only the owner
+/// platform can change the prerequisites of a registered course.
+pub fn set_prerequisite_groups(
+    env: &Env,
+    course_id: u64,
+    groups: Vec<PrerequisiteGroup>,
+    platform: &Address,
 ) -> Result<(), ContractError> {
     validate_course_id(course_id)?;
 
     // Check if platform is authorized
-    if !storage::is_platform(env, platform) {
-        return Err(ContractError::NotAuthorizedPlatform);
-    }
+    require_platform(env, platform)?;
+    require_registered_course_owner(env, course_id, platform)?;
 
-    // Validate prerequisite IDs
-    for i in 0..prerequisites.len() {
-        let prereq_id = prerequisites.get(i).unwrap();
-        if prereq_id == 0 {
+    // Validate prerequisite conditions
+    for group in groups.iter() {
+        if group.any_of.is_empty() {
             return Err(ContractError::InvalidPrerequisite);
         }
-        // Prevent self-reference
-        if prereq_id == course_id {
-            return Err(ContractError::InvalidPrerequisite);
+        for condition in group.any_of.iter() {
+            // Prevent empty IDs, self-reference and unreachable grades
+            if condition.course_id == 0 || condition.course_id == course_id || condition.min_grade > 100 {
+                return Err(ContractError::InvalidPrerequisite);
+            }
         }
     }
 
-    storage::set_course_prerequisites(env, course_id, &prerequisites);
+    if creates_cycle(env, course_id, &groups) {
+        return Err(ContractError::PrerequisiteCycle);
+    }
+
+    storage::set_course_prerequisites(env, course_id, &groups);
     Ok(())
 }
 
+/// Get a course's prerequisite expression
+pub fn get_prerequisite_groups(env: &Env, course_id: u64) -> Vec<PrerequisiteGroup> {
+    storage::get_course_prerequisites(env, course_id)
+}
+
+// Check whether `course_id` is reachable from its new prerequisites through the existing graph
+fn creates_cycle(env: &Env, course_id: u64, groups: &Vec<PrerequisiteGroup>) -> bool {
+    let mut pending: Vec<u64> = Vec::new(env);
+    for group in groups.iter() {
+        for condition in group.any_of.iter() {
+            pending.push_back(condition.course_id);
+        }
+    }
+
+    let mut visited: Vec<u64> = Vec::new(env);
+    while let Some(current) = pending.pop_back() {
+        if current == course_id {
+            return true;
+        }
+        if visited.contains(current) {
+            continue;
+        }
+        visited.push_back(current);
+
+        for group in storage::get_course_prerequisites(env, current).iter() {
+            for condition in group.any_of.iter() {
+                pending.push_back(condition.course_id);
+            }
+        }
+    }
+
+    false
+}
+
 /// Add a learning platform
 pub fn add_platform(
     env: &Env,
@@ -302,6 +484,67 @@ pub fn remove_platform(
     Ok(())
 }
 
+/// Register or rotate the ed25519 key a platform uses to sign completion receipts
+pub fn set_platform_key(
+    env: &Env,
+    platform: &Address,
+    public_key: BytesN<32>,
+) -> Result<(), ContractError> {
+    require_platform(env, platform)?;
+
+    storage::set_platform_key(env, platform, &public_key);
+    emit_platform_key_set(env, platform.clone(), public_key);
+
+    Ok(())
+}
+
+/// Get a platform's registered receipt signing key
+pub fn get_platform_key(env: &Env, platform: &Address) -> Option<BytesN<32>> {
+    storage::get_platform_key(env, platform)
+}
+
+/// Configure the NFT contract that mints course-completion certificates
+pub fn set_nft_contract(
+    env: &Env,
+    admin: &Address,
+    nft_contract: &Address,
+) -> Result<(), ContractError> {
+    if !storage::is_admin(env, admin) {
+        return Err(ContractError::AdminOnly);
+    }
+
+    storage::set_nft_contract(env, nft_contract);
+    Ok(())
+}
+
+/// Trust course certificates minted by another LMS contract
+pub fn add_trusted_issuer(
+    env: &Env,
+    admin: &Address,
+    issuer: &Address,
+) -> Result<(), ContractError> {
+    if !storage::is_admin(env, admin) {
+        return Err(ContractError::AdminOnly);
+    }
+
+    storage::add_trusted_issuer(env, issuer);
+    Ok(())
+}
+
+/// Stop trusting course certificates minted by another LMS contract
+pub fn remove_trusted_issuer(
+    env: &Env,
+    admin: &Address,
+    issuer: &Address,
+) -> Result<(), ContractError> {
+    if !storage::is_admin(env, admin) {
+        return Err(ContractError::AdminOnly);
+    }
+
+    storage::remove_trusted_issuer(env, issuer);
+    Ok(())
+}
+
 /// Check if address is authorized platform
 pub fn is_platform(env: &Env, platform: &Address) -> bool {
     storage::is_platform(env, platform)
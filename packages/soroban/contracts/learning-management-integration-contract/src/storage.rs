@@ -1,9 +1,11 @@
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
 // Storage keys
 const ADMIN_KEY: &str = "admin";
 const PLATFORMS_KEY: &str = "platforms";
 const TOKEN_COUNTER_KEY: &str = "token_counter";
+const NFT_CONTRACT_KEY: &str = "nft_contract";
+const TRUSTED_ISSUERS_KEY: &str = "trusted_issuers";
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -16,6 +18,89 @@ pub struct LearningProgress {
     pub created_at: u64,
     pub updated_at: u64,
     pub nft_issued: bool,
+    pub certificate_id: Option<u64>, // Token ID in the NFT contract once minted
+    pub grade: Option<u32>,          // Final grade (0-100) once the NFT is issued
+}
+
+/// Course settings chosen by the owner platform
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseDetails {
+    pub title: String,
+    pub credits: u32,
+    pub capacity: u32,            // Maximum number of enrollments
+    pub enrollment_opens: u64,    // Enrollment window start (timestamp)
+    pub enrollment_closes: u64,   // Enrollment window end (timestamp)
+    pub required_completion: u32, // Completion percentage needed for the NFT
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Course {
+    pub course_id: u64,
+    pub platform: Address, // Owner platform
+    pub details: CourseDetails,
+    pub enrolled: u32,
+    pub created_at: u64,
+}
+
+/// Graded assessment within a course module
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assessment {
+    pub assessment_id: u32,
+    pub weight: u32,         // Relative weight in the final grade
+    pub pass_threshold: u32, // Minimum score (0-100) to pass
+    pub max_attempts: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseModule {
+    pub module_id: u32,
+    pub title: String,
+    pub assessments: Vec<Assessment>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssessmentAttempt {
+    pub score: u32, // 0-100
+    pub passed: bool,
+    pub submitted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleCompletion {
+    pub module_id: u32,
+    pub completed_at: u64,
+}
+
+/// Completion of `course_id` with at least `min_grade`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrerequisiteCondition {
+    pub course_id: u64,
+    pub min_grade: u32,
+}
+
+/// Satisfied when any of its conditions holds (OR); a course's groups must all hold (AND)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrerequisiteGroup {
+    pub any_of: Vec<PrerequisiteCondition>,
+}
+
+/// Completion receipt signed off-chain by a platform's registered ed25519 key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgressReceipt {
+    pub platform: Address,
+    pub token_id: u64,
+    pub completion_status: u32, // 0-100 percentage
+    pub nonce: u64,             // Unique per platform, prevents replay
+    pub expires_at: u64,        // Ledger timestamp after which the receipt is rejected
 }
 
 #[contracttype]
@@ -25,7 +110,14 @@ pub enum StorageKey {
     UserProgress(Address, u64),       // (user, course_id) -> token_id
     UserNFTs(Address),                // user -> Vec<token_id>
     CourseNFTs(u64),                  // course_id -> Vec<token_id>
-    CoursePrerequisites(u64),         // course_id -> Vec<prerequisite_course_ids>
+    CoursePrerequisites(u64),         // course_id -> Vec<PrerequisiteGroup>
+    PlatformKey(Address),             // platform -> ed25519 public key
+    UsedReceipt(Address, u64),        // (platform, nonce) -> bool
+    Course(u64),                      // course_id -> Course
+    CourseModules(u64),               // course_id -> Vec<CourseModule>
+    AssessmentAttempts(u64, u32),     // (token_id, assessment_id) -> Vec<AssessmentAttempt>
+    ModuleCompletions(u64),           // token_id -> Vec<ModuleCompletion>
+    CourseProgressCount(u64),         // course_id -> number of progress entries
 }
 
 // Admin functions
@@ -82,6 +174,48 @@ pub fn is_platform(env: &Env, platform: &Address) -> bool {
     platforms.contains(platform)
 }
 
+// Course-completion NFT contract
+pub fn get_nft_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&NFT_CONTRACT_KEY)
+}
+
+pub fn set_nft_contract(env: &Env, nft_contract: &Address) {
+    env.storage().instance().set(&NFT_CONTRACT_KEY, nft_contract);
+}
+
+// Other LMS contracts whose certificates satisfy prerequisites here
+pub fn get_trusted_issuers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&TRUSTED_ISSUERS_KEY)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_trusted_issuer(env: &Env, issuer: &Address) {
+    let mut issuers = get_trusted_issuers(env);
+    if !issuers.contains(issuer) {
+        issuers.push_back(issuer.clone());
+        env.storage().instance().set(&TRUSTED_ISSUERS_KEY, &issuers);
+    }
+}
+
+pub fn remove_trusted_issuer(env: &Env, issuer: &Address) {
+    let issuers = get_trusted_issuers(env);
+    let mut new_issuers = Vec::new(env);
+
+    for addr in issuers.iter() {
+        if addr != *issuer {
+            new_issuers.push_back(addr);
+        }
+    }
+
+    env.storage().instance().set(&TRUSTED_ISSUERS_KEY, &new_issuers);
+}
+
+pub fn is_trusted_issuer(env: &Env, issuer: &Address) -> bool {
+    get_trusted_issuers(env).contains(issuer)
+}
+
 // Token counter for auto-incrementing NFT IDs
 pub fn get_next_token_id(env: &Env) -> u64 {
     let current: u64 = env
@@ -116,6 +250,18 @@ pub fn set_user_progress_token_id(env: &Env, user: &Address, course_id: u64, tok
     env.storage().persistent().set(&key, &token_id);
 }
 
+// Progress entries created for a course, whether through enrollment or directly
+pub fn get_course_progress_count(env: &Env, course_id: u64) -> u32 {
+    let key = StorageKey::CourseProgressCount(course_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn increment_course_progress_count(env: &Env, course_id: u64) {
+    let key = StorageKey::CourseProgressCount(course_id);
+    let count = get_course_progress_count(env, course_id);
+    env.storage().persistent().set(&key, &(count + 1));
+}
+
 // User NFT tracking
 pub fn get_user_nfts(env: &Env, user: &Address) -> Vec<u64> {
     let key = StorageKey::UserNFTs(user.clone());
@@ -146,13 +292,82 @@ pub fn add_course_nft(env: &Env, course_id: u64, token_id: u64) {
     }
 }
 
+// Course catalog
+pub fn get_course(env: &Env, course_id: u64) -> Option<Course> {
+    let key = StorageKey::Course(course_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_course(env: &Env, course: &Course) {
+    let key = StorageKey::Course(course.course_id);
+    env.storage().persistent().set(&key, course);
+}
+
 // Course prerequisites management
-pub fn get_course_prerequisites(env: &Env, course_id: u64) -> Vec<u64> {
+pub fn get_course_prerequisites(env: &Env, course_id: u64) -> Vec<PrerequisiteGroup> {
     let key = StorageKey::CoursePrerequisites(course_id);
     env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
 }
 
-pub fn set_course_prerequisites(env: &Env, course_id: u64, prerequisites: &Vec<u64>) {
+pub fn set_course_prerequisites(env: &Env, course_id: u64, prerequisites: &Vec<PrerequisiteGroup>) {
     let key = StorageKey::CoursePrerequisites(course_id);
     env.storage().persistent().set(&key, prerequisites);
 }
+
+// Platform signing keys
+pub fn get_platform_key(env: &Env, platform: &Address) -> Option<BytesN<32>> {
+    let key = StorageKey::PlatformKey(platform.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_platform_key(env: &Env, platform: &Address, public_key: &BytesN<32>) {
+    let key = StorageKey::PlatformKey(platform.clone());
+    env.storage().persistent().set(&key, public_key);
+}
+
+// Receipt replay protection
+pub fn is_receipt_used(env: &Env, platform: &Address, nonce: u64) -> bool {
+    let key = StorageKey::UsedReceipt(platform.clone(), nonce);
+    env.storage().persistent().has(&key)
+}
+
+pub fn mark_receipt_used(env: &Env, platform: &Address, nonce: u64) {
+    let key = StorageKey::UsedReceipt(platform.clone(), nonce);
+    env.storage().persistent().set(&key, &true);
+}
+
+// Course modules and assessments
+pub fn get_course_modules(env: &Env, course_id: u64) -> Vec<CourseModule> {
+    let key = StorageKey::CourseModules(course_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn set_course_modules(env: &Env, course_id: u64, modules: &Vec<CourseModule>) {
+    let key = StorageKey::CourseModules(course_id);
+    env.storage().persistent().set(&key, modules);
+}
+
+pub fn get_assessment_attempts(env: &Env, token_id: u64, assessment_id: u32) -> Vec<AssessmentAttempt> {
+    let key = StorageKey::AssessmentAttempts(token_id, assessment_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn set_assessment_attempts(
+    env: &Env,
+    token_id: u64,
+    assessment_id: u32,
+    attempts: &Vec<AssessmentAttempt>,
+) {
+    let key = StorageKey::AssessmentAttempts(token_id, assessment_id);
+    env.storage().persistent().set(&key, attempts);
+}
+
+pub fn get_module_completions(env: &Env, token_id: u64) -> Vec<ModuleCompletion> {
+    let key = StorageKey::ModuleCompletions(token_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn set_module_completions(env: &Env, token_id: u64, completions: &Vec<ModuleCompletion>) {
+    let key = StorageKey::ModuleCompletions(token_id);
+    env.storage().persistent().set(&key, completions);
+}
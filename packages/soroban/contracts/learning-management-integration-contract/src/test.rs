@@ -1,14 +1,35 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::{LearningManagementContract, LearningManagementContractClient};
-use soroban_sdk::{testutils::{Address as _, Events}, Address, Env, Vec};
+use crate::{
+    Assessment, ContractError, CourseDetails, CourseModule, LearningManagementContract, LearningManagementContractClient,
+    PrerequisiteCondition, PrerequisiteGroup, ProgressReceipt,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    xdr::ToXdr,
+    Address, BytesN, Env, String, Vec,
+};
+
+use educational_nft_contract::{EducationalNFTContract, EducationalNFTContractClient};
 
 fn create_contract<'a>(env: &Env) -> LearningManagementContractClient<'a> {
     let contract_address = env.register(LearningManagementContract, ());
     LearningManagementContractClient::new(env, &contract_address)
 }
 
+// Deploy the course-completion NFT contract with the given LMS contracts as certificate minters
+fn create_nft_contract(env: &Env, admin: &Address, minters: &[&Address]) -> Address {
+    let nft_contract = env.register(EducationalNFTContract, ());
+    let nft_client = EducationalNFTContractClient::new(env, &nft_contract);
+    nft_client.initialize(admin);
+    for minter in minters {
+        nft_client.add_certificate_minter(admin, minter);
+    }
+    nft_contract
+}
+
 #[test]
 fn test_initialize() {
     let env = Env::default();
@@ -19,6 +40,7 @@ fn test_initialize() {
 
     // Initialize contract
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
 }
 
 #[test]
@@ -31,6 +53,7 @@ fn test_initialize_twice_fails() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.initialize(&admin); // AlreadyInitialized = 1
 }
 
@@ -45,6 +68,7 @@ fn test_add_and_remove_platform() {
 
     // Initialize
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
 
     // Add platform
     contract.add_platform(&admin, &platform);
@@ -71,6 +95,7 @@ fn test_initialize_progress() {
 
     // Initialize contract and add platform
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Initialize progress
@@ -99,6 +124,7 @@ fn test_update_progress() {
 
     // Setup
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Initialize progress
@@ -127,6 +153,7 @@ fn test_issue_course_nft() {
 
     // Setup
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Initialize and complete course
@@ -136,7 +163,7 @@ fn test_issue_course_nft() {
     contract.update_progress(&platform, &token_id, &100u32);
 
     // Issue NFT
-    contract.issue_course_nft(&platform, &token_id);
+    contract.issue_course_nft(&platform, &token_id, &Some(100u32));
 
     // Verify NFT was issued
     let progress = contract.get_progress(&token_id);
@@ -155,6 +182,7 @@ fn test_get_user_nfts() {
 
     // Setup
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Create and complete multiple courses
@@ -162,7 +190,7 @@ fn test_get_user_nfts() {
         let token_id =
             contract.initialize_progress(&platform, &user, &course_id, &Vec::new(&env));
         contract.update_progress(&platform, &token_id, &100u32);
-        contract.issue_course_nft(&platform, &token_id);
+        contract.issue_course_nft(&platform, &token_id, &Some(100u32));
     }
 
     // Get user NFTs
@@ -184,6 +212,7 @@ fn test_unauthorized_platform_initialize_progress() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
 
     // Try to initialize progress without being authorized platform
     contract.initialize_progress(&unauthorized, &user, &1u64, &Vec::new(&env));
@@ -202,6 +231,7 @@ fn test_unauthorized_platform_update_progress() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -223,13 +253,14 @@ fn test_unauthorized_platform_issue_nft() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
     contract.update_progress(&platform, &token_id, &100u32);
 
     // Try to issue NFT from unauthorized platform
-    contract.issue_course_nft(&unauthorized, &token_id);
+    contract.issue_course_nft(&unauthorized, &token_id, &Some(100u32));
 }
 
 #[test]
@@ -244,6 +275,7 @@ fn test_non_admin_add_platform() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
 
     // Try to add platform as non-admin
     contract.add_platform(&non_admin, &platform);
@@ -261,6 +293,7 @@ fn test_non_admin_remove_platform() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Try to remove platform as non-admin
@@ -279,6 +312,7 @@ fn test_invalid_course_id_zero() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Try to initialize progress with course_id = 0
@@ -297,6 +331,7 @@ fn test_invalid_completion_status_over_100() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -317,13 +352,14 @@ fn test_issue_nft_incomplete_course() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
     contract.update_progress(&platform, &token_id, &99u32);
 
     // Try to issue NFT when not 100% complete
-    contract.issue_course_nft(&platform, &token_id);
+    contract.issue_course_nft(&platform, &token_id, &Some(100u32));
 }
 
 #[test]
@@ -338,14 +374,15 @@ fn test_issue_nft_already_issued() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
     contract.update_progress(&platform, &token_id, &100u32);
-    contract.issue_course_nft(&platform, &token_id);
+    contract.issue_course_nft(&platform, &token_id, &Some(100u32));
 
     // Try to issue NFT again
-    contract.issue_course_nft(&platform, &token_id);
+    contract.issue_course_nft(&platform, &token_id, &Some(100u32));
 }
 
 #[test]
@@ -360,6 +397,7 @@ fn test_duplicate_progress_for_same_course() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let course_id = 1u64;
@@ -382,6 +420,7 @@ fn test_self_prerequisite() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let course_id = 1u64;
@@ -403,6 +442,7 @@ fn test_invalid_prerequisite_id_zero() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let course_id = 1u64;
@@ -426,13 +466,14 @@ fn test_verify_prerequisites_with_multiple_prereqs() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Complete multiple prerequisite courses
     for prereq_id in 1..=3 {
         let token_id = contract.initialize_progress(&platform, &user, &prereq_id, &Vec::new(&env));
         contract.update_progress(&platform, &token_id, &100u32);
-        contract.issue_course_nft(&platform, &token_id);
+        contract.issue_course_nft(&platform, &token_id, &Some(100u32));
     }
 
     // Set all as prerequisites for main course
@@ -458,12 +499,13 @@ fn test_verify_prerequisites_partial_completion() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Complete first prerequisite
     let token1 = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
     contract.update_progress(&platform, &token1, &100u32);
-    contract.issue_course_nft(&platform, &token1);
+    contract.issue_course_nft(&platform, &token1, &Some(100u32));
 
     // Only partially complete second prerequisite
     let _token2 = contract.initialize_progress(&platform, &user, &2u64, &Vec::new(&env));
@@ -491,6 +533,7 @@ fn test_verify_prerequisites_completed_but_no_nft() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Complete prerequisite but don't issue NFT
@@ -519,6 +562,7 @@ fn test_verify_prerequisites_not_started() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Set prerequisite without user starting it
@@ -544,6 +588,7 @@ fn test_milestone_integration_link_and_notify() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -581,6 +626,7 @@ fn test_milestone_unauthorized_platform_link() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -602,6 +648,7 @@ fn test_milestone_unauthorized_platform_notify() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -623,6 +670,7 @@ fn test_milestone_wrong_milestone_id() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -643,6 +691,7 @@ fn test_milestone_link_nonexistent_progress() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Try to link non-existent progress
@@ -662,6 +711,7 @@ fn test_events_nft_issuance() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let course_id = 1u64;
@@ -669,7 +719,7 @@ fn test_events_nft_issuance() {
     contract.update_progress(&platform, &token_id, &100u32);
 
     // Issue NFT and check events
-    contract.issue_course_nft(&platform, &token_id);
+    contract.issue_course_nft(&platform, &token_id, &Some(100u32));
 
     // Verify events were emitted
     let events = env.events().all();
@@ -690,12 +740,13 @@ fn test_events_prerequisite_verification() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Complete prerequisite
     let prereq_token = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
     contract.update_progress(&platform, &prereq_token, &100u32);
-    contract.issue_course_nft(&platform, &prereq_token);
+    contract.issue_course_nft(&platform, &prereq_token, &Some(100u32));
 
     // Set prerequisite
     let mut prerequisites = Vec::new(&env);
@@ -719,6 +770,7 @@ fn test_events_platform_management() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
 
     // Add platform - should emit event
     contract.add_platform(&admin, &platform);
@@ -742,6 +794,7 @@ fn test_events_progress_update() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
@@ -756,6 +809,721 @@ fn test_events_progress_update() {
     assert!(events.len() >= 1);
 }
 
+// ============= COURSE NFT TESTS =============
+
+#[test]
+fn test_issue_course_nft_mints_certificate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 12_345);
+
+    let admin = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let user = Address::generate(&env);
+    let contract = create_contract(&env);
+    let nft_contract = create_nft_contract(&env, &admin, &[&contract.address]);
+    let nft_client = EducationalNFTContractClient::new(&env, &nft_contract);
+
+    contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &nft_contract);
+    contract.add_platform(&admin, &platform);
+    assert_eq!(contract.get_nft_contract(), Some(nft_contract.clone()));
+
+    let token_id = contract.initialize_progress(&platform, &user, &7u64, &Vec::new(&env));
+    contract.update_progress(&platform, &token_id, &100u32);
+    let certificate_id = contract.issue_course_nft(&platform, &token_id, &Some(88u32));
+
+    assert_eq!(contract.get_progress(&token_id).certificate_id, Some(certificate_id));
+    assert_eq!(nft_client.owner_of(&certificate_id), user);
+
+    let certificate = nft_client.get_certificate(&certificate_id);
+    assert_eq!(certificate.course_id, 7);
+    assert_eq!(certificate.progress_token_id, token_id);
+    assert_eq!(certificate.platform, platform);
+    assert_eq!(certificate.grade, 88);
+    assert_eq!(certificate.completed_at, 12_345);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")] // NFTContractNotSet = 26
+fn test_issue_course_nft_without_nft_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let user = Address::generate(&env);
+    let contract = create_contract(&env);
+
+    contract.initialize(&admin);
+    contract.add_platform(&admin, &platform);
+
+    let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
+    contract.update_progress(&platform, &token_id, &100u32);
+    contract.issue_course_nft(&platform, &token_id, &Some(90u32));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")] // InvalidGrade = 27
+fn test_issue_course_nft_invalid_grade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let user = Address::generate(&env);
+    let contract = create_contract(&env);
+
+    contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
+    contract.add_platform(&admin, &platform);
+
+    let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
+    contract.update_progress(&platform, &token_id, &100u32);
+    contract.issue_course_nft(&platform, &token_id, &Some(101u32));
+}
+
+#[test]
+fn test_prerequisite_met_by_certificate_from_other_platform() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let platform_a = Address::generate(&env);
+    let platform_b = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    // Two LMS deployments sharing the same NFT contract, B trusting certificates minted by A
+    let lms_a = create_contract(&env);
+    let lms_b = create_contract(&env);
+    let nft_contract = create_nft_contract(&env, &admin, &[&lms_a.address, &lms_b.address]);
+
+    lms_a.initialize(&admin);
+    lms_a.set_nft_contract(&admin, &nft_contract);
+    lms_a.add_platform(&admin, &platform_a);
+
+    lms_b.initialize(&admin);
+    lms_b.add_trusted_issuer(&admin, &lms_a.address);
+    lms_b.set_nft_contract(&admin, &nft_contract);
+    lms_b.add_platform(&admin, &platform_b);
+
+    // Course 1 is completed on platform A
+    let token_id = lms_a.initialize_progress(&platform_a, &user, &1u64, &Vec::new(&env));
+    lms_a.update_progress(&platform_a, &token_id, &100u32);
+    lms_a.issue_course_nft(&platform_a, &token_id, &Some(95u32));
+
+    let mut prereqs = Vec::new(&env);
+    prereqs.push_back(1u64);
+    lms_b.set_course_prerequisites(&platform_b, &2u64, &prereqs);
+
+    // Platform A is not registered on B, so its certificate is not accepted
+    assert!(!lms_b.verify_prerequisites(&user, &2u64));
+
+    lms_b.add_platform(&admin, &platform_a);
+    assert!(lms_b.verify_prerequisites(&user, &2u64));
+}
+
+#[test]
+fn test_transferred_certificate_no_longer_satisfies_prerequisite() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let platform_a = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let lms_a = create_contract(&env);
+    let lms_b = create_contract(&env);
+    let nft_contract = create_nft_contract(&env, &admin, &[&lms_a.address, &lms_b.address]);
+    let nft_client = EducationalNFTContractClient::new(&env, &nft_contract);
+
+    lms_a.initialize(&admin);
+    lms_a.set_nft_contract(&admin, &nft_contract);
+    lms_a.add_platform(&admin, &platform_a);
+
+    lms_b.initialize(&admin);
+    lms_b.add_trusted_issuer(&admin, &lms_a.address);
+    lms_b.set_nft_contract(&admin, &nft_contract);
+    lms_b.add_platform(&admin, &platform_a);
+
+    let token_id = lms_a.initialize_progress(&platform_a, &user, &1u64, &Vec::new(&env));
+    lms_a.update_progress(&platform_a, &token_id, &100u32);
+    let certificate_id = lms_a.issue_course_nft(&platform_a, &token_id, &Some(95u32));
+
+    let mut prereqs = Vec::new(&env);
+    prereqs.push_back(1u64);
+    lms_b.set_course_prerequisites(&platform_a, &2u64, &prereqs);
+    assert!(lms_b.verify_prerequisites(&user, &2u64));
+
+    // The certificate records its learner, so it counts for nobody after a transfer
+    nft_client.transfer_certificate(&user, &other, &certificate_id);
+    assert!(!lms_b.verify_prerequisites(&user, &2u64));
+    assert!(!lms_b.verify_prerequisites(&other, &2u64));
+}
+
+#[test]
+fn test_certificate_from_untrusted_minter_not_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let user = Address::generate(&env);
+    let rogue_minter = Address::generate(&env);
+
+    let contract = create_contract(&env);
+    let nft_contract = create_nft_contract(&env, &admin, &[&contract.address, &rogue_minter]);
+    let nft_client = EducationalNFTContractClient::new(&env, &nft_contract);
+
+    contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &nft_contract);
+    contract.add_platform(&admin, &platform);
+
+    let mut prereqs = Vec::new(&env);
+    prereqs.push_back(1u64);
+    contract.set_course_prerequisites(&platform, &2u64, &prereqs);
+
+    // Another approved minter claims the course was completed on a registered platform
+    nft_client.mint_course_certificate(
+        &rogue_minter,
+        &user,
+        &educational_nft_contract::CourseCertificate {
+            course_id: 1,
+            learner: user.clone(),
+            progress_token_id: 0,
+            platform: platform.clone(),
+            grade: 100,
+            completed_at: 0,
+        },
+    );
+    assert!(!contract.verify_prerequisites(&user, &2u64));
+
+    contract.add_trusted_issuer(&admin, &rogue_minter);
+    assert!(contract.verify_prerequisites(&user, &2u64));
+
+    contract.remove_trusted_issuer(&admin, &rogue_minter);
+    assert!(!contract.verify_prerequisites(&user, &2u64));
+}
+
+// ============= COURSE CATALOG TESTS =============
+
+fn setup_catalog<'a>(env: &Env) -> (LearningManagementContractClient<'a>, Address, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let platform = Address::generate(env);
+    let contract = create_contract(env);
+
+    contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(env, &admin, &[&contract.address]));
+    contract.add_platform(&admin, &platform);
+
+    (contract, admin, platform)
+}
+
+fn course_details(env: &Env, capacity: u32, required_completion: u32) -> CourseDetails {
+    CourseDetails {
+        title: String::from_str(env, "Intro to Soroban"),
+        credits: 3,
+        capacity,
+        enrollment_opens: 0,
+        enrollment_closes: 1_000,
+        required_completion,
+    }
+}
+
+fn any_of(env: &Env, conditions: &[(u64, u32)]) -> PrerequisiteGroup {
+    let mut any_of = Vec::new(env);
+    for (course_id, min_grade) in conditions {
+        any_of.push_back(PrerequisiteCondition {
+            course_id: *course_id,
+            min_grade: *min_grade,
+        });
+    }
+    PrerequisiteGroup { any_of }
+}
+
+// Complete a course and issue its NFT with the given grade
+fn complete_course(
+    contract: &LearningManagementContractClient,
+    env: &Env,
+    platform: &Address,
+    user: &Address,
+    course_id: u64,
+    grade: u32,
+) {
+    let token_id = contract.initialize_progress(platform, user, &course_id, &Vec::new(env));
+    contract.update_progress(platform, &token_id, &100u32);
+    contract.issue_course_nft(platform, &token_id, &Some(grade));
+}
+
+#[test]
+fn test_register_course_and_enroll() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+    let user = Address::generate(&env);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+    complete_course(&contract, &env, &platform, &user, 1, 90);
+
+    let mut groups = Vec::new(&env);
+    groups.push_back(any_of(&env, &[(1, 0)]));
+    contract.set_prerequisite_groups(&platform, &10u64, &groups);
+
+    let token_id = contract.enroll(&platform, &user, &10u64);
+
+    let progress = contract.get_progress(&token_id);
+    assert_eq!(progress.course_id, 10);
+    assert_eq!(progress.prerequisites.len(), 1);
+
+    let course = contract.get_course(&10u64);
+    assert_eq!(course.platform, platform);
+    assert_eq!(course.enrolled, 1);
+    assert_eq!(contract.get_prerequisite_groups(&10u64), groups);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")] // CourseAlreadyExists = 29
+fn test_register_course_twice() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")] // EnrollmentClosed = 31
+fn test_enroll_outside_window() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_001);
+    contract.enroll(&platform, &Address::generate(&env), &10u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")] // CourseFull = 32
+fn test_enroll_over_capacity() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 1, 100));
+
+    contract.enroll(&platform, &Address::generate(&env), &10u64);
+    contract.enroll(&platform, &Address::generate(&env), &10u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")] // NotCourseOwner = 30
+fn test_enroll_by_other_platform() {
+    let env = Env::default();
+    let (contract, admin, platform) = setup_catalog(&env);
+    let other_platform = Address::generate(&env);
+    contract.add_platform(&admin, &other_platform);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+    contract.enroll(&other_platform, &Address::generate(&env), &10u64);
+}
+
+#[test]
+fn test_initialize_progress_on_registered_course_enrolls() {
+    let env = Env::default();
+    let (contract, admin, platform) = setup_catalog(&env);
+    let other_platform = Address::generate(&env);
+    contract.add_platform(&admin, &other_platform);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 1, 100));
+
+    let user = Address::generate(&env);
+    let result = contract.try_initialize_progress(&other_platform, &user, &10u64, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::NotCourseOwner)));
+
+    contract.initialize_progress(&platform, &user, &10u64, &Vec::new(&env));
+    assert_eq!(contract.get_course(&10u64).enrolled, 1);
+
+    // Capacity is enforced as for enroll
+    let result = contract.try_initialize_progress(&platform, &Address::generate(&env), &10u64, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::CourseFull)));
+}
+
+#[test]
+fn test_other_platform_cannot_update_or_certify_registered_course() {
+    let env = Env::default();
+    let (contract, admin, platform) = setup_catalog(&env);
+    let other_platform = Address::generate(&env);
+    contract.add_platform(&admin, &other_platform);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+    let token_id = contract.enroll(&platform, &Address::generate(&env), &10u64);
+
+    let result = contract.try_update_progress(&other_platform, &token_id, &100u32);
+    assert_eq!(result, Err(Ok(ContractError::NotCourseOwner)));
+
+    contract.update_progress(&platform, &token_id, &100u32);
+    let result = contract.try_issue_course_nft(&other_platform, &token_id, &Some(90u32));
+    assert_eq!(result, Err(Ok(ContractError::NotCourseOwner)));
+
+    contract.issue_course_nft(&platform, &token_id, &Some(90u32));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")] // PrerequisiteNotMet = 11
+fn test_enroll_without_prerequisites() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 100));
+    let mut prerequisites = Vec::new(&env);
+    prerequisites.push_back(1u64);
+    contract.set_course_prerequisites(&platform, &10u64, &prerequisites);
+
+    contract.enroll(&platform, &Address::generate(&env), &10u64);
+}
+
+#[test]
+fn test_prerequisite_or_groups_and_min_grade() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+    let user = Address::generate(&env);
+
+    // Course 10 requires course 1, and either course 2 with grade >= 80 or course 3
+    let mut groups = Vec::new(&env);
+    groups.push_back(any_of(&env, &[(1, 0)]));
+    groups.push_back(any_of(&env, &[(2, 80), (3, 0)]));
+    contract.set_prerequisite_groups(&platform, &10u64, &groups);
+
+    complete_course(&contract, &env, &platform, &user, 1, 60);
+    assert!(!contract.verify_prerequisites(&user, &10u64));
+
+    // Grade too low for course 2 to count
+    complete_course(&contract, &env, &platform, &user, 2, 70);
+    assert!(!contract.verify_prerequisites(&user, &10u64));
+
+    complete_course(&contract, &env, &platform, &user, 3, 50);
+    assert!(contract.verify_prerequisites(&user, &10u64));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")] // PrerequisiteCycle = 33
+fn test_prerequisite_cycle_rejected() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+
+    let mut groups = Vec::new(&env);
+    groups.push_back(any_of(&env, &[(2, 0)]));
+    contract.set_prerequisite_groups(&platform, &1u64, &groups);
+
+    let mut groups = Vec::new(&env);
+    groups.push_back(any_of(&env, &[(4, 0), (3, 0)]));
+    contract.set_prerequisite_groups(&platform, &2u64, &groups);
+
+    // 3 -> 1 -> 2 -> 3
+    let mut prerequisites = Vec::new(&env);
+    prerequisites.push_back(1u64);
+    contract.set_course_prerequisites(&platform, &3u64, &prerequisites);
+}
+
+#[test]
+fn test_required_completion_percentage() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+    let user = Address::generate(&env);
+
+    contract.register_course(&platform, &10u64, &course_details(&env, 2, 80));
+    let token_id = contract.enroll(&platform, &user, &10u64);
+
+    contract.update_progress(&platform, &token_id, &80u32);
+    contract.issue_course_nft(&platform, &token_id, &Some(75u32));
+
+    assert!(contract.get_progress(&token_id).nft_issued);
+}
+
+// ============= MODULE AND ASSESSMENT TESTS =============
+
+fn assessment(assessment_id: u32, weight: u32, pass_threshold: u32, max_attempts: u32) -> Assessment {
+    Assessment {
+        assessment_id,
+        weight,
+        pass_threshold,
+        max_attempts,
+    }
+}
+
+// Course 10 with module 1 (assessment 1, weight 1, pass 60, 2 attempts)
+// and module 2 (assessment 2, weight 3, pass 70, 1 attempt); returns the enrolled user's token
+fn setup_graded_course<'a>(env: &Env) -> (LearningManagementContractClient<'a>, Address, Address, u64) {
+    let (contract, _, platform) = setup_catalog(env);
+    let nft_contract = contract.get_nft_contract().unwrap();
+
+    contract.register_course(&platform, &10u64, &course_details(env, 5, 100));
+
+    let mut modules = Vec::new(env);
+    let mut assessments = Vec::new(env);
+    assessments.push_back(assessment(1, 1, 60, 2));
+    modules.push_back(CourseModule {
+        module_id: 1,
+        title: String::from_str(env, "Basics"),
+        assessments,
+    });
+    let mut assessments = Vec::new(env);
+    assessments.push_back(assessment(2, 3, 70, 1));
+    modules.push_back(CourseModule {
+        module_id: 2,
+        title: String::from_str(env, "Contracts"),
+        assessments,
+    });
+    contract.set_course_modules(&platform, &10u64, &modules);
+
+    let token_id = contract.enroll(&platform, &Address::generate(env), &10u64);
+    (contract, platform, nft_contract, token_id)
+}
+
+#[test]
+fn test_graded_course_derives_completion_and_grade() {
+    let env = Env::default();
+    let (contract, platform, nft_contract, token_id) = setup_graded_course(&env);
+    env.ledger().with_mut(|li| li.timestamp = 500);
+
+    assert!(!contract.record_assessment_attempt(&platform, &token_id, &1u32, &50u32));
+    assert!(contract.record_assessment_attempt(&platform, &token_id, &1u32, &80u32));
+    contract.complete_module(&platform, &token_id, &1u32);
+    assert_eq!(contract.get_progress(&token_id).completion_status, 50);
+
+    assert!(contract.record_assessment_attempt(&platform, &token_id, &2u32, &90u32));
+    contract.complete_module(&platform, &token_id, &2u32);
+    assert_eq!(contract.get_progress(&token_id).completion_status, 100);
+
+    let attempts = contract.get_assessment_attempts(&token_id, &1u32);
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts.get(1).unwrap().submitted_at, 500);
+    assert_eq!(contract.get_module_completions(&token_id).len(), 2);
+
+    // (80 * 1 + 90 * 3) / 4
+    assert_eq!(contract.get_current_grade(&token_id), 87);
+    let certificate_id = contract.issue_course_nft(&platform, &token_id, &None);
+
+    assert_eq!(contract.get_progress(&token_id).grade, Some(87));
+    let nft_client = EducationalNFTContractClient::new(&env, &nft_contract);
+    assert_eq!(nft_client.get_certificate(&certificate_id).grade, 87);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")] // AttemptLimitReached = 36
+fn test_assessment_attempt_limit() {
+    let env = Env::default();
+    let (contract, platform, _, token_id) = setup_graded_course(&env);
+
+    contract.record_assessment_attempt(&platform, &token_id, &2u32, &40u32);
+    contract.record_assessment_attempt(&platform, &token_id, &2u32, &95u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")] // AssessmentsNotPassed = 37
+fn test_complete_module_without_passing() {
+    let env = Env::default();
+    let (contract, platform, _, token_id) = setup_graded_course(&env);
+
+    contract.record_assessment_attempt(&platform, &token_id, &1u32, &59u32);
+    contract.complete_module(&platform, &token_id, &1u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")] // OperationNotAllowed = 21
+fn test_graded_course_rejects_reported_progress() {
+    let env = Env::default();
+    let (contract, platform, _, token_id) = setup_graded_course(&env);
+
+    contract.update_progress(&platform, &token_id, &100u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")] // InvalidGrade = 27
+fn test_graded_course_rejects_reported_grade() {
+    let env = Env::default();
+    let (contract, platform, _, token_id) = setup_graded_course(&env);
+
+    for (assessment_id, module_id) in [(1u32, 1u32), (2, 2)] {
+        contract.record_assessment_attempt(&platform, &token_id, &assessment_id, &100u32);
+        contract.complete_module(&platform, &token_id, &module_id);
+    }
+    contract.issue_course_nft(&platform, &token_id, &Some(100u32));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")] // OperationNotAllowed = 21
+fn test_course_modules_fixed_after_enrollment() {
+    let env = Env::default();
+    let (contract, platform, _, _) = setup_graded_course(&env);
+
+    let modules = contract.get_course_modules(&10u64);
+    contract.set_course_modules(&platform, &10u64, &modules);
+}
+
+#[test]
+fn test_course_modules_fixed_after_progress_before_registration() {
+    let env = Env::default();
+    let (contract, _, platform) = setup_catalog(&env);
+
+    // Progress started while the course was outside the catalog
+    contract.initialize_progress(&platform, &Address::generate(&env), &10u64, &Vec::new(&env));
+    contract.register_course(&platform, &10u64, &course_details(&env, 5, 100));
+    assert_eq!(contract.get_course(&10u64).enrolled, 0);
+
+    let mut modules = Vec::new(&env);
+    let mut assessments = Vec::new(&env);
+    assessments.push_back(assessment(1, 1, 60, 2));
+    modules.push_back(CourseModule {
+        module_id: 1,
+        title: String::from_str(&env, "Basics"),
+        assessments,
+    });
+    let result = contract.try_set_course_modules(&platform, &10u64, &modules);
+    assert_eq!(result, Err(Ok(ContractError::OperationNotAllowed)));
+}
+
+// ============= SIGNED PROGRESS TESTS =============
+
+fn setup_signed_progress<'a>(
+    env: &Env,
+) -> (LearningManagementContractClient<'a>, Address, u64, SigningKey) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let platform = Address::generate(env);
+    let user = Address::generate(env);
+    let contract = create_contract(env);
+
+    contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(env, &admin, &[&contract.address]));
+    contract.add_platform(&admin, &platform);
+    let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(env));
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    contract.set_platform_key(&platform, &public_key);
+
+    (contract, platform, token_id, signing_key)
+}
+
+fn sign_receipt(
+    env: &Env,
+    contract: &LearningManagementContractClient,
+    receipt: &ProgressReceipt,
+    signing_key: &SigningKey,
+) -> BytesN<64> {
+    let message = (contract.address.clone(), receipt.clone()).to_xdr(env);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+fn receipt(platform: &Address, token_id: u64, completion_status: u32, nonce: u64) -> ProgressReceipt {
+    ProgressReceipt {
+        platform: platform.clone(),
+        token_id,
+        completion_status,
+        nonce,
+        expires_at: 1_000,
+    }
+}
+
+#[test]
+fn test_update_progress_requires_platform_auth() {
+    let env = Env::default();
+    let (contract, platform, token_id, _) = setup_signed_progress(&env);
+
+    // Drop the mocked authorizations: a registered platform address alone is not enough
+    env.set_auths(&[]);
+
+    assert!(contract.try_update_progress(&platform, &token_id, &100u32).is_err());
+    assert!(contract.try_issue_course_nft(&platform, &token_id, &Some(100u32)).is_err());
+    assert_eq!(contract.get_progress(&token_id).completion_status, 0);
+}
+
+#[test]
+fn test_submit_signed_progress() {
+    let env = Env::default();
+    let (contract, platform, token_id, signing_key) = setup_signed_progress(&env);
+
+    let receipt = receipt(&platform, token_id, 100, 1);
+    let signature = sign_receipt(&env, &contract, &receipt, &signing_key);
+
+    // The relayer submits without any platform authorization
+    env.set_auths(&[]);
+    contract.submit_signed_progress(&receipt, &signature);
+
+    assert_eq!(contract.get_progress(&token_id).completion_status, 100);
+    assert_eq!(
+        contract.get_platform_key(&platform),
+        Some(BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")] // ReceiptAlreadyUsed = 25
+fn test_signed_progress_replay_rejected() {
+    let env = Env::default();
+    let (contract, platform, token_id, signing_key) = setup_signed_progress(&env);
+
+    let receipt = receipt(&platform, token_id, 50, 1);
+    let signature = sign_receipt(&env, &contract, &receipt, &signing_key);
+
+    contract.submit_signed_progress(&receipt, &signature);
+    contract.submit_signed_progress(&receipt, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")] // ReceiptExpired = 24
+fn test_signed_progress_expired() {
+    let env = Env::default();
+    let (contract, platform, token_id, signing_key) = setup_signed_progress(&env);
+
+    let receipt = receipt(&platform, token_id, 100, 1);
+    let signature = sign_receipt(&env, &contract, &receipt, &signing_key);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_001);
+    contract.submit_signed_progress(&receipt, &signature);
+}
+
+#[test]
+#[should_panic]
+fn test_signed_progress_tampered_receipt() {
+    let env = Env::default();
+    let (contract, platform, token_id, signing_key) = setup_signed_progress(&env);
+
+    let signed = receipt(&platform, token_id, 50, 1);
+    let signature = sign_receipt(&env, &contract, &signed, &signing_key);
+
+    // Relayer tries to raise the completion status
+    let tampered = receipt(&platform, token_id, 100, 1);
+    contract.submit_signed_progress(&tampered, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")] // PlatformKeyNotSet = 23
+fn test_signed_progress_without_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let user = Address::generate(&env);
+    let contract = create_contract(&env);
+
+    contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
+    contract.add_platform(&admin, &platform);
+    let token_id = contract.initialize_progress(&platform, &user, &1u64, &Vec::new(&env));
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let receipt = receipt(&platform, token_id, 100, 1);
+    let signature = sign_receipt(&env, &contract, &receipt, &signing_key);
+    contract.submit_signed_progress(&receipt, &signature);
+}
+
 // ============= COMPREHENSIVE INTEGRATION TEST =============
 
 #[test]
@@ -769,13 +1537,14 @@ fn test_full_learning_path_with_prerequisites_and_milestone() {
     let contract = create_contract(&env);
 
     contract.initialize(&admin);
+    contract.set_nft_contract(&admin, &create_nft_contract(&env, &admin, &[&contract.address]));
     contract.add_platform(&admin, &platform);
 
     // Step 1: Complete foundational course (no prerequisites)
     let foundation_course = 1u64;
     let foundation_token = contract.initialize_progress(&platform, &user, &foundation_course, &Vec::new(&env));
     contract.update_progress(&platform, &foundation_token, &100u32);
-    contract.issue_course_nft(&platform, &foundation_token);
+    contract.issue_course_nft(&platform, &foundation_token, &Some(100u32));
 
     // Step 2: Complete intermediate course (requires foundation)
     let intermediate_course = 2u64;
@@ -787,7 +1556,7 @@ fn test_full_learning_path_with_prerequisites_and_milestone() {
 
     let intermediate_token = contract.initialize_progress(&platform, &user, &intermediate_course, &Vec::new(&env));
     contract.update_progress(&platform, &intermediate_token, &100u32);
-    contract.issue_course_nft(&platform, &intermediate_token);
+    contract.issue_course_nft(&platform, &intermediate_token, &Some(100u32));
 
     // Step 3: Advanced course (requires both foundation and intermediate)
     let advanced_course = 3u64;
@@ -804,7 +1573,7 @@ fn test_full_learning_path_with_prerequisites_and_milestone() {
     contract.link_progress_with_milestone(&platform, &advanced_token, &100u64, &1u64);
 
     contract.update_progress(&platform, &advanced_token, &100u32);
-    contract.issue_course_nft(&platform, &advanced_token);
+    contract.issue_course_nft(&platform, &advanced_token, &Some(100u32));
 
     // Notify milestone completion
     contract.notify_milestone_completion(&platform, &advanced_token, &1u64);
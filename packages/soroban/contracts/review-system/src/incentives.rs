@@ -1,15 +1,22 @@
 use soroban_sdk::{
-    contracterror, contracttype, Address,
+    contracterror, contracttype, token, Address, Env, Map, Symbol, Vec,
 };
 
+use crate::{DataKey, ModerationStatus, Response, ReviewSystemContract};
+
+/// Maximum rewards queued in one epoch, which bounds the work of distributing it
+pub const MAX_EPOCH_REWARDS: u32 = 50;
+
 /// Review reward data structure as specified in the requirements
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReviewReward {
     pub review_id: u64,         // Associated review ID
-    pub reviewer: Address,      // Stellar address of the reviewer  
+    pub reviewer: Address,      // Stellar address of the reviewer
     pub token_amount: i128,     // Reward amount in tokens (Stroops)
     pub timestamp: u64,         // Reward issuance timestamp
+    pub epoch_id: u64,          // Epoch the reward is paid out at the end of
+    pub paid_amount: i128,      // Amount actually paid, 0 until the epoch is distributed
 }
 
 /// Quality thresholds for review rewards
@@ -40,16 +47,316 @@ pub enum QualityTier {
     Exceptional,
 }
 
+/// Budget rules of the reward pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardPoolConfig {
+    pub epoch_duration: u64,               // Length of a reward epoch in seconds
+    pub epoch_budget: i128,                // Maximum paid out per epoch, on top of the rollover
+    pub reviewer_cap: i128,                // Maximum paid to one reviewer per epoch
+}
+
+/// Outcome of a distributed reward epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardEpoch {
+    pub epoch_id: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub budget: i128,                      // Funded budget available to the epoch
+    pub distributed: i128,                 // Amount paid to reviewers
+    pub reward_count: u32,                 // Rewards queued during the epoch
+}
+
 /// Errors specific to the reward system
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum RewardError {
-    RewardContractNotSet = 100,
+    RewardTokenNotSet = 100,
     RewardAlreadyIssued = 101,
     NotEligibleForReward = 102,
     InvalidRewardAmount = 103,
     RewardDistributionFailed = 104,
     ThresholdsNotSet = 105,
     RewardAmountsNotSet = 106,
-}
\ No newline at end of file
+    NotAdmin = 107,
+    InvalidPoolConfig = 108,
+    RewardsAlreadyInitialized = 109,
+    EpochRewardsFull = 110,
+    EpochsNotDistributed = 111,
+    NotReviewer = 112,
+}
+
+/// Reward pool and epoch handling
+impl ReviewSystemContract {
+    /// Check that `admin` is the contract admin and authorized the call
+    pub(crate) fn require_reward_admin(env: &Env, admin: &Address) -> Result<(), RewardError> {
+        admin.require_auth();
+
+        if !Self::is_reward_admin(env, admin) {
+            return Err(RewardError::NotAdmin);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn is_reward_admin(env: &Env, address: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<DataKey, Address>(&DataKey::Admin)
+            .is_some_and(|admin| admin == *address)
+    }
+
+    pub(crate) fn validate_pool_config(config: &RewardPoolConfig) -> Result<(), RewardError> {
+        if config.epoch_duration == 0 || config.epoch_budget <= 0 || config.reviewer_cap <= 0 {
+            return Err(RewardError::InvalidPoolConfig);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_pool_config(env: &Env) -> Result<RewardPoolConfig, RewardError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardPoolConfig)
+            .ok_or(RewardError::RewardTokenNotSet)
+    }
+
+    pub(crate) fn get_pool_balance(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardPoolBalance)
+            .unwrap_or(0)
+    }
+
+    /// Epoch the current ledger time falls in
+    pub(crate) fn current_epoch_id(env: &Env, config: &RewardPoolConfig) -> u64 {
+        let genesis: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RewardEpochGenesis)
+            .unwrap_or(0);
+        env.ledger().timestamp().saturating_sub(genesis) / config.epoch_duration
+    }
+
+    /// Close the ended epochs up to and including the oldest one holding rewards, which
+    /// is distributed. Empty epochs only roll their budget over, so a call pays out at
+    /// most one epoch of `MAX_EPOCH_REWARDS` rewards.
+    /// Returns the number of epochs closed.
+    pub(crate) fn close_ended_epochs(env: &Env) -> Result<u64, RewardError> {
+        let config = Self::get_pool_config(env)?;
+        let current_epoch = Self::current_epoch_id(env, &config);
+        let next_epoch = Self::next_epoch_to_close(env);
+
+        if next_epoch >= current_epoch {
+            return Ok(0);
+        }
+
+        let mut pending = Self::get_pending_epochs(env);
+        let rewarded_epoch = pending.first().filter(|epoch_id| *epoch_id < current_epoch);
+        let closed_until = rewarded_epoch.map_or(current_epoch, |epoch_id| epoch_id + 1);
+
+        // The epochs before the rewarded one were empty and just roll their budget over
+        let empty_epochs = rewarded_epoch.unwrap_or(current_epoch) - next_epoch;
+        if empty_epochs > 0 {
+            let rollover = Self::get_rollover(env)
+                .saturating_add(config.epoch_budget.saturating_mul(empty_epochs as i128))
+                .min(Self::get_pool_balance(env));
+            env.storage().persistent().set(&DataKey::RewardRollover, &rollover);
+        }
+
+        if let Some(epoch_id) = rewarded_epoch {
+            Self::distribute_epoch(env, &config, epoch_id)?;
+            pending.pop_front();
+            env.storage()
+                .persistent()
+                .set(&DataKey::PendingRewardEpochs, &pending);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::NextEpochToClose, &closed_until);
+
+        Ok(closed_until - next_epoch)
+    }
+
+    /// First epoch not closed yet
+    pub(crate) fn next_epoch_to_close(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::NextEpochToClose)
+            .unwrap_or(0)
+    }
+
+    /// Epochs holding rewards that are not distributed yet, oldest first
+    pub(crate) fn get_pending_epochs(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingRewardEpochs)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Pay the rewards queued in an epoch. The funded budget is shared in proportion to
+    /// each review's quality score times its helpful votes, without exceeding the reward
+    /// amount of the review or the per reviewer cap. Whatever is left rolls over.
+    fn distribute_epoch(env: &Env, config: &RewardPoolConfig, epoch_id: u64) -> Result<(), RewardError> {
+        let thresholds: QualityThresholds = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QualityThresholds)
+            .ok_or(RewardError::ThresholdsNotSet)?;
+
+        let review_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EpochRewards(epoch_id))
+            .unwrap_or(Vec::new(env));
+
+        let mut weights: Vec<i128> = Vec::new(env);
+        let mut total_weight = 0i128;
+        for review_id in review_ids.iter() {
+            let weight = Self::best_approved_response(env, review_id, &thresholds)
+                .map(|(response, score)| score as i128 * response.helpful_votes as i128)
+                .unwrap_or(0);
+            weights.push_back(weight);
+            total_weight += weight;
+        }
+
+        let balance = Self::get_pool_balance(env);
+        let budget = config.epoch_budget.saturating_add(Self::get_rollover(env)).min(balance);
+
+        let token_address: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RewardToken)
+            .ok_or(RewardError::RewardTokenNotSet)?;
+        let token_client = token::Client::new(env, &token_address);
+
+        let mut paid_per_reviewer: Map<Address, i128> = Map::new(env);
+        let mut distributed = 0i128;
+        for (index, review_id) in review_ids.iter().enumerate() {
+            let mut reward: ReviewReward = match env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReviewReward(review_id))
+            {
+                Some(reward) => reward,
+                None => continue,
+            };
+
+            let weight = weights.get(index as u32).unwrap_or(0);
+            if total_weight == 0 || weight == 0 {
+                continue;
+            }
+
+            let already_paid = paid_per_reviewer.get(reward.reviewer.clone()).unwrap_or(0);
+            let share = budget * weight / total_weight;
+            let payout = share
+                .min(reward.token_amount)
+                .min(config.reviewer_cap - already_paid);
+            if payout <= 0 {
+                continue;
+            }
+
+            token_client.transfer(&env.current_contract_address(), &reward.reviewer, &payout);
+
+            reward.paid_amount = payout;
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReviewReward(review_id), &reward);
+            paid_per_reviewer.set(reward.reviewer.clone(), already_paid + payout);
+            distributed += payout;
+
+            env.events().publish(
+                (Symbol::new(env, "reward_paid"), reward.reviewer.clone()),
+                (review_id, epoch_id, payout),
+            );
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardPoolBalance, &(balance - distributed));
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardRollover, &(budget - distributed));
+
+        let total_paid: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TotalRewardsPaid)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TotalRewardsPaid, &(total_paid + distributed));
+
+        let (start_time, end_time) = Self::epoch_bounds(env, config, epoch_id);
+        let epoch = RewardEpoch {
+            epoch_id,
+            start_time,
+            end_time,
+            budget,
+            distributed,
+            reward_count: review_ids.len(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardEpoch(epoch_id), &epoch);
+
+        env.events().publish(
+            (Symbol::new(env, "epoch_distributed"), epoch_id),
+            (budget, distributed, budget - distributed),
+        );
+
+        Ok(())
+    }
+
+    /// Budget carried over from the previous epochs
+    pub(crate) fn get_rollover(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardRollover)
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn epoch_bounds(env: &Env, config: &RewardPoolConfig, epoch_id: u64) -> (u64, u64) {
+        let genesis: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RewardEpochGenesis)
+            .unwrap_or(0);
+        let start_time = genesis + epoch_id * config.epoch_duration;
+        (start_time, start_time + config.epoch_duration)
+    }
+
+    /// Approved response of a review with the highest quality score, with that score
+    pub(crate) fn best_approved_response(
+        env: &Env,
+        review_id: u64,
+        thresholds: &QualityThresholds,
+    ) -> Option<(Response, u32)> {
+        let response_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ResponsesByReview(review_id))
+            .unwrap_or(Vec::new(env));
+
+        let mut best: Option<(Response, u32)> = None;
+        for response_id in response_ids.iter() {
+            if let Some(response) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Response>(&DataKey::Response(response_id))
+            {
+                // Only consider approved responses
+                if response.moderation_status != ModerationStatus::Approved {
+                    continue;
+                }
+
+                let score = Self::calculate_quality_score(&response, thresholds);
+                if best.as_ref().map_or(score > 0, |(_, best_score)| score > *best_score) {
+                    best = Some((response, score));
+                }
+            }
+        }
+        best
+    }
+}
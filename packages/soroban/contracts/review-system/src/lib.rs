@@ -1,7 +1,7 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracterror, contracttype, Address, Env, String, Symbol, Vec, IntoVal,
+    contract, contractimpl, contracterror, contracttype, Address, Env, String, Symbol, Vec,
 };
 
 mod response;
@@ -15,7 +15,10 @@ mod test;
 pub use response::ThreadNode;
 pub use utils::{ResponseStats, CredibilityTier, RewardStatistics};
 pub use reputation::ReviewerProfile;
-pub use incentives::{ReviewReward, QualityThresholds, RewardAmounts, QualityTier, RewardError};
+pub use incentives::{
+    ReviewReward, QualityThresholds, RewardAmounts, QualityTier, RewardError, RewardPoolConfig,
+    RewardEpoch, MAX_EPOCH_REWARDS,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -54,12 +57,22 @@ pub enum DataKey {
     ReviewerProfile(Address), // reviewer_address -> ReviewerProfile
     UserReputationContract, // Address of the user reputation contract for integration
     // Reward system keys
-    RewardContract,                        // Address of the reward distribution contract
+    RewardToken,                           // Address of the token rewards are paid in
     ReviewReward(u64),                     // review_id -> ReviewReward
     ReviewerRewards(Address),              // reviewer -> Vec<u64> (review_ids with rewards)
     RewardCounter,                         // Counter for total rewards issued
     QualityThresholds,                     // Quality thresholds for rewards
     RewardAmounts,                         // Standard reward amounts by quality tier
+    // Reward pool keys
+    RewardPoolConfig,                      // Epoch length and budget caps
+    RewardPoolBalance,                     // Funded tokens not paid out yet
+    RewardEpochGenesis,                    // Start time of epoch 0
+    NextEpochToClose,                      // First epoch not distributed yet
+    RewardRollover,                        // Unspent budget carried into the next epoch
+    RewardEpoch(u64),                      // epoch_id -> RewardEpoch
+    EpochRewards(u64),                     // epoch_id -> Vec<u64> (review_ids queued)
+    PendingRewardEpochs,                   // Vec<u64> of epochs with rewards not distributed yet
+    TotalRewardsPaid,                      // Total amount paid to reviewers
 }
 
 #[contracterror]
@@ -446,20 +459,27 @@ impl ReviewSystemContract {
 
     // === REWARD SYSTEM FUNCTIONS ===
 
-    /// Initialize reward system with contract address and parameters
+    /// Initialize reward system with the reward token, quality rules and pool budget.
+    /// Epoch 0 starts now.
     pub fn initialize_rewards(
         env: Env,
         admin: Address,
-        reward_contract: Address,
+        reward_token: Address,
         quality_thresholds: QualityThresholds,
         reward_amounts: RewardAmounts,
-    ) {
-        admin.require_auth();
+        pool_config: RewardPoolConfig,
+    ) -> Result<(), RewardError> {
+        Self::require_reward_admin(&env, &admin)?;
 
-        // Store reward contract address
+        if env.storage().persistent().has(&DataKey::RewardToken) {
+            return Err(RewardError::RewardsAlreadyInitialized);
+        }
+        Self::validate_pool_config(&pool_config)?;
+
+        // Store reward token address
         env.storage()
             .persistent()
-            .set(&DataKey::RewardContract, &reward_contract);
+            .set(&DataKey::RewardToken, &reward_token);
 
         // Store quality thresholds
         env.storage()
@@ -471,6 +491,14 @@ impl ReviewSystemContract {
             .persistent()
             .set(&DataKey::RewardAmounts, &reward_amounts);
 
+        // Store pool budget and start the first epoch
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardPoolConfig, &pool_config);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardEpochGenesis, &env.ledger().timestamp());
+
         // Initialize reward counter
         env.storage()
             .persistent()
@@ -478,18 +506,117 @@ impl ReviewSystemContract {
 
         env.events().publish(
             (Symbol::new(&env, "rewards_initialized"), admin),
-            (reward_contract, quality_thresholds, reward_amounts),
+            (reward_token, quality_thresholds, reward_amounts, pool_config),
         );
+
+        Ok(())
     }
 
-    /// Issue tokens for a high-quality review
+    /// Deposit reward tokens into the pool. Anyone can fund it.
+    pub fn fund_reward_pool(env: Env, funder: Address, amount: i128) -> Result<i128, RewardError> {
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(RewardError::InvalidRewardAmount);
+        }
+
+        let token_address: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RewardToken)
+            .ok_or(RewardError::RewardTokenNotSet)?;
+        soroban_sdk::token::Client::new(&env, &token_address).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let balance = Self::get_pool_balance(&env) + amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardPoolBalance, &balance);
+
+        env.events().publish(
+            (Symbol::new(&env, "reward_pool_funded"), funder),
+            (amount, balance),
+        );
+
+        Ok(balance)
+    }
+
+    /// Update the epoch budget and per reviewer cap (admin only).
+    /// The epoch duration cannot change once rewards are initialized.
+    pub fn update_reward_pool_config(
+        env: Env,
+        admin: Address,
+        pool_config: RewardPoolConfig,
+    ) -> Result<(), RewardError> {
+        Self::require_reward_admin(&env, &admin)?;
+        Self::validate_pool_config(&pool_config)?;
+
+        let current = Self::get_pool_config(&env)?;
+        if pool_config.epoch_duration != current.epoch_duration {
+            return Err(RewardError::InvalidPoolConfig);
+        }
+
+        // Ended epochs are distributed under the budget they ran with
+        Self::close_ended_epochs(&env)?;
+        if Self::next_epoch_to_close(&env) < Self::current_epoch_id(&env, &current) {
+            return Err(RewardError::EpochsNotDistributed);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardPoolConfig, &pool_config);
+
+        env.events().publish(
+            (Symbol::new(&env, "reward_pool_updated"), admin),
+            pool_config,
+        );
+
+        Ok(())
+    }
+
+    /// Distribute the rewards of the oldest ended epoch holding rewards, closing the empty
+    /// epochs before it. Anyone can trigger it; call it again while it returns a non-zero
+    /// count to catch up on several ended epochs.
+    /// Returns the number of epochs closed.
+    pub fn distribute_epoch_rewards(env: Env) -> Result<u64, RewardError> {
+        Self::close_ended_epochs(&env)
+    }
+
+    /// Get the id of the epoch rewards are currently queued in
+    pub fn get_current_epoch(env: Env) -> Result<u64, RewardError> {
+        let config = Self::get_pool_config(&env)?;
+        Ok(Self::current_epoch_id(&env, &config))
+    }
+
+    /// Get the outcome of a distributed epoch. Epochs without rewards are not recorded.
+    pub fn get_reward_epoch(env: Env, epoch_id: u64) -> Option<RewardEpoch> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardEpoch(epoch_id))
+    }
+
+    /// Get the reward pool budget rules
+    pub fn get_reward_pool_config(env: Env) -> Result<RewardPoolConfig, RewardError> {
+        Self::get_pool_config(&env)
+    }
+
+    /// Get the funded tokens not paid out yet
+    pub fn get_reward_pool_balance(env: Env) -> i128 {
+        Self::get_pool_balance(&env)
+    }
+
+    /// Queue a reward for a high-quality review in the current epoch, as the admin or the
+    /// review's reviewer. The amount follows the review's quality tier; only the admin can
+    /// set a custom amount. Rewards are paid from the pool when the epoch is distributed.
     pub fn issue_reward(
         env: Env,
         caller: Address,
         review_id: u64,
         token_amount: Option<i128>,
     ) -> Result<ReviewReward, RewardError> {
-        use crate::incentives::*;
         caller.require_auth();
 
         // Check if reward already issued for this review
@@ -503,9 +630,21 @@ impl ReviewSystemContract {
         // Check reward eligibility
         let quality_tier = Self::check_reward_eligibility_internal(&env, review_id)?;
 
+        // Get review to find reviewer
+        let reviewer = Self::get_reviewer_for_review(&env, review_id)
+            .map_err(|_| RewardError::NotEligibleForReward)?;
+
+        // Epoch slots are capped, so only the admin or the reviewer can take one
+        if caller != reviewer && !Self::is_reward_admin(&env, &caller) {
+            return Err(RewardError::NotReviewer);
+        }
+
         // Determine reward amount
         let final_amount = if let Some(amount) = token_amount {
-            // Validate custom amount
+            // Custom amounts are reserved to the admin
+            if !Self::is_reward_admin(&env, &caller) {
+                return Err(RewardError::NotAdmin);
+            }
             if amount <= 0 {
                 return Err(RewardError::InvalidRewardAmount);
             }
@@ -515,12 +654,17 @@ impl ReviewSystemContract {
             Self::get_standard_reward_amount(&env, &quality_tier)?
         };
 
-        // Get review to find reviewer
-        let reviewer = Self::get_reviewer_for_review(&env, review_id)
-            .map_err(|_| RewardError::NotEligibleForReward)?;
+        let config = Self::get_pool_config(&env)?;
+        let epoch_id = Self::current_epoch_id(&env, &config);
 
-        // Distribute tokens via reward contract
-        Self::distribute_reward_tokens(&env, &reviewer, final_amount)?;
+        let mut epoch_rewards: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EpochRewards(epoch_id))
+            .unwrap_or(Vec::new(&env));
+        if epoch_rewards.len() >= MAX_EPOCH_REWARDS {
+            return Err(RewardError::EpochRewardsFull);
+        }
 
         // Create reward record
         let reward = ReviewReward {
@@ -528,6 +672,8 @@ impl ReviewSystemContract {
             reviewer: reviewer.clone(),
             token_amount: final_amount,
             timestamp: env.ledger().timestamp(),
+            epoch_id,
+            paid_amount: 0,
         };
 
         // Store reward record
@@ -535,6 +681,19 @@ impl ReviewSystemContract {
             .persistent()
             .set(&DataKey::ReviewReward(review_id), &reward);
 
+        // Queue the reward for the end of the epoch
+        if epoch_rewards.is_empty() {
+            let mut pending = Self::get_pending_epochs(&env);
+            pending.push_back(epoch_id);
+            env.storage()
+                .persistent()
+                .set(&DataKey::PendingRewardEpochs, &pending);
+        }
+        epoch_rewards.push_back(review_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EpochRewards(epoch_id), &epoch_rewards);
+
         // Update reviewer's reward list
         Self::add_to_reviewer_rewards(&env, &reviewer, review_id);
 
@@ -551,7 +710,7 @@ impl ReviewSystemContract {
         // Emit reward issuance event
         env.events().publish(
             (Symbol::new(&env, "reward_issued"), reviewer.clone()),
-            (review_id, final_amount, env.ledger().timestamp()),
+            (review_id, final_amount, epoch_id),
         );
 
         Ok(reward)
@@ -720,12 +879,22 @@ impl ReviewSystemContract {
             .get(&DataKey::RewardCounter)
             .unwrap_or(0);
 
-        // For a complete implementation, you'd iterate through all rewards
-        // to calculate these statistics. For now, we return basic info.
+        let total_amount_distributed: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TotalRewardsPaid)
+            .unwrap_or(0);
+
+        // Counting unique reviewers would need to iterate through all rewards,
+        // so it is not tracked.
         RewardStatistics {
             total_rewards_issued,
-            total_amount_distributed: 0, // Would need to calculate from all rewards
-            average_reward_amount: 0,    // Would need to calculate from all rewards
+            total_amount_distributed,
+            average_reward_amount: if total_rewards_issued > 0 {
+                total_amount_distributed / total_rewards_issued as i128
+            } else {
+                0
+            },
             unique_rewarded_reviewers: 0, // Would need to count unique addresses
         }
     }
@@ -754,8 +923,6 @@ impl ReviewSystemContract {
         env: &Env,
         review_id: u64,
     ) -> Result<QualityTier, RewardError> {
-        use crate::incentives::*;
-
         // Get quality thresholds
         let thresholds: QualityThresholds = env
             .storage()
@@ -763,43 +930,9 @@ impl ReviewSystemContract {
             .get(&DataKey::QualityThresholds)
             .ok_or(RewardError::ThresholdsNotSet)?;
 
-        // Find responses for this review to evaluate quality
-        let response_ids: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ResponsesByReview(review_id))
-            .unwrap_or(Vec::new(env));
-
-        if response_ids.is_empty() {
-            return Err(RewardError::NotEligibleForReward);
-        }
-
         // Evaluate the best response for this review
-        let mut best_response: Option<Response> = None;
-        let mut highest_quality_score = 0u32;
-
-        for response_id in response_ids.iter() {
-            if let Some(response) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Response>(&DataKey::Response(response_id))
-            {
-                // Only consider approved responses
-                if response.moderation_status != ModerationStatus::Approved {
-                    continue;
-                }
-
-                // Calculate quality score
-                let quality_score = Self::calculate_quality_score(&response, &thresholds);
-                
-                if quality_score > highest_quality_score {
-                    highest_quality_score = quality_score;
-                    best_response = Some(response);
-                }
-            }
-        }
-
-        let response = best_response.ok_or(RewardError::NotEligibleForReward)?;
+        let (response, _) = Self::best_approved_response(env, review_id, &thresholds)
+            .ok_or(RewardError::NotEligibleForReward)?;
 
         // Check if meets minimum criteria
         if !Self::meets_minimum_criteria(&response, &thresholds) {
@@ -875,8 +1008,6 @@ impl ReviewSystemContract {
 
     /// Get standard reward amount for quality tier
     fn get_standard_reward_amount(env: &Env, tier: &QualityTier) -> Result<i128, RewardError> {
-        use crate::incentives::*;
-
         let amounts: RewardAmounts = env
             .storage()
             .persistent()
@@ -914,50 +1045,6 @@ impl ReviewSystemContract {
         Err(ResponseError::ResponseNotFound)
     }
 
-    /// Distribute reward tokens via external reward contract
-    fn distribute_reward_tokens(
-        env: &Env,
-        recipient: &Address,
-        amount: i128,
-    ) -> Result<(), RewardError> {
-        #[cfg(test)]
-        {
-            // In test mode, just emit an event
-            env.events().publish(
-                (Symbol::new(env, "mock_reward_distributed"), recipient),
-                amount,
-            );
-            return Ok(());
-        }
-
-        #[cfg(not(test))]
-        {
-            let reward_contract: Address = env
-                .storage()
-                .persistent()
-                .get(&DataKey::RewardContract)
-                .ok_or(RewardError::RewardContractNotSet)?;
-
-            // Call the reward contract to distribute tokens
-            let result = env.try_invoke_contract::<(), RewardError>(
-                &reward_contract,
-                &Symbol::new(env, "distribute_tokens"),
-                soroban_sdk::vec![
-                    env,
-                    recipient.into_val(env),
-                    amount.into_val(env),
-                    Symbol::new(env, "review_reward").into_val(env)
-                ],
-            );
-
-            result
-                .map_err(|_| RewardError::RewardDistributionFailed)?
-                .map_err(|_| RewardError::RewardDistributionFailed)?;
-
-            Ok(())
-        }
-    }
-
     /// Add review to reviewer's reward list
     fn add_to_reviewer_rewards(env: &Env, reviewer: &Address, review_id: u64) {
         let mut rewards: Vec<u64> = env
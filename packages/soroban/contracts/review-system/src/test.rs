@@ -614,14 +614,18 @@ fn test_reviewer_profile_updates() {
 
 // === REWARD SYSTEM TESTS ===
 
-use crate::{QualityThresholds, RewardAmounts};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use crate::{QualityThresholds, RewardAmounts, RewardError, RewardPoolConfig, MAX_EPOCH_REWARDS};
 
-fn setup_reward_system(
+const EPOCH_DURATION: u64 = 7 * 24 * 60 * 60;
+
+fn setup_reward_system<'a>(
     env: &Env,
     client: &ReviewSystemContractClient,
     admin: &Address,
-) {
-    let reward_contract = Address::generate(env);
+) -> TokenClient<'a> {
+    let reward_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
     
     let quality_thresholds = QualityThresholds {
         min_length: 50,
@@ -636,12 +640,47 @@ fn setup_reward_system(
         exceptional_reward: 10_000_000, // 10 XLM in stroops
     };
 
+    let pool_config = RewardPoolConfig {
+        epoch_duration: EPOCH_DURATION,
+        epoch_budget: 20_000_000,       // 20 XLM per epoch
+        reviewer_cap: 8_000_000,        // 8 XLM per reviewer per epoch
+    };
+
     client.initialize_rewards(
         admin,
-        &reward_contract,
+        &reward_token,
         &quality_thresholds,
         &reward_amounts,
+        &pool_config,
     );
+
+    // Fund the pool with 100 XLM
+    StellarAssetClient::new(env, &reward_token).mint(admin, &100_000_000);
+    client.fund_reward_pool(admin, &100_000_000);
+
+    TokenClient::new(env, &reward_token)
+}
+
+/// Add an approved response to `review_id` with `helpful_votes` helpful votes
+fn add_rewardable_response(
+    env: &Env,
+    client: &ReviewSystemContractClient,
+    reviewer: &Address,
+    review_id: u64,
+    helpful_votes: u32,
+) -> u64 {
+    let response_text = String::from_str(env, "Thorough response covering the strengths and gaps of the course with concrete suggestions.");
+    let response_id = client.add_response(reviewer, &review_id, &0u64, &response_text);
+    client.update_moderation_status(&response_id, &ModerationStatus::Approved);
+
+    for _ in 0..helpful_votes {
+        client.vote_helpful(&Address::generate(env), &response_id, &true);
+    }
+    response_id
+}
+
+fn end_epoch(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += EPOCH_DURATION);
 }
 
 #[test]
@@ -774,9 +813,9 @@ fn test_prevent_duplicate_rewards() {
     // Issue first reward
     let _reward1 = client.issue_reward(&admin, &review_id, &None);
 
-    // Try to issue second reward for same review - this should panic in test environment
-    // since Soroban client will panic on Result::Err
-    // We can't easily test error cases with the generated client
+    // A second reward for the same review is rejected
+    let result = client.try_issue_reward(&admin, &review_id, &None);
+    assert_eq!(result, Err(Ok(RewardError::RewardAlreadyIssued)));
 }
 
 #[test]
@@ -825,13 +864,12 @@ fn test_invalid_reward_amount() {
     let voter = Address::generate(&env);
     client.vote_helpful(&voter, &response_id, &true);
 
-    // Try to issue reward with invalid (zero) amount - will panic
-    // let invalid_amount = 0i128;
-    // This would panic: client.issue_reward(&admin, &review_id, &Some(invalid_amount));
+    // Zero and negative custom amounts are rejected
+    let result = client.try_issue_reward(&admin, &review_id, &Some(0i128));
+    assert_eq!(result, Err(Ok(RewardError::InvalidRewardAmount)));
 
-    // Try with negative amount - will also panic  
-    // let negative_amount = -1000i128;
-    // This would panic: client.issue_reward(&admin, &review_id, &Some(negative_amount));
+    let result = client.try_issue_reward(&admin, &review_id, &Some(-1000i128));
+    assert_eq!(result, Err(Ok(RewardError::InvalidRewardAmount)));
 }
 
 #[test]
@@ -940,4 +978,348 @@ fn test_get_review_reward() {
     assert_eq!(reward_data.review_id, review_id);
     assert_eq!(reward_data.reviewer, reviewer);
     assert!(reward_data.token_amount > 0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_custom_reward_amount_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    setup_reward_system(&env, &client, &admin);
+
+    let reviewer = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer, 1, 1);
+
+    // The reviewer cannot pick their own reward amount
+    let result = client.try_issue_reward(&reviewer, &1u64, &Some(1_000_000_000i128));
+    assert_eq!(result, Err(Ok(RewardError::NotAdmin)));
+
+    // Without a custom amount the reviewer can queue the tier reward
+    let reward = client.issue_reward(&reviewer, &1u64, &None);
+    assert_eq!(reward.token_amount, 10_000_000);
+}
+
+#[test]
+fn test_issue_reward_reviewer_or_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    setup_reward_system(&env, &client, &admin);
+
+    let reviewer = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer, 1, 1);
+
+    // A third party cannot take the review's slot in the capped epoch
+    let griefer = Address::generate(&env);
+    let result = client.try_issue_reward(&griefer, &1u64, &None);
+    assert_eq!(result, Err(Ok(RewardError::NotReviewer)));
+    assert!(client.get_review_reward(&1u64).is_none());
+
+    let reward = client.issue_reward(&admin, &1u64, &None);
+    assert_eq!(reward.reviewer, reviewer);
+}
+
+#[test]
+fn test_initialize_rewards_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+
+    let thresholds = QualityThresholds {
+        min_length: 50,
+        min_helpful_votes: 1,
+        min_helpfulness_ratio: 60,
+        max_not_helpful_votes: 2,
+    };
+    let amounts = RewardAmounts {
+        basic_reward: 1_000_000,
+        high_quality_reward: 5_000_000,
+        exceptional_reward: 10_000_000,
+    };
+    let pool_config = RewardPoolConfig {
+        epoch_duration: EPOCH_DURATION,
+        epoch_budget: 20_000_000,
+        reviewer_cap: 8_000_000,
+    };
+    let token = Address::generate(&env);
+
+    let attacker = Address::generate(&env);
+    let result = client.try_initialize_rewards(&attacker, &token, &thresholds, &amounts, &pool_config);
+    assert_eq!(result, Err(Ok(RewardError::NotAdmin)));
+
+    let invalid_config = RewardPoolConfig { epoch_budget: 0, ..pool_config.clone() };
+    let result = client.try_initialize_rewards(&admin, &token, &thresholds, &amounts, &invalid_config);
+    assert_eq!(result, Err(Ok(RewardError::InvalidPoolConfig)));
+
+    client.initialize_rewards(&admin, &token, &thresholds, &amounts, &pool_config);
+    let result = client.try_initialize_rewards(&admin, &token, &thresholds, &amounts, &pool_config);
+    assert_eq!(result, Err(Ok(RewardError::RewardsAlreadyInitialized)));
+}
+
+#[test]
+fn test_fund_reward_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    let token = setup_reward_system(&env, &client, &admin);
+
+    assert_eq!(client.get_reward_pool_balance(), 100_000_000);
+    assert_eq!(token.balance(&client.address), 100_000_000);
+
+    let sponsor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&sponsor, &5_000_000);
+    assert_eq!(client.fund_reward_pool(&sponsor, &5_000_000), 105_000_000);
+    assert_eq!(token.balance(&sponsor), 0);
+
+    let result = client.try_fund_reward_pool(&sponsor, &0i128);
+    assert_eq!(result, Err(Ok(RewardError::InvalidRewardAmount)));
+}
+
+#[test]
+fn test_rewards_paid_at_epoch_end() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    let token = setup_reward_system(&env, &client, &admin);
+
+    let reviewer = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer, 1, 1);
+
+    // Nothing is paid while the epoch runs
+    let reward = client.issue_reward(&admin, &1u64, &Some(5_000_000));
+    assert_eq!(reward.epoch_id, 0);
+    assert_eq!(reward.paid_amount, 0);
+    assert_eq!(token.balance(&reviewer), 0);
+    assert_eq!(client.distribute_epoch_rewards(), 0);
+
+    end_epoch(&env);
+    assert_eq!(client.get_current_epoch(), 1);
+    assert_eq!(client.distribute_epoch_rewards(), 1);
+
+    assert_eq!(token.balance(&reviewer), reward.token_amount);
+    assert_eq!(client.get_review_reward(&1u64).unwrap().paid_amount, reward.token_amount);
+    assert_eq!(client.get_reward_pool_balance(), 100_000_000 - reward.token_amount);
+
+    let epoch = client.get_reward_epoch(&0u64).unwrap();
+    assert_eq!(epoch.budget, 20_000_000);
+    assert_eq!(epoch.distributed, reward.token_amount);
+    assert_eq!(epoch.reward_count, 1);
+    assert_eq!(epoch.end_time, EPOCH_DURATION);
+
+    let stats = client.get_reward_statistics();
+    assert_eq!(stats.total_amount_distributed, reward.token_amount);
+}
+
+#[test]
+fn test_epoch_budget_split_by_weighted_quality() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    let token = setup_reward_system(&env, &client, &admin);
+
+    // Budget of 4 XLM for two reviews asking 5 XLM and 8 XLM
+    client.update_reward_pool_config(&admin, &RewardPoolConfig {
+        epoch_duration: EPOCH_DURATION,
+        epoch_budget: 4_000_000,
+        reviewer_cap: 8_000_000,
+    });
+
+    let reviewer1 = Address::generate(&env);
+    let reviewer2 = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer1, 1, 1);
+    add_rewardable_response(&env, &client, &reviewer2, 2, 3);
+    client.issue_reward(&admin, &1u64, &Some(5_000_000));
+    client.issue_reward(&admin, &2u64, &Some(8_000_000));
+
+    end_epoch(&env);
+    client.distribute_epoch_rewards();
+
+    // Both responses score 100, so the budget is split 1:3 by helpful votes
+    assert_eq!(token.balance(&reviewer1), 1_000_000);
+    assert_eq!(token.balance(&reviewer2), 3_000_000);
+    assert_eq!(client.get_reward_epoch(&0u64).unwrap().distributed, 4_000_000);
+}
+
+#[test]
+fn test_reviewer_cap_per_epoch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    let token = setup_reward_system(&env, &client, &admin);
+
+    // Two 5 XLM rewards for the same reviewer against the 8 XLM cap
+    let reviewer = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer, 1, 1);
+    add_rewardable_response(&env, &client, &reviewer, 2, 1);
+    client.issue_reward(&admin, &1u64, &Some(5_000_000));
+    client.issue_reward(&admin, &2u64, &Some(5_000_000));
+
+    end_epoch(&env);
+    client.distribute_epoch_rewards();
+
+    assert_eq!(token.balance(&reviewer), 8_000_000);
+    assert_eq!(client.get_review_reward(&2u64).unwrap().paid_amount, 3_000_000);
+}
+
+#[test]
+fn test_unspent_budget_rolls_over() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    let token = setup_reward_system(&env, &client, &admin);
+
+    client.update_reward_pool_config(&admin, &RewardPoolConfig {
+        epoch_duration: EPOCH_DURATION,
+        epoch_budget: 4_000_000,
+        reviewer_cap: 8_000_000,
+    });
+
+    // Epoch 0 spends 1 XLM of its 4 XLM budget
+    let reviewer1 = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer1, 1, 1);
+    client.issue_reward(&admin, &1u64, &Some(1_000_000));
+
+    // Epoch 1 is skipped without rewards, epoch 2 queues a 7 XLM reward
+    end_epoch(&env);
+    end_epoch(&env);
+    let reviewer2 = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer2, 2, 1);
+    let reward = client.issue_reward(&admin, &2u64, &Some(7_000_000));
+    assert_eq!(reward.epoch_id, 2);
+
+    // Issuing the reward does not distribute the ended epochs
+    assert_eq!(token.balance(&reviewer1), 0);
+
+    // Each call distributes one rewarded epoch
+    end_epoch(&env);
+    assert_eq!(client.distribute_epoch_rewards(), 1);
+    assert_eq!(token.balance(&reviewer1), 1_000_000);
+    assert_eq!(token.balance(&reviewer2), 0);
+
+    // 3 XLM from epoch 0 and 4 XLM from epoch 1 roll over on top of the 4 XLM budget
+    assert_eq!(client.distribute_epoch_rewards(), 2);
+    assert!(client.get_reward_epoch(&1u64).is_none());
+    assert_eq!(token.balance(&reviewer2), 7_000_000);
+    assert_eq!(client.distribute_epoch_rewards(), 0);
+
+    let epoch = client.get_reward_epoch(&2u64).unwrap();
+    assert_eq!(epoch.budget, 11_000_000);
+    assert_eq!(epoch.distributed, 7_000_000);
+}
+
+#[test]
+fn test_epoch_rewards_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    setup_reward_system(&env, &client, &admin);
+
+    let reviewer = Address::generate(&env);
+    for review_id in 1..=MAX_EPOCH_REWARDS as u64 {
+        add_rewardable_response(&env, &client, &reviewer, review_id, 1);
+        client.issue_reward(&admin, &review_id, &Some(1));
+    }
+
+    let review_id = MAX_EPOCH_REWARDS as u64 + 1;
+    add_rewardable_response(&env, &client, &reviewer, review_id, 1);
+    let result = client.try_issue_reward(&admin, &review_id, &Some(1));
+    assert_eq!(result, Err(Ok(RewardError::EpochRewardsFull)));
+
+    // The next epoch takes rewards again
+    end_epoch(&env);
+    let reward = client.issue_reward(&admin, &review_id, &Some(1));
+    assert_eq!(reward.epoch_id, 1);
+}
+
+#[test]
+fn test_config_update_requires_ended_epochs_distributed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    setup_reward_system(&env, &client, &admin);
+
+    // Two ended epochs hold rewards, and one call only distributes the first
+    let reviewer = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer, 1, 1);
+    client.issue_reward(&admin, &1u64, &Some(1_000_000));
+    end_epoch(&env);
+    add_rewardable_response(&env, &client, &reviewer, 2, 1);
+    client.issue_reward(&admin, &2u64, &Some(1_000_000));
+    end_epoch(&env);
+
+    let config = RewardPoolConfig {
+        epoch_duration: EPOCH_DURATION,
+        epoch_budget: 4_000_000,
+        reviewer_cap: 8_000_000,
+    };
+    let result = client.try_update_reward_pool_config(&admin, &config);
+    assert_eq!(result, Err(Ok(RewardError::EpochsNotDistributed)));
+
+    client.distribute_epoch_rewards();
+    client.update_reward_pool_config(&admin, &config);
+    assert_eq!(client.get_reward_epoch(&1u64).unwrap().distributed, 1_000_000);
+}
+
+#[test]
+fn test_epoch_budget_limited_by_pool_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, moderation_contract, verification_contract, user_reputation_contract) = create_contract(&env);
+    client.initialize(&admin, &moderation_contract, &verification_contract, &user_reputation_contract);
+    let token = setup_reward_system(&env, &client, &admin);
+
+    client.update_reward_pool_config(&admin, &RewardPoolConfig {
+        epoch_duration: EPOCH_DURATION,
+        epoch_budget: 200_000_000,
+        reviewer_cap: 8_000_000,
+    });
+
+    // Drain the 100 XLM pool down to 4 XLM with a full epoch of payouts
+    for review_id in 1..=12u64 {
+        let reviewer = Address::generate(&env);
+        add_rewardable_response(&env, &client, &reviewer, review_id, 1);
+        client.issue_reward(&admin, &review_id, &Some(8_000_000));
+    }
+    end_epoch(&env);
+    client.distribute_epoch_rewards();
+    assert_eq!(client.get_reward_pool_balance(), 4_000_000);
+
+    let result = client.try_update_reward_pool_config(&admin, &RewardPoolConfig {
+        epoch_duration: 1,
+        epoch_budget: 4_000_000,
+        reviewer_cap: 8_000_000,
+    });
+    assert_eq!(result, Err(Ok(RewardError::InvalidPoolConfig)));
+
+    // Only what the pool holds can be paid out
+    let reviewer = Address::generate(&env);
+    add_rewardable_response(&env, &client, &reviewer, 13, 1);
+    client.issue_reward(&admin, &13u64, &Some(8_000_000));
+    end_epoch(&env);
+    client.distribute_epoch_rewards();
+
+    assert_eq!(token.balance(&reviewer), 4_000_000);
+    assert_eq!(client.get_reward_pool_balance(), 0);
+    assert_eq!(token.balance(&client.address), 0);
+}